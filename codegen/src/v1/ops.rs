@@ -179,12 +179,25 @@ pub fn codegen(ops: &Operations, rust_types: &RustTypes) {
         "",
         "use std::borrow::Cow;",
         "",
+        "use tracing::{Instrument, debug_span};",
+        "",
     ]);
 
+    codegen_names(ops);
     codegen_http(ops, rust_types);
     codegen_router(ops, rust_types);
 }
 
+fn codegen_names(ops: &Operations) {
+    g!("/// The names of all operations, in alphabetical order.");
+    g!("pub const NAMES: &[&str] = &[");
+    for op in ops.values() {
+        g!("\"{}\",", op.name);
+    }
+    g!("];");
+    g!();
+}
+
 fn status_code_name(code: u16) -> &'static str {
     match code {
         200 => "OK",
@@ -216,6 +229,18 @@ fn codegen_http(ops: &Operations, rust_types: &RustTypes) {
     }
 
     codegen_post_object_fork_op(rust_types);
+
+    for op in ops.values() {
+        codegen_op_input(op);
+    }
+}
+
+fn codegen_op_input(op: &Operation) {
+    g!("impl crate::intercept::OperationInput for {} {{", op.input);
+    g!("type Output = {};", op.output);
+    g!("const NAME: &'static str = \"{}\";", op.name);
+    g!("}}");
+    g!();
 }
 
 #[allow(clippy::too_many_lines)]
@@ -239,7 +264,7 @@ fn codegen_post_object_fork_op(rust_types: &RustTypes) {
     g(["pub struct PostObject;", "", "impl PostObject {"]);
     g([
         "    pub fn deserialize_http(req: &mut http::Request) -> S3Result<PostObjectInput> {",
-        "        let Some(m) = req.s3ext.multipart.take() else {",
+        "        let Some(m) = req.s3ext.multipart.take().map(|m| *m) else {",
         "            return Err(invalid_request!(\"missing multipart form\"));",
         "        };",
         "",
@@ -251,7 +276,7 @@ fn codegen_post_object_fork_op(rust_types: &RustTypes) {
         "        let success_action_status: Option<i32> = http::parse_field_value(&m, \"success_action_status\")?;",
         "",
         "        // Get the validated POST policy from request extensions",
-        "        let policy = req.s3ext.post_policy.take();",
+        "        let policy = req.s3ext.post_policy.take().map(|p| *p);",
         "",
         "        let put_input = PutObject::deserialize_http_multipart(req, m)?;",
         "        let mut post_input = put_object_input_into_post_object_input(put_input);",
@@ -328,7 +353,7 @@ fn codegen_post_object_fork_op(rust_types: &RustTypes) {
 
     g([
         "    async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {",
-        "        let post_input = Self::deserialize_http(req)?;",
+        "        let post_input = debug_span!(\"deserialize\").in_scope(|| Self::deserialize_http(req))?;",
         "        // Save POST-specific fields before conversion",
         "        let success_action_redirect = post_input.success_action_redirect.clone();",
         "        let success_action_status = post_input.success_action_status;",
@@ -350,19 +375,23 @@ fn codegen_post_object_fork_op(rust_types: &RustTypes) {
         "            // New hook for POST object (optional).",
         "            access.post_object(&mut post_req).await?;",
         "        }",
-        "        let result = s3.post_object(post_req).await;",
+        "        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut post_req).await?;",
+        "        let mut result = s3.post_object(post_req).instrument(debug_span!(\"handler\")).await;",
+        "        crate::intercept::after(intercepted, &mut result).await;",
         "        let s3_resp = match result {",
         "            Ok(val) => val,",
-        "            Err(err) => return super::serialize_error(err, false),",
+        "            Err(err) => return super::serialize_request_error(err, req),",
         "        };",
         "        // Serialize with POST-specific response behavior",
-        "        let mut resp = Self::serialize_http(",
-        "            &bucket,",
-        "            &key,",
-        "            success_action_redirect.as_deref(),",
-        "            success_action_status,",
-        "            &s3_resp.output,",
-        "        )?;",
+        "        let mut resp = debug_span!(\"serialize\").in_scope(|| {",
+        "            Self::serialize_http(",
+        "                &bucket,",
+        "                &key,",
+        "                success_action_redirect.as_deref(),",
+        "                success_action_status,",
+        "                &s3_resp.output,",
+        "            )",
+        "        })?;",
         "        resp.headers.extend(s3_resp.headers);",
         "        resp.extensions.extend(s3_resp.extensions);",
         "        Ok(resp)",
@@ -584,7 +613,7 @@ fn codegen_op_http_de(op: &Operation, rust_types: &RustTypes) {
                 if op.name == "PutObject" {
                     // POST object
                     g!("if let Some(m) = req.s3ext.multipart.take() {{");
-                    g!("    return Self::deserialize_http_multipart(req, m);");
+                    g!("    return Self::deserialize_http_multipart(req, *m);");
                     g!("}}");
                     g!();
                 }
@@ -916,7 +945,7 @@ fn codegen_op_http_call(op: &Operation) {
 
     let method = op.name.to_snake_case();
 
-    g!("let input = Self::deserialize_http(req)?;");
+    g!("let input = debug_span!(\"deserialize\").in_scope(|| Self::deserialize_http(req))?;");
     g!("let mut s3_req = super::build_s3_request(input, req);");
     g!("let s3 = ccx.s3;");
 
@@ -924,20 +953,23 @@ fn codegen_op_http_call(op: &Operation) {
     g!("    access.{method}(&mut s3_req).await?;");
     g!("}}");
 
+    g!("let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;");
+
     if op.name == "GetObject" {
         g!("let overridden_headers = super::get_object::extract_overridden_response_headers(&s3_req)?;");
     }
 
-    g!("let result = s3.{method}(s3_req).await;");
+    g!("let mut result = s3.{method}(s3_req).instrument(debug_span!(\"handler\")).await;");
+    g!("crate::intercept::after(intercepted, &mut result).await;");
 
     g([
         "let s3_resp = match result {",
         "    Ok(val) => val,",
-        "    Err(err) => return super::serialize_error(err, false),",
+        "    Err(err) => return super::serialize_request_error(err, req),",
         "};",
     ]);
 
-    g!("let mut resp = Self::serialize_http(s3_resp.output)?;");
+    g!("let mut resp = debug_span!(\"serialize\").in_scope(|| Self::serialize_http(s3_resp.output))?;");
 
     if op.name == "GetObject" {
         g!("resp.headers.extend(overridden_headers);");
@@ -62,7 +62,12 @@ impl STS {
     async fn test_assume_role(self: Arc<Self>) -> Result<()> {
         let sts = &self.sts;
 
-        let result = sts.assume_role().role_arn("example").role_session_name("test").send().await;
+        let result = sts
+            .assume_role()
+            .role_arn("arn:aws:iam::123456789012:role/example")
+            .role_session_name("test")
+            .send()
+            .await;
 
         let resp = result?;
 
@@ -7,6 +7,7 @@ use s3s_fs::Result;
 use s3s::auth::SimpleAuth;
 use s3s::host::MultiDomain;
 use s3s::service::S3ServiceBuilder;
+use s3s::sts::{SessionStore, StsAuth, StsRoute};
 
 use std::io::IsTerminal;
 use std::ops::Not;
@@ -43,6 +44,16 @@ struct Opt {
     #[arg(long)]
     domain: Vec<String>,
 
+    /// Enable the STS `AssumeRole` and `GetSessionToken` actions.
+    /// Requires the access key and the secret key.
+    #[arg(long)]
+    enable_sts: bool,
+
+    /// Role ARNs that may be assumed through STS `AssumeRole`.
+    /// Without this option, every `AssumeRole` request is denied.
+    #[arg(long)]
+    sts_role: Vec<String>,
+
     /// Root directory of stored data.
     root: PathBuf,
 }
@@ -71,6 +82,16 @@ fn check_cli_args(opt: &Opt) {
         cmd.error(ErrorKind::MissingRequiredArgument, msg).exit();
     }
 
+    if opt.enable_sts && opt.access_key.is_none() {
+        let msg = "STS requires the access key and the secret key";
+        cmd.error(ErrorKind::MissingRequiredArgument, msg).exit();
+    }
+
+    if opt.sts_role.is_empty().not() && opt.enable_sts.not() {
+        let msg = "STS roles require STS to be enabled";
+        cmd.error(ErrorKind::MissingRequiredArgument, msg).exit();
+    }
+
     for s in &opt.domain {
         if s.contains('/') {
             let msg = format!("expected domain name, found URL-like string: {s:?}");
@@ -99,7 +120,16 @@ async fn run(opt: Opt) -> Result {
 
         // Enable authentication
         if let (Some(ak), Some(sk)) = (opt.access_key, opt.secret_key) {
-            b.set_auth(SimpleAuth::from_single(ak, sk));
+            let auth = SimpleAuth::from_single(ak, sk);
+            if opt.enable_sts {
+                let store = SessionStore::new();
+                b.set_auth(StsAuth::new(auth, store.clone()));
+                let roles = opt.sts_role;
+                b.set_route(StsRoute::new(store).with_role_trust(move |_, role_arn| roles.iter().any(|r| r == role_arn)));
+                info!("STS AssumeRole and GetSessionToken are enabled");
+            } else {
+                b.set_auth(auth);
+            }
             info!("authentication is enabled");
         }
 
@@ -2,9 +2,9 @@
 // SPDX-FileCopyrightText: 2023-2026 The s3s Authors
 
 use s3s::auth::SimpleAuth;
-use s3s::header::CONTENT_TYPE;
 use s3s::host::SingleDomain;
 use s3s::service::S3ServiceBuilder;
+use s3s::sts::{SessionStore, StsAuth, StsRoute};
 use s3s::validation::NameValidation;
 use s3s_fs::FileSystem;
 
@@ -27,7 +27,6 @@ use aws_sdk_s3::types::CreateBucketConfiguration;
 use aws_sdk_s3::error::ProvideErrorMetadata;
 
 use anyhow::Result;
-use hyper::Method;
 use tokio::sync::Mutex;
 use tokio::sync::MutexGuard;
 use tracing::{debug, error};
@@ -37,28 +36,6 @@ const FS_ROOT: &str = concat!(env!("CARGO_TARGET_TMPDIR"), "/s3s-fs-tests-aws");
 const DOMAIN_NAME: &str = "localhost:8014";
 const REGION: &str = "us-west-2";
 
-// STS AssumeRole route that returns NotImplemented
-struct AssumeRoleRoute;
-
-#[async_trait::async_trait]
-impl s3s::route::S3Route for AssumeRoleRoute {
-    fn is_match(&self, method: &Method, uri: &hyper::Uri, headers: &hyper::HeaderMap, _: &mut hyper::http::Extensions) -> bool {
-        if method == Method::POST
-            && uri.path() == "/"
-            && let Some(val) = headers.get(CONTENT_TYPE)
-            && val.as_bytes() == b"application/x-www-form-urlencoded"
-        {
-            return true;
-        }
-        false
-    }
-
-    async fn call(&self, _req: s3s::S3Request<s3s::Body>) -> s3s::S3Result<s3s::S3Response<s3s::Body>> {
-        debug!("AssumeRole called - returning NotImplemented");
-        Err(s3s::s3_error!(NotImplemented, "STS operations are not supported by s3s-fs"))
-    }
-}
-
 fn setup_tracing() {
     use tracing_subscriber::EnvFilter;
 
@@ -89,9 +66,13 @@ fn config() -> &'static SdkConfig {
         // Setup S3 service
         let service = {
             let mut b = S3ServiceBuilder::new(fs);
-            b.set_auth(SimpleAuth::from_single(cred.access_key_id(), cred.secret_access_key()));
+            let store = SessionStore::new();
+            let base = SimpleAuth::from_single(cred.access_key_id(), cred.secret_access_key());
+            b.set_auth(StsAuth::new(base, store.clone()));
             b.set_host(SingleDomain::new(DOMAIN_NAME).unwrap());
-            b.set_route(AssumeRoleRoute);
+            b.set_route(
+                StsRoute::new(store).with_role_trust(|_, role_arn| role_arn == "arn:aws:iam::123456789012:role/test-role"),
+            );
             b.build()
         };
 
@@ -1733,34 +1714,87 @@ async fn test_multipart_with_attributes() -> Result<()> {
 
 #[tokio::test]
 #[tracing::instrument]
-async fn test_sts_assume_role_not_implemented() -> Result<()> {
+async fn test_sts_assume_role() -> Result<()> {
     let _guard = serial().await;
 
     // Create STS client using the same config as S3
     let sdk_config = config();
     let sts_client = aws_sdk_sts::Client::new(sdk_config);
 
-    // Attempt to call AssumeRole - should fail with NotImplemented
-    let result = sts_client
+    let resp = sts_client
         .assume_role()
         .role_arn("arn:aws:iam::123456789012:role/test-role")
         .role_session_name("test-session")
+        .duration_seconds(900)
         .send()
-        .await;
+        .await?;
 
-    // Verify the operation returned an error
-    assert!(result.is_err(), "Expected AssumeRole to fail with NotImplemented error");
+    let user = resp.assumed_role_user().unwrap();
+    assert_eq!(user.arn(), "arn:aws:sts::123456789012:assumed-role/test-role/test-session");
 
-    // Check that the error is NotImplemented
-    let error = result.unwrap_err();
-    let error_str = format!("{error:?}");
-    debug!("AssumeRole error (expected): {error_str}");
+    let cred = resp.credentials().unwrap();
+    assert!(cred.access_key_id().starts_with("ASIA"));
+    debug!(ak = ?cred.access_key_id(), exp = ?cred.expiration());
 
-    // The error should contain "NotImplemented" or similar indication
-    assert!(
-        error_str.contains("NotImplemented") || error_str.contains("not implemented"),
-        "Expected NotImplemented error, got: {error_str}"
+    // Use the temporary credentials to access S3
+    let temp_cred = Credentials::new(
+        cred.access_key_id(),
+        cred.secret_access_key(),
+        Some(cred.session_token().to_owned()),
+        None,
+        "sts",
+    );
+    let c = Client::new(
+        &sdk_config
+            .to_builder()
+            .credentials_provider(SharedCredentialsProvider::new(temp_cred))
+            .build(),
+    );
+
+    let bucket = format!("test-sts-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+    delete_bucket(&c, bucket).await?;
+
+    // A forged session token must be rejected
+    let forged_cred = Credentials::new(cred.access_key_id(), cred.secret_access_key(), Some("forged".to_owned()), None, "sts");
+    let c = Client::new(
+        &sdk_config
+            .to_builder()
+            .credentials_provider(SharedCredentialsProvider::new(forged_cred))
+            .build(),
+    );
+    let err = c.list_buckets().send().await.unwrap_err();
+    assert_eq!(err.as_service_error().and_then(ProvideErrorMetadata::code), Some("InvalidToken"));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_sts_get_session_token() -> Result<()> {
+    let _guard = serial().await;
+
+    let sdk_config = config();
+    let sts_client = aws_sdk_sts::Client::new(sdk_config);
+
+    let resp = sts_client.get_session_token().send().await?;
+    let cred = resp.credentials().unwrap();
+
+    let temp_cred = Credentials::new(
+        cred.access_key_id(),
+        cred.secret_access_key(),
+        Some(cred.session_token().to_owned()),
+        None,
+        "sts",
+    );
+    let c = Client::new(
+        &sdk_config
+            .to_builder()
+            .credentials_provider(SharedCredentialsProvider::new(temp_cred))
+            .build(),
     );
+    c.list_buckets().send().await?;
 
     Ok(())
 }
@@ -0,0 +1,1179 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Bucket policy access control
+//!
+//! [`BucketPolicyAccess`] authorizes requests with the bucket policies that are stored
+//! by the S3 implementation via `PutBucketPolicy`. It implements both
+//! [`S3Access`] and [`AnonymousAccess`]:
+//!
+//! - A request is denied if a statement of the bucket policy explicitly denies it.
+//! - A request is allowed if a statement of the bucket policy allows it.
+//! - Otherwise, anonymous requests are denied and signed requests are allowed,
+//!   unless [`BucketPolicyAccess::require_explicit_allow`] is set.
+//!
+//! The request is described by a [`RequestContext`] (see [`request_context`]):
+//!
+//! - The action is the IAM action of the operation (see [`action_name`]).
+//! - The resource is the ARN of the bucket or the object (see [`resource_arn`]).
+//! - The principal is an `AWS` principal with the ARN, the account ID and the root ARN
+//!   of the [`Identity`](s3s::auth::Identity), and the access key. Temporary credentials also
+//!   have the identifiers of their [`ParentPrincipal`](s3s::auth::ParentPrincipal), so that
+//!   statements about the parent apply to them.
+//! - The condition keys are taken from the request: `aws:SourceIp` (from the peer
+//!   [`SocketAddr`] in the request extensions), `aws:SecureTransport` (from the
+//!   [`TlsInfo`] in the request extensions), `aws:CurrentTime`,
+//!   `aws:EpochTime`, `aws:UserAgent`, `aws:Referer`, `aws:username`, `aws:userid`,
+//!   `aws:PrincipalAccount`, `aws:PrincipalArn`, `s3:prefix`, `s3:delimiter`, `s3:max-keys`,
+//!   `s3:VersionId` and `s3:x-amz-*` headers such as `s3:x-amz-acl`.
+//!   `s3:ExistingObjectTag/<key>` is fetched with `GetObjectTagging` only if the policy uses it.
+//!   If the tags cannot be fetched, the request fails with the error of `GetObjectTagging`.
+//!
+//! Some operations access more than the object of the request path. These are checked by the
+//! per-operation methods of [`S3Access`], so they require `BucketPolicyAccess` to be configured
+//! via `set_access`:
+//!
+//! - `DeleteObjects` is checked as `s3:DeleteObject` for every key in the request body.
+//! - `CopyObject` and `UploadPartCopy` are checked as `s3:GetObject` on the copy source object.
+//!
+//! Bucket policies are cached per bucket for one minute. Register [`BucketPolicyAccess::invalidation`]
+//! as an interceptor to apply `PutBucketPolicy` and `DeleteBucketPolicy` immediately.
+//! A bucket policy that cannot be parsed denies all requests, except that signed requests
+//! can still get, replace or delete it.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::auth::SimpleAuth;
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::{S3, S3Request, S3Response, S3Result};
+//! use s3s::dto::{GetObjectInput, GetObjectOutput};
+//! use s3s_policy::access::BucketPolicyAccess;
+//!
+//! #[derive(Clone)]
+//! struct MyS3;
+//!
+//! #[async_trait::async_trait]
+//! impl S3 for MyS3 {
+//! #   async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+//! #       Err(s3s::s3_error!(NotImplemented))
+//! #   }
+//!     // Implement S3 operations, including `get_bucket_policy`
+//! }
+//!
+//! let access = BucketPolicyAccess::new(MyS3);
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_auth(SimpleAuth::from_single("ACCESS_KEY", "SECRET_KEY"));
+//! builder.add_interceptor(access.invalidation());
+//! builder.set_anonymous_access(access.clone());
+//! builder.set_access(access);
+//! let service = builder.build();
+//! ```
+
+use crate::eval::{Decision, Evaluator, RequestContext, RequestPrincipal};
+use crate::model::Policy;
+
+use s3s::access::{AnonymousAccess, S3Access, S3AccessContext};
+use s3s::access_log::TlsInfo;
+use s3s::auth::{Credentials, Identity};
+use s3s::dto::{CopyObjectInput, CopySource, DeleteBucketPolicyInput, DeleteObjectsInput, PutBucketPolicyInput};
+use s3s::dto::{GetBucketPolicyInput, GetObjectTaggingInput, UploadPartCopyInput};
+use s3s::intercept::{AfterContext, BeforeContext, S3Interceptor};
+use s3s::path::S3Path;
+use s3s::{S3, S3Error, S3ErrorCode, S3Request, S3Result};
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use hyper::http::Extensions;
+use hyper::{HeaderMap, Method, Uri};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tracing::{debug, warn};
+
+/// The condition key prefix of object tags, in lowercase.
+const EXISTING_OBJECT_TAG: &str = "s3:existingobjecttag/";
+
+/// The request headers that are exposed as `s3:<header>` condition keys.
+const HEADER_KEYS: &[&str] = &[
+    "x-amz-acl",
+    "x-amz-copy-source",
+    "x-amz-grant-full-control",
+    "x-amz-grant-read",
+    "x-amz-grant-read-acp",
+    "x-amz-grant-write",
+    "x-amz-grant-write-acp",
+    "x-amz-metadata-directive",
+    "x-amz-server-side-encryption",
+    "x-amz-server-side-encryption-aws-kms-key-id",
+    "x-amz-storage-class",
+    "x-amz-content-sha256",
+];
+
+/// The global condition keys that are set by [`request_context`].
+const GLOBAL_KEYS: &[&str] = &[
+    "aws:CurrentTime",
+    "aws:EpochTime",
+    "aws:PrincipalAccount",
+    "aws:PrincipalArn",
+    "aws:Referer",
+    "aws:SecureTransport",
+    "aws:SourceIp",
+    "aws:UserAgent",
+    "aws:userid",
+    "aws:username",
+];
+
+/// The S3 condition keys that are set by [`request_context`], besides the headers and object tags.
+const S3_KEYS: &[&str] = &["s3:delimiter", "s3:max-keys", "s3:prefix", "s3:VersionId"];
+
+/// The actions that signed requests can perform on a bucket whose policy cannot be parsed.
+const BUCKET_POLICY_ACTIONS: &[&str] = &["s3:GetBucketPolicy", "s3:PutBucketPolicy", "s3:DeleteBucketPolicy"];
+
+/// How long a bucket policy is cached
+const CACHE_TTL: Duration = Duration::from_mins(1);
+
+/// The maximum number of buckets whose policy is cached
+const CACHE_CAPACITY: usize = 1024;
+
+/// Returns `true` if the condition key can be set by [`request_context`] or [`BucketPolicyAccess`].
+///
+/// Condition keys are case-insensitive.
+#[must_use]
+pub fn is_supported_condition_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    if let Some(tag) = key.strip_prefix(EXISTING_OBJECT_TAG) {
+        return !tag.is_empty();
+    }
+    if let Some(header) = key.strip_prefix("s3:")
+        && HEADER_KEYS.contains(&header)
+    {
+        return true;
+    }
+    GLOBAL_KEYS.iter().chain(S3_KEYS).any(|k| k.eq_ignore_ascii_case(&key))
+}
+
+/// An [`S3Access`] and [`AnonymousAccess`] implementation backed by bucket policies.
+///
+/// Clones share the cached policies.
+#[derive(Clone)]
+pub struct BucketPolicyAccess {
+    s3: Arc<dyn S3>,
+    require_explicit_allow: bool,
+    cache: Arc<PolicyCache>,
+}
+
+/// The policy of a bucket
+#[derive(Clone)]
+enum BucketPolicy {
+    /// The bucket has no policy.
+    None,
+    Valid(Arc<Evaluator>),
+    /// The policy cannot be parsed.
+    Invalid,
+}
+
+impl BucketPolicyAccess {
+    /// Creates an access control that reads bucket policies with `GetBucketPolicy` of `s3`.
+    #[must_use]
+    pub fn new(s3: impl S3) -> Self {
+        Self::from_arc(Arc::new(s3))
+    }
+
+    /// Creates an access control that reads bucket policies with `GetBucketPolicy` of `s3`.
+    #[must_use]
+    pub fn from_arc(s3: Arc<dyn S3>) -> Self {
+        Self {
+            s3,
+            require_explicit_allow: false,
+            cache: Arc::default(),
+        }
+    }
+
+    /// Denies signed requests that are not allowed by the bucket policy.
+    ///
+    /// By default, signed requests are allowed unless the bucket policy denies them,
+    /// since `s3s` has no identity-based policies.
+    #[must_use]
+    pub fn require_explicit_allow(mut self, val: bool) -> Self {
+        self.require_explicit_allow = val;
+        self
+    }
+
+    /// Returns an interceptor that invalidates the cached policy of a bucket
+    /// when it is changed by `PutBucketPolicy` or `DeleteBucketPolicy`.
+    ///
+    /// Register it via [`S3ServiceBuilder::add_interceptor`](s3s::service::S3ServiceBuilder::add_interceptor).
+    /// Otherwise, a changed policy takes effect when the cached one expires.
+    #[must_use]
+    pub fn invalidation(&self) -> PolicyInvalidation {
+        PolicyInvalidation(Arc::clone(&self.cache))
+    }
+
+    async fn authorize(&self, cx: &mut S3AccessContext<'_>) -> S3Result<()> {
+        let anonymous = cx.credentials().is_none();
+        let ctx = request_context(cx);
+        match cx.s3_path() {
+            S3Path::Root => self.implicit(anonymous),
+            S3Path::Bucket { bucket } => self.evaluate(ctx, anonymous, bucket, None).await,
+            S3Path::Object { bucket, key } => self.evaluate(ctx, anonymous, bucket, Some(key)).await,
+        }
+    }
+
+    /// Decides a request that is not matched by any bucket policy.
+    fn implicit(&self, anonymous: bool) -> S3Result<()> {
+        if anonymous || self.require_explicit_allow {
+            Err(S3Error::new(S3ErrorCode::AccessDenied))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Evaluates a request context with the policy of the bucket.
+    ///
+    /// `key` is the object whose tags are used by `s3:ExistingObjectTag` conditions.
+    async fn evaluate(&self, mut ctx: RequestContext, anonymous: bool, bucket: &str, key: Option<&str>) -> S3Result<()> {
+        let evaluator = match self.load_policy(bucket).await? {
+            BucketPolicy::None => return self.implicit(anonymous),
+            BucketPolicy::Valid(evaluator) => evaluator,
+            // A policy that cannot be evaluated denies all requests, but it can still be fixed
+            BucketPolicy::Invalid => {
+                if !anonymous && BUCKET_POLICY_ACTIONS.contains(&ctx.action.as_str()) {
+                    return Ok(());
+                }
+                return Err(S3Error::new(S3ErrorCode::AccessDenied));
+            }
+        };
+
+        if let Some(key) = key
+            && evaluator.condition_keys().any(is_existing_object_tag)
+        {
+            self.add_object_tags(&mut ctx, bucket, key).await?;
+        }
+
+        let evaluation = evaluator.evaluate(&ctx);
+        match evaluation.decision {
+            Decision::Allow => Ok(()),
+            Decision::Deny => {
+                let sids: Vec<_> = evaluation
+                    .deciding_statements()
+                    .map(|s| s.sid.as_deref().unwrap_or(""))
+                    .collect();
+                debug!(action = %ctx.action, resource = %ctx.resource, ?sids, "denied by bucket policy");
+                Err(S3Error::new(S3ErrorCode::AccessDenied))
+            }
+            Decision::NoMatch => self.implicit(anonymous),
+        }
+    }
+
+    /// Checks that the request can read the source object of a copy.
+    async fn authorize_copy_source<T: Sync>(&self, req: &S3Request<T>, source: &CopySource) -> S3Result<()> {
+        let anonymous = req.credentials.is_none();
+        // Access points and outposts have no bucket policy here
+        let CopySource::Bucket { bucket, key, version_id } = source else {
+            return self.implicit(anonymous);
+        };
+
+        let mut ctx = s3_request_context(req, "GetObject", &S3Path::object(bucket, key));
+        ctx.action = action_name("GetObject", version_id.is_some());
+        if let Some(version_id) = version_id {
+            ctx.set_condition_key("s3:VersionId", [&**version_id]);
+        }
+        self.evaluate(ctx, anonymous, bucket, Some(key)).await
+    }
+
+    /// Returns the policy of the bucket.
+    async fn load_policy(&self, bucket: &str) -> S3Result<BucketPolicy> {
+        if let Some(policy) = self.cache.get(bucket, Instant::now()) {
+            return Ok(policy);
+        }
+        let policy = self.fetch_policy(bucket).await?;
+        self.cache.insert(bucket, policy.clone(), Instant::now());
+        Ok(policy)
+    }
+
+    async fn fetch_policy(&self, bucket: &str) -> S3Result<BucketPolicy> {
+        let input = GetBucketPolicyInput::builder()
+            .bucket(bucket.to_owned())
+            .build()
+            .map_err(S3Error::internal_error)?;
+        let req = internal_request(input, &format!("/{bucket}?policy"))?;
+
+        let text = match self.s3.get_bucket_policy(req).await {
+            Ok(resp) => match resp.output.policy {
+                Some(text) => text,
+                None => return Ok(BucketPolicy::None),
+            },
+            Err(e) => match e.code() {
+                S3ErrorCode::NoSuchBucketPolicy | S3ErrorCode::NoSuchBucket | S3ErrorCode::NotImplemented => {
+                    return Ok(BucketPolicy::None);
+                }
+                _ => return Err(e),
+            },
+        };
+
+        match compile(&text) {
+            Ok(evaluator) => Ok(BucketPolicy::Valid(Arc::new(evaluator))),
+            Err(err) => {
+                warn!(bucket, %err, "invalid bucket policy");
+                Ok(BucketPolicy::Invalid)
+            }
+        }
+    }
+
+    /// Sets the `s3:ExistingObjectTag/<key>` condition keys of the object.
+    ///
+    /// An object that does not exist has no tags. Other errors are returned,
+    /// since a deny conditioned on the tags could not be applied.
+    async fn add_object_tags(&self, ctx: &mut RequestContext, bucket: &str, key: &str) -> S3Result<()> {
+        let input = GetObjectTaggingInput::builder()
+            .bucket(bucket.to_owned())
+            .key(key.to_owned())
+            .build()
+            .map_err(S3Error::internal_error)?;
+        let req = internal_request(input, &format!("/{bucket}/{}?tagging", urlencoding::encode(key)))?;
+
+        match self.s3.get_object_tagging(req).await {
+            Ok(resp) => {
+                for tag in resp.output.tag_set {
+                    if let (Some(k), Some(v)) = (tag.key, tag.value) {
+                        ctx.set_condition_key(&format!("s3:ExistingObjectTag/{k}"), [v]);
+                    }
+                }
+                Ok(())
+            }
+            Err(err) => match err.code() {
+                S3ErrorCode::NoSuchKey | S3ErrorCode::NoSuchVersion => Ok(()),
+                _ => {
+                    warn!(bucket, key, ?err, "failed to get object tags");
+                    Err(err)
+                }
+            },
+        }
+    }
+}
+
+#[derive(Default)]
+struct PolicyCache {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+struct CacheEntry {
+    policy: BucketPolicy,
+    expires_at: Instant,
+}
+
+impl PolicyCache {
+    fn entries(&self) -> MutexGuard<'_, HashMap<String, CacheEntry>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get(&self, bucket: &str, now: Instant) -> Option<BucketPolicy> {
+        let entries = self.entries();
+        entries.get(bucket).filter(|e| now < e.expires_at).map(|e| e.policy.clone())
+    }
+
+    fn insert(&self, bucket: &str, policy: BucketPolicy, now: Instant) {
+        let mut entries = self.entries();
+        if entries.len() >= CACHE_CAPACITY && !entries.contains_key(bucket) {
+            entries.retain(|_, e| now < e.expires_at);
+            if entries.len() >= CACHE_CAPACITY {
+                let oldest = entries.iter().min_by_key(|(_, e)| e.expires_at).map(|(b, _)| b.clone());
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        let expires_at = now + CACHE_TTL;
+        entries.insert(bucket.to_owned(), CacheEntry { policy, expires_at });
+    }
+
+    fn remove(&self, bucket: &str) {
+        self.entries().remove(bucket);
+    }
+}
+
+/// An interceptor that invalidates the cached policy of a bucket that is changed through the service.
+///
+/// See [`BucketPolicyAccess::invalidation`].
+pub struct PolicyInvalidation(Arc<PolicyCache>);
+
+/// The bucket whose policy is being changed
+#[derive(Clone)]
+struct ChangedBucket(String);
+
+#[async_trait::async_trait]
+impl S3Interceptor for PolicyInvalidation {
+    async fn before(&self, cx: &mut BeforeContext<'_>) -> S3Result<()> {
+        let bucket = match (cx.input::<PutBucketPolicyInput>(), cx.input::<DeleteBucketPolicyInput>()) {
+            (Some(input), _) => input.bucket.clone(),
+            (_, Some(input)) => input.bucket.clone(),
+            _ => return Ok(()),
+        };
+        cx.state_mut().insert(ChangedBucket(bucket));
+        Ok(())
+    }
+
+    async fn after(&self, cx: &mut AfterContext<'_>) {
+        if let Some(ChangedBucket(bucket)) = cx.state().get() {
+            self.0.remove(bucket);
+        }
+    }
+}
+
+fn compile(text: &str) -> Result<Evaluator, Box<dyn std::error::Error>> {
+    let policy: Policy = serde_json::from_str(text)?;
+    Ok(Evaluator::new(&policy)?)
+}
+
+fn is_existing_object_tag(key: &str) -> bool {
+    key.len() > EXISTING_OBJECT_TAG.len() && key[..EXISTING_OBJECT_TAG.len()].eq_ignore_ascii_case(EXISTING_OBJECT_TAG)
+}
+
+/// Creates an internal request without credentials.
+fn internal_request<T>(input: T, uri: &str) -> S3Result<S3Request<T>> {
+    Ok(S3Request {
+        input,
+        method: Method::GET,
+        uri: Uri::try_from(uri).map_err(S3Error::internal_error)?,
+        headers: HeaderMap::new(),
+        extensions: Extensions::new(),
+        credentials: None,
+        region: None,
+        service: None,
+        trailing_headers: None,
+    })
+}
+
+#[async_trait::async_trait]
+impl S3Access for BucketPolicyAccess {
+    async fn check(&self, cx: &mut S3AccessContext<'_>) -> S3Result<()> {
+        self.authorize(cx).await
+    }
+
+    async fn copy_object(&self, req: &mut S3Request<CopyObjectInput>) -> S3Result<()> {
+        self.authorize_copy_source(req, &req.input.copy_source).await
+    }
+
+    async fn delete_objects(&self, req: &mut S3Request<DeleteObjectsInput>) -> S3Result<()> {
+        let anonymous = req.credentials.is_none();
+        let bucket = &req.input.bucket;
+        let base = s3_request_context(req, "DeleteObject", &S3Path::bucket(bucket));
+        for object in &req.input.delete.objects {
+            let mut ctx = base.clone();
+            ctx.action = action_name("DeleteObject", object.version_id.is_some());
+            ctx.resource = resource_arn(&S3Path::object(bucket, &object.key));
+            if let Some(version_id) = &object.version_id {
+                ctx.set_condition_key("s3:VersionId", [version_id.as_str()]);
+            }
+            self.evaluate(ctx, anonymous, bucket, Some(&object.key)).await?;
+        }
+        Ok(())
+    }
+
+    async fn upload_part_copy(&self, req: &mut S3Request<UploadPartCopyInput>) -> S3Result<()> {
+        self.authorize_copy_source(req, &req.input.copy_source).await
+    }
+}
+
+#[async_trait::async_trait]
+impl AnonymousAccess for BucketPolicyAccess {
+    async fn check(&self, cx: &mut S3AccessContext<'_>) -> S3Result<()> {
+        self.authorize(cx).await
+    }
+}
+
+/// Returns the IAM action of an S3 operation, such as `s3:ListBucket` for `ListObjectsV2`.
+///
+/// Object operations on a specific version use the version actions,
+/// such as `s3:GetObjectVersion` for `GetObject` with a `versionId`.
+#[must_use]
+pub fn action_name(op: &str, has_version_id: bool) -> String {
+    let versioned = match op {
+        "GetObject" | "HeadObject" => Some("s3:GetObjectVersion"),
+        "DeleteObject" => Some("s3:DeleteObjectVersion"),
+        "GetObjectAcl" => Some("s3:GetObjectVersionAcl"),
+        "PutObjectAcl" => Some("s3:PutObjectVersionAcl"),
+        "GetObjectTagging" => Some("s3:GetObjectVersionTagging"),
+        "PutObjectTagging" => Some("s3:PutObjectVersionTagging"),
+        "DeleteObjectTagging" => Some("s3:DeleteObjectVersionTagging"),
+        "GetObjectAttributes" => Some("s3:GetObjectVersionAttributes"),
+        _ => None,
+    };
+    if has_version_id && let Some(action) = versioned {
+        return action.to_owned();
+    }
+
+    let action = match op {
+        "ListBuckets" => "s3:ListAllMyBuckets",
+        "HeadBucket" | "ListObjects" | "ListObjectsV2" => "s3:ListBucket",
+        "ListObjectVersions" => "s3:ListBucketVersions",
+        "ListMultipartUploads" => "s3:ListBucketMultipartUploads",
+        "ListParts" => "s3:ListMultipartUploadParts",
+        "HeadObject" | "SelectObjectContent" => "s3:GetObject",
+        "CopyObject" | "PostObject" | "CreateMultipartUpload" | "UploadPart" | "UploadPartCopy" | "CompleteMultipartUpload" => {
+            "s3:PutObject"
+        }
+        "DeleteObjects" => "s3:DeleteObject",
+        "GetBucketCors" => "s3:GetBucketCORS",
+        "PutBucketCors" | "DeleteBucketCors" => "s3:PutBucketCORS",
+        "GetBucketLifecycle" | "GetBucketLifecycleConfiguration" => "s3:GetLifecycleConfiguration",
+        "PutBucketLifecycle" | "PutBucketLifecycleConfiguration" | "DeleteBucketLifecycle" => "s3:PutLifecycleConfiguration",
+        "GetBucketEncryption" => "s3:GetEncryptionConfiguration",
+        "PutBucketEncryption" | "DeleteBucketEncryption" => "s3:PutEncryptionConfiguration",
+        "GetBucketReplication" => "s3:GetReplicationConfiguration",
+        "PutBucketReplication" | "DeleteBucketReplication" => "s3:PutReplicationConfiguration",
+        "GetBucketNotification" | "GetBucketNotificationConfiguration" => "s3:GetBucketNotification",
+        "PutBucketNotification" | "PutBucketNotificationConfiguration" => "s3:PutBucketNotification",
+        "GetBucketAnalyticsConfiguration" | "ListBucketAnalyticsConfigurations" => "s3:GetAnalyticsConfiguration",
+        "PutBucketAnalyticsConfiguration" | "DeleteBucketAnalyticsConfiguration" => "s3:PutAnalyticsConfiguration",
+        "GetBucketInventoryConfiguration" | "ListBucketInventoryConfigurations" => "s3:GetInventoryConfiguration",
+        "PutBucketInventoryConfiguration" | "DeleteBucketInventoryConfiguration" => "s3:PutInventoryConfiguration",
+        "GetBucketMetricsConfiguration" | "ListBucketMetricsConfigurations" => "s3:GetMetricsConfiguration",
+        "PutBucketMetricsConfiguration" | "DeleteBucketMetricsConfiguration" => "s3:PutMetricsConfiguration",
+        "GetBucketIntelligentTieringConfiguration" | "ListBucketIntelligentTieringConfigurations" => {
+            "s3:GetIntelligentTieringConfiguration"
+        }
+        "PutBucketIntelligentTieringConfiguration" | "DeleteBucketIntelligentTieringConfiguration" => {
+            "s3:PutIntelligentTieringConfiguration"
+        }
+        "DeleteBucketOwnershipControls" => "s3:PutBucketOwnershipControls",
+        "DeleteBucketTagging" => "s3:PutBucketTagging",
+        "GetBucketAccelerateConfiguration" => "s3:GetAccelerateConfiguration",
+        "PutBucketAccelerateConfiguration" => "s3:PutAccelerateConfiguration",
+        "GetObjectLockConfiguration" => "s3:GetBucketObjectLockConfiguration",
+        "PutObjectLockConfiguration" => "s3:PutBucketObjectLockConfiguration",
+        "GetPublicAccessBlock" => "s3:GetBucketPublicAccessBlock",
+        "PutPublicAccessBlock" | "DeletePublicAccessBlock" => "s3:PutBucketPublicAccessBlock",
+        "WriteGetObjectResponse" => "s3-object-lambda:WriteGetObjectResponse",
+        "CreateSession" => "s3express:CreateSession",
+        "ListDirectoryBuckets" => "s3express:ListAllMyDirectoryBuckets",
+        _ => return format!("s3:{op}"),
+    };
+    action.to_owned()
+}
+
+/// Returns the resource ARN of an S3 path.
+///
+/// The root path has the resource `*`.
+#[must_use]
+pub fn resource_arn(path: &S3Path) -> String {
+    match path {
+        S3Path::Root => "*".to_owned(),
+        S3Path::Bucket { bucket } => format!("arn:aws:s3:::{bucket}"),
+        S3Path::Object { bucket, key } => format!("arn:aws:s3:::{bucket}/{key}"),
+    }
+}
+
+/// Builds the policy evaluation context of a request.
+#[must_use]
+pub fn request_context(cx: &mut S3AccessContext<'_>) -> RequestContext {
+    let extensions = cx.extensions_mut();
+    let tls = extensions.get::<TlsInfo>().is_some();
+    let source = extensions.get::<SocketAddr>().copied();
+    build_context(&RequestParts {
+        op: cx.s3_op().name(),
+        path: cx.s3_path(),
+        credentials: cx.credentials(),
+        uri: cx.uri(),
+        headers: cx.headers(),
+        tls,
+        source,
+    })
+}
+
+/// Builds the policy evaluation context of an operation request, as if it was for `op` on `path`.
+fn s3_request_context<T>(req: &S3Request<T>, op: &str, path: &S3Path) -> RequestContext {
+    build_context(&RequestParts {
+        op,
+        path,
+        credentials: req.credentials.as_ref(),
+        uri: &req.uri,
+        headers: &req.headers,
+        tls: req.extensions.get::<TlsInfo>().is_some(),
+        source: req.extensions.get::<SocketAddr>().copied(),
+    })
+}
+
+/// The parts of a request that are described by its [`RequestContext`]
+struct RequestParts<'a> {
+    op: &'a str,
+    path: &'a S3Path,
+    credentials: Option<&'a Credentials>,
+    uri: &'a Uri,
+    headers: &'a HeaderMap,
+    tls: bool,
+    source: Option<SocketAddr>,
+}
+
+fn build_context(parts: &RequestParts<'_>) -> RequestContext {
+    let query: Vec<(String, String)> = parts
+        .uri
+        .query()
+        .map(|q| {
+            q.split('&')
+                .filter(|s| !s.is_empty())
+                .map(|s| {
+                    let (k, v) = s.split_once('=').unwrap_or((s, ""));
+                    let decode = |s: &str| urlencoding::decode(s).map_or_else(|_| s.to_owned(), std::borrow::Cow::into_owned);
+                    (decode(k), decode(v))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let query_value = |name: &str| query.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone());
+
+    let has_version_id = query_value("versionId").is_some();
+    let action = action_name(parts.op, has_version_id);
+    let mut ctx = RequestContext::new(action, resource_arn(parts.path));
+
+    if let Some(creds) = parts.credentials {
+        let mut ids = Vec::new();
+        let identity = creds.identity.as_deref();
+        push_principal_ids(&mut ids, &creds.access_key, identity);
+        if let Some(parent) = identity.and_then(|i| i.parent.as_deref()) {
+            push_principal_ids(&mut ids, &parent.access_key, parent.identity.as_ref());
+        }
+        ctx.principal = Some(RequestPrincipal::aws(ids));
+
+        if let Some(identity) = identity {
+            let keys = [
+                ("aws:username", &identity.username),
+                ("aws:userid", &identity.user_id),
+                ("aws:PrincipalAccount", &identity.account_id),
+                ("aws:PrincipalArn", &identity.arn),
+            ];
+            for (key, value) in keys {
+                if let Some(value) = value {
+                    ctx.set_condition_key(key, [value.as_str()]);
+                }
+            }
+        }
+    }
+
+    for name in ["prefix", "delimiter", "max-keys"] {
+        if let Some(value) = query_value(name) {
+            ctx.set_condition_key(&format!("s3:{name}"), [value]);
+        }
+    }
+    if let Some(version_id) = query_value("versionId") {
+        ctx.set_condition_key("s3:VersionId", [version_id]);
+    }
+
+    let headers = parts.headers;
+    for name in HEADER_KEYS {
+        if let Some(value) = headers.get(*name).and_then(|v| v.to_str().ok()) {
+            ctx.set_condition_key(&format!("s3:{name}"), [value]);
+        }
+    }
+    for (key, name) in [
+        ("aws:UserAgent", hyper::header::USER_AGENT),
+        ("aws:Referer", hyper::header::REFERER),
+    ] {
+        if let Some(value) = headers.get(name).and_then(|v| v.to_str().ok()) {
+            ctx.set_condition_key(key, [value]);
+        }
+    }
+
+    let now = OffsetDateTime::now_utc();
+    if let Ok(time) = now.format(&Rfc3339) {
+        ctx.set_condition_key("aws:CurrentTime", [time]);
+    }
+    ctx.set_condition_key("aws:EpochTime", [now.unix_timestamp().to_string()]);
+
+    // The scheme of the request target is chosen by the client, so only the TLS connection counts
+    ctx.set_condition_key("aws:SecureTransport", [if parts.tls { "true" } else { "false" }]);
+    if let Some(addr) = parts.source {
+        ctx.set_condition_key("aws:SourceIp", [addr.ip().to_canonical().to_string()]);
+    }
+
+    ctx
+}
+
+/// Pushes the principal identifiers of an access key and its identity.
+fn push_principal_ids(ids: &mut Vec<String>, access_key: &str, identity: Option<&Identity>) {
+    if let Some(identity) = identity {
+        ids.extend(identity.arn.clone());
+        if let Some(account_id) = &identity.account_id {
+            ids.push(account_id.clone());
+            ids.push(format!("arn:aws:iam::{account_id}:root"));
+        }
+    }
+    ids.push(access_key.to_owned());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use s3s::auth::SimpleAuth;
+    use s3s::dto::{CopyObjectOutput, DeleteBucketPolicyOutput, DeleteObjectsOutput, PutBucketPolicyOutput};
+    use s3s::dto::{GetBucketPolicyOutput, GetObjectInput, GetObjectOutput, GetObjectTaggingOutput, Tag};
+    use s3s::service::{S3Service, S3ServiceBuilder};
+    use s3s::sign::{SignedPayload, Signer};
+    use s3s::{Body, S3Response};
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn condition_keys() {
+        let supported = [
+            "aws:SourceIp",
+            "AWS:USERNAME",
+            "s3:prefix",
+            "s3:x-amz-acl",
+            "s3:ExistingObjectTag/team",
+        ];
+        for key in supported {
+            assert!(is_supported_condition_key(key), "{key}");
+        }
+        let unsupported = [
+            "aws:PrincipalTag/team",
+            "s3:ExistingObjectTag/",
+            "s3:x-amz-meta-foo",
+            "prefix",
+        ];
+        for key in unsupported {
+            assert!(!is_supported_condition_key(key), "{key}");
+        }
+    }
+
+    #[test]
+    fn actions() {
+        let cases = [
+            ("GetObject", false, "s3:GetObject"),
+            ("GetObject", true, "s3:GetObjectVersion"),
+            ("HeadObject", false, "s3:GetObject"),
+            ("ListObjectsV2", true, "s3:ListBucket"),
+            ("ListBuckets", false, "s3:ListAllMyBuckets"),
+            ("UploadPart", false, "s3:PutObject"),
+            ("DeleteBucketCors", false, "s3:PutBucketCORS"),
+            ("PutBucketPolicy", false, "s3:PutBucketPolicy"),
+        ];
+        for (op, has_version_id, expected) in cases {
+            assert_eq!(action_name(op, has_version_id), expected, "{op}");
+        }
+
+        assert_eq!(resource_arn(&S3Path::object("bucket", "a/b")), "arn:aws:s3:::bucket/a/b");
+        assert_eq!(resource_arn(&S3Path::bucket("bucket")), "arn:aws:s3:::bucket");
+        assert_eq!(resource_arn(&S3Path::root()), "*");
+    }
+
+    #[derive(Clone)]
+    struct PolicyS3;
+
+    const POLICY: &str = r#"{
+        "Version": "2012-10-17",
+        "Statement": [
+            {
+                "Sid": "PublicRead",
+                "Effect": "Allow",
+                "Principal": "*",
+                "Action": "s3:GetObject",
+                "Resource": "arn:aws:s3:::bucket/public/*"
+            },
+            {
+                "Sid": "DenyConfidential",
+                "Effect": "Deny",
+                "Principal": "*",
+                "Action": "s3:GetObject",
+                "Resource": "arn:aws:s3:::bucket/*",
+                "Condition": {"StringEquals": {"s3:ExistingObjectTag/classification": "confidential"}}
+            },
+            {
+                "Sid": "ProtectDelete",
+                "Effect": "Deny",
+                "Principal": "*",
+                "Action": "s3:DeleteObject",
+                "Resource": "arn:aws:s3:::bucket/protected/*"
+            }
+        ]
+    }"#;
+
+    #[async_trait::async_trait]
+    impl S3 for PolicyS3 {
+        async fn get_bucket_policy(
+            &self,
+            req: S3Request<GetBucketPolicyInput>,
+        ) -> S3Result<s3s::S3Response<GetBucketPolicyOutput>> {
+            if req.input.bucket != "bucket" {
+                return Err(s3s::s3_error!(NoSuchBucketPolicy));
+            }
+            Ok(S3Response::new(GetBucketPolicyOutput {
+                policy: Some(POLICY.to_owned()),
+            }))
+        }
+
+        async fn get_object_tagging(
+            &self,
+            req: S3Request<GetObjectTaggingInput>,
+        ) -> S3Result<S3Response<GetObjectTaggingOutput>> {
+            if req.input.key.contains("missing") {
+                return Err(s3s::s3_error!(NoSuchKey));
+            }
+            if req.input.key.contains("broken") {
+                return Err(s3s::s3_error!(InternalError));
+            }
+            let classification = if req.input.key.contains("secret") {
+                "confidential"
+            } else {
+                "public"
+            };
+            Ok(S3Response::new(GetObjectTaggingOutput {
+                tag_set: vec![Tag {
+                    key: Some("classification".to_owned()),
+                    value: Some(classification.to_owned()),
+                }],
+                version_id: None,
+            }))
+        }
+
+        async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+            Ok(S3Response::new(GetObjectOutput::default()))
+        }
+
+        async fn copy_object(&self, _req: S3Request<CopyObjectInput>) -> S3Result<S3Response<CopyObjectOutput>> {
+            Ok(S3Response::new(CopyObjectOutput::default()))
+        }
+
+        async fn delete_objects(&self, _req: S3Request<DeleteObjectsInput>) -> S3Result<S3Response<DeleteObjectsOutput>> {
+            Ok(S3Response::new(DeleteObjectsOutput::default()))
+        }
+    }
+
+    fn service() -> S3Service {
+        let mut builder = S3ServiceBuilder::new(PolicyS3);
+        builder.set_auth(SimpleAuth::from_single("AKIDEXAMPLE", "SECRET"));
+        builder.set_access(BucketPolicyAccess::new(PolicyS3));
+        builder.set_anonymous_access(BucketPolicyAccess::new(PolicyS3));
+        builder.build()
+    }
+
+    async fn send(service: &S3Service, mut req: hyper::Request<Body>, authenticated: bool) -> hyper::StatusCode {
+        if authenticated {
+            let signer = Signer::new("AKIDEXAMPLE", "SECRET", "us-east-1");
+            signer.sign(&mut req, SignedPayload::Unsigned).unwrap();
+        }
+        service.call(req).await.unwrap().status()
+    }
+
+    async fn get_object(service: &S3Service, path: &str, authenticated: bool) -> hyper::StatusCode {
+        let req = hyper::Request::builder()
+            .method(Method::GET)
+            .uri(format!("http://localhost{path}"))
+            .body(Body::empty())
+            .unwrap();
+        send(service, req, authenticated).await
+    }
+
+    #[tokio::test]
+    async fn bucket_policy() {
+        let service = service();
+
+        let cases = [
+            ("/bucket/public/a.txt", false, 200),
+            ("/bucket/public/secret.txt", false, 403),
+            ("/bucket/private/a.txt", false, 403),
+            ("/bucket/private/a.txt", true, 200),
+            ("/bucket/private/secret.txt", true, 403),
+            ("/bucket/private/missing.txt", true, 200),
+            // the tags of the object cannot be loaded
+            ("/bucket/public/broken.txt", false, 500),
+            ("/bucket/private/broken.txt", true, 500),
+            ("/other/a.txt", false, 403),
+            ("/other/a.txt", true, 200),
+        ];
+        for (path, signed, expected) in cases {
+            assert_eq!(get_object(&service, path, signed).await.as_u16(), expected, "{path} {signed}");
+        }
+    }
+
+    #[tokio::test]
+    async fn require_explicit_allow() {
+        let mut builder = S3ServiceBuilder::new(PolicyS3);
+        builder.set_auth(SimpleAuth::from_single("AKIDEXAMPLE", "SECRET"));
+        builder.set_access(BucketPolicyAccess::new(PolicyS3).require_explicit_allow(true));
+        let service = builder.build();
+
+        assert_eq!(get_object(&service, "/bucket/public/a.txt", true).await.as_u16(), 200);
+        assert_eq!(get_object(&service, "/bucket/private/a.txt", true).await.as_u16(), 403);
+    }
+
+    #[tokio::test]
+    async fn delete_objects() {
+        let service = service();
+
+        let delete_objects = |keys: &[&str]| {
+            let mut body = String::from("<Delete>");
+            for key in keys {
+                body.push_str("<Object><Key>");
+                body.push_str(key);
+                body.push_str("</Key></Object>");
+            }
+            body.push_str("</Delete>");
+            hyper::Request::builder()
+                .method(Method::POST)
+                .uri("http://localhost/bucket?delete")
+                .body(Body::from(body))
+                .unwrap()
+        };
+
+        assert_eq!(send(&service, delete_objects(&["a.txt", "b.txt"]), true).await.as_u16(), 200);
+        assert_eq!(
+            send(&service, delete_objects(&["a.txt", "protected/b.txt"]), true)
+                .await
+                .as_u16(),
+            403
+        );
+    }
+
+    #[tokio::test]
+    async fn copy_source() {
+        let service = service();
+
+        let copy_object = |source: &str| {
+            hyper::Request::builder()
+                .method(Method::PUT)
+                .uri("http://localhost/bucket/public/copy.txt")
+                .header("x-amz-copy-source", source)
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        assert_eq!(send(&service, copy_object("bucket/private/a.txt"), true).await.as_u16(), 200);
+        assert_eq!(send(&service, copy_object("bucket/private/secret.txt"), true).await.as_u16(), 403);
+    }
+
+    /// Stores the bucket policy of `bucket` and counts how many times it is read.
+    #[derive(Clone, Default)]
+    struct StoredPolicyS3 {
+        policy: Arc<Mutex<Option<String>>>,
+        fetches: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl S3 for StoredPolicyS3 {
+        async fn get_bucket_policy(&self, _req: S3Request<GetBucketPolicyInput>) -> S3Result<S3Response<GetBucketPolicyOutput>> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            let policy = self.policy.lock().unwrap().clone();
+            match policy {
+                Some(policy) => Ok(S3Response::new(GetBucketPolicyOutput { policy: Some(policy) })),
+                None => Err(s3s::s3_error!(NoSuchBucketPolicy)),
+            }
+        }
+
+        async fn put_bucket_policy(&self, req: S3Request<PutBucketPolicyInput>) -> S3Result<S3Response<PutBucketPolicyOutput>> {
+            *self.policy.lock().unwrap() = Some(req.input.policy);
+            Ok(S3Response::new(PutBucketPolicyOutput::default()))
+        }
+
+        async fn delete_bucket_policy(
+            &self,
+            _req: S3Request<DeleteBucketPolicyInput>,
+        ) -> S3Result<S3Response<DeleteBucketPolicyOutput>> {
+            *self.policy.lock().unwrap() = None;
+            Ok(S3Response::new(DeleteBucketPolicyOutput::default()))
+        }
+
+        async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+            Ok(S3Response::new(GetObjectOutput::default()))
+        }
+    }
+
+    #[tokio::test]
+    async fn policy_cache() {
+        let s3 = StoredPolicyS3::default();
+        *s3.policy.lock().unwrap() = Some("{".to_owned());
+
+        let access = BucketPolicyAccess::new(s3.clone());
+        let mut builder = S3ServiceBuilder::new(s3.clone());
+        builder.set_auth(SimpleAuth::from_single("AKIDEXAMPLE", "SECRET"));
+        builder.add_interceptor(access.invalidation());
+        builder.set_anonymous_access(access.clone());
+        builder.set_access(access);
+        let service = builder.build();
+
+        let put_bucket_policy = || {
+            let policy = r#"{
+                "Version": "2012-10-17",
+                "Statement": [{
+                    "Effect": "Deny",
+                    "Principal": "*",
+                    "Action": "s3:GetObject",
+                    "Resource": "arn:aws:s3:::bucket/private/*"
+                }]
+            }"#;
+            hyper::Request::builder()
+                .method(Method::PUT)
+                .uri("http://localhost/bucket?policy")
+                .body(Body::from(policy.to_owned()))
+                .unwrap()
+        };
+
+        // An invalid policy denies everything but fixing it
+        assert_eq!(get_object(&service, "/bucket/a.txt", true).await.as_u16(), 403);
+        assert_eq!(get_object(&service, "/bucket/a.txt", true).await.as_u16(), 403);
+        assert_eq!(s3.fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(send(&service, put_bucket_policy(), false).await.as_u16(), 403);
+        assert!(send(&service, put_bucket_policy(), true).await.is_success());
+
+        // The new policy applies immediately, and is read once
+        assert_eq!(get_object(&service, "/bucket/a.txt", true).await.as_u16(), 200);
+        assert_eq!(get_object(&service, "/bucket/private/a.txt", true).await.as_u16(), 403);
+        assert_eq!(s3.fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn secure_transport() {
+        let s3 = StoredPolicyS3::default();
+        *s3.policy.lock().unwrap() = Some(
+            r#"{
+                "Version": "2012-10-17",
+                "Statement": [{
+                    "Effect": "Deny",
+                    "Principal": "*",
+                    "Action": "s3:*",
+                    "Resource": "arn:aws:s3:::bucket/*",
+                    "Condition": {"Bool": {"aws:SecureTransport": "false"}}
+                }]
+            }"#
+            .to_owned(),
+        );
+
+        let mut builder = S3ServiceBuilder::new(s3.clone());
+        builder.set_auth(SimpleAuth::from_single("AKIDEXAMPLE", "SECRET"));
+        builder.set_access(BucketPolicyAccess::new(s3));
+        let service = builder.build();
+
+        let get = |tls: bool| {
+            let mut req = hyper::Request::builder()
+                .method(Method::GET)
+                .uri("https://localhost/bucket/a.txt")
+                .body(Body::empty())
+                .unwrap();
+            if tls {
+                req.extensions_mut().insert(TlsInfo {
+                    cipher_suite: "TLS_AES_128_GCM_SHA256".to_owned(),
+                    version: "TLSv1.3".to_owned(),
+                });
+            }
+            req
+        };
+
+        // an `https` request target does not make a plaintext request secure
+        assert_eq!(send(&service, get(false), true).await.as_u16(), 403);
+        assert_eq!(send(&service, get(true), true).await.as_u16(), 200);
+    }
+
+    /// Owns `AKIDEXAMPLE` in the account `111111111111`.
+    struct AccountAuth;
+
+    #[async_trait::async_trait]
+    impl s3s::auth::S3Auth for AccountAuth {
+        async fn get_secret_key(&self, access_key: &str) -> S3Result<s3s::auth::SecretKey> {
+            self.get_access_key_info(access_key, None).await.map(|info| info.secret_key)
+        }
+
+        async fn get_access_key_info(&self, access_key: &str, _: Option<&str>) -> S3Result<s3s::auth::AccessKeyInfo> {
+            if access_key != "AKIDEXAMPLE" {
+                return Err(s3s::s3_error!(InvalidAccessKeyId));
+            }
+            Ok(s3s::auth::AccessKeyInfo {
+                secret_key: "SECRET".into(),
+                identity: Some(Identity {
+                    arn: Some("arn:aws:iam::111111111111:user/alice".to_owned()),
+                    account_id: Some("111111111111".to_owned()),
+                    ..Default::default()
+                }),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn assumed_role() {
+        use s3s::sts::{SessionStore, StsAuth, StsRoute};
+
+        let s3 = StoredPolicyS3::default();
+        *s3.policy.lock().unwrap() = Some(
+            r#"{
+                "Version": "2012-10-17",
+                "Statement": [
+                    {
+                        "Effect": "Allow",
+                        "Principal": {"AWS": "111111111111"},
+                        "Action": "s3:GetObject",
+                        "Resource": "arn:aws:s3:::bucket/own/*"
+                    },
+                    {
+                        "Effect": "Allow",
+                        "Principal": {"AWS": "222222222222"},
+                        "Action": "s3:GetObject",
+                        "Resource": "arn:aws:s3:::bucket/shared/*"
+                    },
+                    {
+                        "Effect": "Deny",
+                        "Principal": {"AWS": ["AKIDEXAMPLE", "arn:aws:iam::111111111111:user/alice"]},
+                        "Action": "s3:GetObject",
+                        "Resource": "arn:aws:s3:::bucket/own/denied/*"
+                    }
+                ]
+            }"#
+            .to_owned(),
+        );
+
+        let store = SessionStore::new();
+        let mut builder = S3ServiceBuilder::new(s3.clone());
+        builder.set_auth(StsAuth::new(AccountAuth, store.clone()));
+        builder.set_route(StsRoute::new(store).with_role_trust(|_, _| true));
+        builder.set_access(BucketPolicyAccess::new(s3).require_explicit_allow(true));
+        let service = builder.build();
+
+        let assume_role = |account: &str| {
+            let body = format!("Action=AssumeRole&RoleArn=arn%3Aaws%3Aiam%3A%3A{account}%3Arole%2Fexample&RoleSessionName=test");
+            let mut req = hyper::Request::builder()
+                .method(Method::POST)
+                .uri("http://localhost/")
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from(body.clone()))
+                .unwrap();
+            let mut signer = Signer::new("AKIDEXAMPLE", "SECRET", "us-east-1");
+            signer.set_service("sts");
+            signer.sign(&mut req, SignedPayload::Bytes(body.as_bytes())).unwrap();
+            service.call(req)
+        };
+
+        // a role in another account cannot be assumed
+        let resp = assume_role("222222222222").await.unwrap();
+        assert_eq!(resp.status().as_u16(), 403);
+
+        let resp = assume_role("111111111111").await.unwrap();
+        assert_eq!(resp.status().as_u16(), 200);
+        let xml = resp.into_body().store_all_limited(usize::MAX).await.unwrap();
+        let xml = std::str::from_utf8(&xml).unwrap();
+        let field = |name: &str| {
+            let value = xml.split(&format!("<{name}>")).nth(1).unwrap();
+            value.split('<').next().unwrap().to_owned()
+        };
+
+        let mut session = Signer::new(field("AccessKeyId"), field("SecretAccessKey"), "us-east-1");
+        session.set_session_token(field("SessionToken"));
+        let get = |path: &str| {
+            let mut req = hyper::Request::builder()
+                .method(Method::GET)
+                .uri(format!("http://localhost{path}"))
+                .body(Body::empty())
+                .unwrap();
+            session.sign(&mut req, SignedPayload::Unsigned).unwrap();
+            service.call(req)
+        };
+
+        assert_eq!(get("/bucket/own/a.txt").await.unwrap().status().as_u16(), 200);
+        // the session does not gain the grants of another account
+        assert_eq!(get("/bucket/shared/a.txt").await.unwrap().status().as_u16(), 403);
+        // the session does not escape the denies of its parent
+        assert_eq!(get("/bucket/own/denied/a.txt").await.unwrap().status().as_u16(), 403);
+    }
+}
@@ -0,0 +1,772 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Condition operators
+//!
+//! <https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_elements_condition_operators.html>
+//!
+//! An operator is a base operator with an optional `ForAllValues:` or `ForAnyValue:` set qualifier
+//! and an optional `IfExists` suffix, such as `ForAnyValue:StringLikeIfExists`.
+//! The values of a policy are parsed when the policy is compiled, so that malformed
+//! policies are rejected before they are evaluated.
+//!
+//! For a condition key that is missing from the request context:
+//!
+//! - An operator with `IfExists` matches.
+//! - A negated operator, such as `StringNotEquals`, matches.
+//! - `ForAllValues:` matches and `ForAnyValue:` does not match.
+//! - Other operators do not match.
+//!
+//! Without a set qualifier, a positive operator matches if any value of the key matches
+//! any value of the policy, and a negated operator matches if no value of the key matches.
+//!
+//! The values of string and ARN operators can contain policy variables (see [`crate::eval`]).
+
+use crate::eval::{PolicyError, RequestContext};
+use crate::model::ConditionRule;
+use crate::pattern::{PatternSet, Token, match_tokens, tokenize};
+use crate::variable::Template;
+
+use std::fmt;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+
+/// A parsed condition operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Operator {
+    pub qualifier: Option<SetQualifier>,
+    pub base: BaseOperator,
+    pub if_exists: bool,
+}
+
+/// A set qualifier for multivalued condition keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetQualifier {
+    /// `ForAllValues:` matches if every value of the key matches.
+    ForAllValues,
+    /// `ForAnyValue:` matches if at least one value of the key matches.
+    ForAnyValue,
+}
+
+/// A condition operator without qualifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BaseOperator {
+    StringEquals,
+    StringNotEquals,
+    StringEqualsIgnoreCase,
+    StringNotEqualsIgnoreCase,
+    StringLike,
+    StringNotLike,
+    NumericEquals,
+    NumericNotEquals,
+    NumericLessThan,
+    NumericLessThanEquals,
+    NumericGreaterThan,
+    NumericGreaterThanEquals,
+    DateEquals,
+    DateNotEquals,
+    DateLessThan,
+    DateLessThanEquals,
+    DateGreaterThan,
+    DateGreaterThanEquals,
+    Bool,
+    BinaryEquals,
+    IpAddress,
+    NotIpAddress,
+    ArnEquals,
+    ArnLike,
+    ArnNotEquals,
+    ArnNotLike,
+    Null,
+}
+
+impl BaseOperator {
+    const ALL: &[(&str, BaseOperator)] = &[
+        ("StringEquals", Self::StringEquals),
+        ("StringNotEquals", Self::StringNotEquals),
+        ("StringEqualsIgnoreCase", Self::StringEqualsIgnoreCase),
+        ("StringNotEqualsIgnoreCase", Self::StringNotEqualsIgnoreCase),
+        ("StringLike", Self::StringLike),
+        ("StringNotLike", Self::StringNotLike),
+        ("NumericEquals", Self::NumericEquals),
+        ("NumericNotEquals", Self::NumericNotEquals),
+        ("NumericLessThan", Self::NumericLessThan),
+        ("NumericLessThanEquals", Self::NumericLessThanEquals),
+        ("NumericGreaterThan", Self::NumericGreaterThan),
+        ("NumericGreaterThanEquals", Self::NumericGreaterThanEquals),
+        ("DateEquals", Self::DateEquals),
+        ("DateNotEquals", Self::DateNotEquals),
+        ("DateLessThan", Self::DateLessThan),
+        ("DateLessThanEquals", Self::DateLessThanEquals),
+        ("DateGreaterThan", Self::DateGreaterThan),
+        ("DateGreaterThanEquals", Self::DateGreaterThanEquals),
+        ("Bool", Self::Bool),
+        ("BinaryEquals", Self::BinaryEquals),
+        ("IpAddress", Self::IpAddress),
+        ("NotIpAddress", Self::NotIpAddress),
+        ("ArnEquals", Self::ArnEquals),
+        ("ArnLike", Self::ArnLike),
+        ("ArnNotEquals", Self::ArnNotEquals),
+        ("ArnNotLike", Self::ArnNotLike),
+        ("Null", Self::Null),
+    ];
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        Self::ALL.iter().find(|&&(_, op)| op == self).map_or("", |&(name, _)| name)
+    }
+
+    /// Returns `true` if the operator matches when the values do not match.
+    #[must_use]
+    pub fn is_negated(self) -> bool {
+        matches!(
+            self,
+            Self::StringNotEquals
+                | Self::StringNotEqualsIgnoreCase
+                | Self::StringNotLike
+                | Self::NumericNotEquals
+                | Self::DateNotEquals
+                | Self::NotIpAddress
+                | Self::ArnNotEquals
+                | Self::ArnNotLike
+        )
+    }
+}
+
+impl FromStr for Operator {
+    type Err = PolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unsupported = || PolicyError::UnsupportedConditionOperator(s.to_owned());
+
+        let (qualifier, rest) = if let Some(rest) = s.strip_prefix("ForAllValues:") {
+            (Some(SetQualifier::ForAllValues), rest)
+        } else if let Some(rest) = s.strip_prefix("ForAnyValue:") {
+            (Some(SetQualifier::ForAnyValue), rest)
+        } else {
+            (None, s)
+        };
+
+        let (rest, if_exists) = match rest.strip_suffix("IfExists") {
+            Some(rest) => (rest, true),
+            None => (rest, false),
+        };
+
+        let base = BaseOperator::ALL
+            .iter()
+            .find(|&&(name, _)| name == rest)
+            .map(|&(_, op)| op)
+            .ok_or_else(unsupported)?;
+
+        if base == BaseOperator::Null && (qualifier.is_some() || if_exists) {
+            return Err(unsupported());
+        }
+
+        Ok(Self {
+            qualifier,
+            base,
+            if_exists,
+        })
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.qualifier {
+            Some(SetQualifier::ForAllValues) => f.write_str("ForAllValues:")?,
+            Some(SetQualifier::ForAnyValue) => f.write_str("ForAnyValue:")?,
+            None => {}
+        }
+        f.write_str(self.base.as_str())?;
+        if self.if_exists {
+            f.write_str("IfExists")?;
+        }
+        Ok(())
+    }
+}
+
+/// A condition block compiled for evaluation.
+#[derive(Debug)]
+pub(crate) struct Condition {
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    operator: String,
+    parsed: Operator,
+    key: String,
+    values: Values,
+}
+
+/// The values of a policy, parsed for the operator.
+#[derive(Debug)]
+enum Values {
+    String(Vec<String>, Vec<Template>),
+    Pattern(Option<PatternSet>, Vec<Template>),
+    Numeric(Vec<f64>),
+    Date(Vec<i128>),
+    Bool(bool),
+    Ip(Vec<Cidr>),
+    Arn(Vec<ArnPattern>),
+}
+
+impl Condition {
+    /// Compiles a condition block. `variables` enables policy variables in the values.
+    pub fn new(rule: &ConditionRule, variables: bool) -> Result<Self, PolicyError> {
+        let mut entries = Vec::new();
+        for (operator, key_values) in &rule.0 {
+            let parsed: Operator = operator.parse()?;
+            for (key, values) in &key_values.0 {
+                let values = Values::parse(operator, parsed.base, values.as_slice(), variables)?;
+                entries.push(Entry {
+                    operator: operator.clone(),
+                    parsed,
+                    key: key.clone(),
+                    values,
+                });
+            }
+        }
+        Ok(Self { entries })
+    }
+
+    /// Returns the condition keys that are referenced by the condition,
+    /// including the keys of policy variables.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .flat_map(|e| std::iter::once(e.key.as_str()).chain(e.values.templates().flat_map(Template::keys)))
+    }
+
+    /// Evaluates the condition.
+    ///
+    /// Returns the operator and the key of the first entry that does not match.
+    pub fn check(&self, ctx: &RequestContext) -> Result<(), (&str, &str)> {
+        for entry in &self.entries {
+            let values = ctx.condition_key(&entry.key);
+            if !entry.is_match(values, ctx) {
+                return Err((&entry.operator, &entry.key));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Entry {
+    fn is_match(&self, key_values: Option<&[String]>, ctx: &RequestContext) -> bool {
+        let Operator {
+            qualifier,
+            base,
+            if_exists,
+        } = self.parsed;
+
+        let key_values = key_values.unwrap_or_default();
+
+        if base == BaseOperator::Null {
+            let Values::Bool(expected) = self.values else { return false };
+            return key_values.is_empty() == expected;
+        }
+
+        if key_values.is_empty() && if_exists {
+            return true;
+        }
+
+        let negated = base.is_negated();
+        let matches = |v: &String| self.values.contains(base, v, ctx) != negated;
+
+        match qualifier {
+            Some(SetQualifier::ForAllValues) => key_values.iter().all(matches),
+            None if negated => key_values.iter().all(matches),
+            Some(SetQualifier::ForAnyValue) | None => key_values.iter().any(matches),
+        }
+    }
+}
+
+impl Values {
+    fn parse(operator: &str, base: BaseOperator, values: &[String], variables: bool) -> Result<Self, PolicyError> {
+        use BaseOperator::{
+            ArnEquals, ArnLike, ArnNotEquals, ArnNotLike, BinaryEquals, Bool, DateEquals, DateGreaterThan, DateGreaterThanEquals,
+            DateLessThan, DateLessThanEquals, DateNotEquals, IpAddress, NotIpAddress, Null, NumericEquals, NumericGreaterThan,
+            NumericGreaterThanEquals, NumericLessThan, NumericLessThanEquals, NumericNotEquals, StringEquals,
+            StringEqualsIgnoreCase, StringLike, StringNotEquals, StringNotEqualsIgnoreCase, StringNotLike,
+        };
+
+        let invalid_value = |value: &str| PolicyError::InvalidConditionValue {
+            operator: operator.to_owned(),
+            value: value.to_owned(),
+        };
+        match base {
+            StringEquals | StringNotEquals | StringEqualsIgnoreCase | StringNotEqualsIgnoreCase => {
+                let (values, templates) = split_templates(values, variables)?;
+                Ok(Self::String(values, templates))
+            }
+            BinaryEquals => Ok(Self::String(values.to_owned(), Vec::new())),
+            StringLike | StringNotLike => {
+                let (values, templates) = split_templates(values, variables)?;
+                let patterns = if values.is_empty() && !templates.is_empty() {
+                    None
+                } else {
+                    let patterns = PatternSet::new(values.iter().map(String::as_str)).map_err(|_| {
+                        let invalid = values.iter().find(|v| PatternSet::new([v.as_str()]).is_err());
+                        invalid_value(invalid.map_or("", String::as_str))
+                    })?;
+                    Some(patterns)
+                };
+                Ok(Self::Pattern(patterns, templates))
+            }
+            NumericEquals
+            | NumericNotEquals
+            | NumericLessThan
+            | NumericLessThanEquals
+            | NumericGreaterThan
+            | NumericGreaterThanEquals => Ok(Self::Numeric(parse_all(operator, values, parse_number)?)),
+            DateEquals | DateNotEquals | DateLessThan | DateLessThanEquals | DateGreaterThan | DateGreaterThanEquals => {
+                Ok(Self::Date(parse_all(operator, values, parse_date)?))
+            }
+            Bool | Null => match values {
+                [v] => parse_bool(v).map(Self::Bool).ok_or_else(|| invalid_value(v)),
+                _ => Err(invalid_value(&values.join(","))),
+            },
+            IpAddress | NotIpAddress => Ok(Self::Ip(parse_all(operator, values, Cidr::parse)?)),
+            ArnEquals | ArnLike | ArnNotEquals | ArnNotLike => {
+                let patterns = parse_all(operator, values, ArnPattern::parse)?;
+                if variables {
+                    patterns
+                        .into_iter()
+                        .map(ArnPattern::with_variables)
+                        .collect::<Result<_, _>>()
+                        .map(Self::Arn)
+                } else {
+                    Ok(Self::Arn(patterns))
+                }
+            }
+        }
+    }
+
+    fn templates(&self) -> impl Iterator<Item = &Template> {
+        let (templates, arns): (&[Template], &[ArnPattern]) = match self {
+            Self::String(_, templates) | Self::Pattern(_, templates) => (templates, &[]),
+            Self::Arn(patterns) => (&[], patterns),
+            _ => (&[], &[]),
+        };
+        templates.iter().chain(arns.iter().filter_map(|p| p.template.as_ref()))
+    }
+
+    /// Returns `true` if a value of the request matches any value of the policy,
+    /// ignoring the negation of the operator.
+    fn contains(&self, base: BaseOperator, value: &str, ctx: &RequestContext) -> bool {
+        use BaseOperator::{
+            DateGreaterThan, DateGreaterThanEquals, DateLessThan, DateLessThanEquals, NumericGreaterThan,
+            NumericGreaterThanEquals, NumericLessThan, NumericLessThanEquals, StringEqualsIgnoreCase, StringNotEqualsIgnoreCase,
+        };
+
+        match self {
+            Self::String(values, templates) => {
+                let eq = |v: &str| match base {
+                    StringEqualsIgnoreCase | StringNotEqualsIgnoreCase => v.eq_ignore_ascii_case(value),
+                    _ => v == value,
+                };
+                values.iter().any(|v| eq(v)) || templates.iter().any(|t| t.resolve_string(ctx).is_some_and(|v| eq(&v)))
+            }
+            Self::Pattern(patterns, templates) => {
+                patterns.as_ref().is_some_and(|p| p.is_match(value))
+                    || templates
+                        .iter()
+                        .any(|t| t.resolve_pattern(ctx).is_some_and(|p| match_tokens(&p, value)))
+            }
+            Self::Numeric(values) => {
+                let Some(value) = parse_number(value) else { return false };
+                values.iter().any(|&v| match base {
+                    NumericLessThan => value < v,
+                    NumericLessThanEquals => value <= v,
+                    NumericGreaterThan => value > v,
+                    NumericGreaterThanEquals => value >= v,
+                    _ => (value - v).abs() < f64::EPSILON * value.abs().max(1.0),
+                })
+            }
+            Self::Date(values) => {
+                let Some(value) = parse_date(value) else { return false };
+                values.iter().any(|&v| match base {
+                    DateLessThan => value < v,
+                    DateLessThanEquals => value <= v,
+                    DateGreaterThan => value > v,
+                    DateGreaterThanEquals => value >= v,
+                    _ => value == v,
+                })
+            }
+            Self::Bool(expected) => parse_bool(value) == Some(*expected),
+            Self::Ip(cidrs) => {
+                let Ok(addr) = value.parse::<IpAddr>() else { return false };
+                cidrs.iter().any(|c| c.contains(addr))
+            }
+            Self::Arn(patterns) => patterns.iter().any(|p| p.is_match(value, ctx)),
+        }
+    }
+}
+
+/// Checks that the values of a policy are valid for a parsed operator.
+pub(crate) fn validate_values(operator: &str, parsed: Operator, values: &[String], variables: bool) -> Result<(), PolicyError> {
+    Values::parse(operator, parsed.base, values, variables).map(drop)
+}
+
+/// Separates the values with policy variables from the plain values.
+fn split_templates(values: &[String], variables: bool) -> Result<(Vec<String>, Vec<Template>), PolicyError> {
+    let mut plain = Vec::new();
+    let mut templates = Vec::new();
+    for value in values {
+        match variables.then(|| Template::parse(value)).transpose()?.flatten() {
+            Some(template) => templates.push(template),
+            None => plain.push(value.clone()),
+        }
+    }
+    Ok((plain, templates))
+}
+
+/// Parses the values of a policy for an operator.
+fn parse_all<T>(operator: &str, values: &[String], f: fn(&str) -> Option<T>) -> Result<Vec<T>, PolicyError> {
+    values
+        .iter()
+        .map(|v| {
+            f(v).ok_or_else(|| PolicyError::InvalidConditionValue {
+                operator: operator.to_owned(),
+                value: v.clone(),
+            })
+        })
+        .collect()
+}
+
+fn parse_number(s: &str) -> Option<f64> {
+    s.trim().parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+fn parse_bool(s: &str) -> Option<bool> {
+    if s.eq_ignore_ascii_case("true") {
+        Some(true)
+    } else if s.eq_ignore_ascii_case("false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Parses an ISO 8601 date or time, or a UNIX timestamp in seconds, into nanoseconds.
+fn parse_date(s: &str) -> Option<i128> {
+    let s = s.trim();
+    if let Ok(secs) = s.parse::<i64>() {
+        return Some(i128::from(secs) * 1_000_000_000);
+    }
+    if let Ok(t) = OffsetDateTime::parse(s, &Rfc3339) {
+        return Some(t.unix_timestamp_nanos());
+    }
+    let date = time::Date::parse(s, format_description!("[year]-[month]-[day]")).ok()?;
+    Some(date.midnight().assume_utc().unix_timestamp_nanos())
+}
+
+/// An IP address range, such as `203.0.113.0/24`.
+#[derive(Debug)]
+struct Cidr {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl Cidr {
+    fn parse(s: &str) -> Option<Self> {
+        let (addr, prefix_len) = match s.trim().split_once('/') {
+            Some((addr, len)) => (addr.parse::<IpAddr>().ok()?, Some(len.parse::<u8>().ok()?)),
+            None => (s.trim().parse::<IpAddr>().ok()?, None),
+        };
+        let max_len = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix_len = prefix_len.unwrap_or(max_len);
+        (prefix_len <= max_len).then_some(Self { addr, prefix_len })
+    }
+
+    fn contains(&self, addr: IpAddr) -> bool {
+        let (a, b, bits) = match (self.addr, addr.to_canonical()) {
+            (IpAddr::V4(a), IpAddr::V4(b)) => (u128::from(a.to_bits()), u128::from(b.to_bits()), 32),
+            (IpAddr::V6(a), IpAddr::V6(b)) => (a.to_bits(), b.to_bits(), 128),
+            _ => return false,
+        };
+        let shift = bits - u32::from(self.prefix_len);
+        shift >= bits || (a >> shift) == (b >> shift)
+    }
+}
+
+/// An ARN pattern, matched component by component.
+///
+/// The wildcards of a component do not match across `:` separators,
+/// except in the last component (the resource).
+#[derive(Debug)]
+struct ArnPattern {
+    pattern: String,
+    tokens: Vec<Token>,
+    template: Option<Template>,
+}
+
+impl ArnPattern {
+    const PARTS: usize = 6;
+
+    fn parse(s: &str) -> Option<Self> {
+        let is_arn = s.splitn(Self::PARTS, ':').count() == Self::PARTS && s.starts_with("arn:");
+        (s == "*" || is_arn).then(|| Self {
+            pattern: s.to_owned(),
+            tokens: tokenize(s).collect(),
+            template: None,
+        })
+    }
+
+    fn with_variables(mut self) -> Result<Self, PolicyError> {
+        self.template = Template::parse(&self.pattern)?;
+        Ok(self)
+    }
+
+    fn is_match(&self, s: &str, ctx: &RequestContext) -> bool {
+        if self.pattern == "*" {
+            return true;
+        }
+        match &self.template {
+            Some(template) => template
+                .resolve_pattern(ctx)
+                .is_some_and(|tokens| Self::match_parts(&tokens, s)),
+            None => Self::match_parts(&self.tokens, s),
+        }
+    }
+
+    fn match_parts(pattern: &[Token], s: &str) -> bool {
+        let mut parts = s.splitn(Self::PARTS, ':');
+        pattern
+            .splitn(Self::PARTS, |t| *t == Token::Byte(b':'))
+            .all(|p| parts.next().is_some_and(|s| match_tokens(p, s)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::model::{ConditionKeyValues, OneOrMore};
+
+    use indexmap::indexmap;
+
+    fn condition(operator: &str, key: &str, values: &[&str]) -> Result<Condition, PolicyError> {
+        let values = OneOrMore::More(values.iter().map(|&v| v.to_owned()).collect());
+        Condition::new(
+            &ConditionRule(indexmap! {
+                operator.to_owned() => ConditionKeyValues(indexmap! { key.to_owned() => values })
+            }),
+            true,
+        )
+    }
+
+    fn check(ctx: &RequestContext, cases: &[(&str, &str, &[&str], bool)]) {
+        for &(operator, key, values, expected) in cases {
+            let cond = condition(operator, key, values).unwrap();
+            assert_eq!(cond.check(ctx).is_ok(), expected, "{operator} {key} {values:?}");
+        }
+    }
+
+    #[test]
+    fn parse_operator() {
+        let op: Operator = "ForAnyValue:StringLikeIfExists".parse().unwrap();
+        assert_eq!(op.qualifier, Some(SetQualifier::ForAnyValue));
+        assert_eq!(op.base, BaseOperator::StringLike);
+        assert!(op.if_exists);
+        assert_eq!(op.to_string(), "ForAnyValue:StringLikeIfExists");
+
+        for s in ["Null", "IpAddress", "ForAllValues:ArnNotLike", "DateLessThanEqualsIfExists"] {
+            assert_eq!(s.parse::<Operator>().unwrap().to_string(), s);
+        }
+        for s in [
+            "",
+            "StringEqual",
+            "stringequals",
+            "NullIfExists",
+            "ForAnyValue:Null",
+            "ForSomeValues:StringEquals",
+        ] {
+            assert!(s.parse::<Operator>().is_err(), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn strings() {
+        let ctx = RequestContext::new("s3:ListBucket", "arn:aws:s3:::bucket")
+            .with_condition_key("s3:prefix", ["home/alice/"])
+            .with_condition_key("aws:SecureTransport", ["true"]);
+
+        check(
+            &ctx,
+            &[
+                ("StringEquals", "s3:prefix", &["home/alice/"], true),
+                ("StringEquals", "S3:Prefix", &["HOME/alice/"], false),
+                ("StringEqualsIgnoreCase", "s3:prefix", &["HOME/alice/"], true),
+                ("StringNotEquals", "s3:prefix", &["home/bob/"], true),
+                ("StringNotEquals", "s3:delimiter", &["/"], true),
+                ("StringLike", "s3:prefix", &["home/*", "public/*"], true),
+                ("StringNotLike", "s3:prefix", &["home/*"], false),
+                ("StringLike", "s3:delimiter", &["*"], false),
+                ("StringLikeIfExists", "s3:delimiter", &["/"], true),
+                ("Bool", "aws:SecureTransport", &["false"], false),
+                ("Bool", "aws:securetransport", &["true"], true),
+                ("Null", "s3:delimiter", &["true"], true),
+                ("Null", "s3:prefix", &["true"], false),
+                ("Null", "s3:prefix", &["false"], true),
+            ],
+        );
+    }
+
+    #[test]
+    fn numbers_and_dates() {
+        let ctx = RequestContext::new("s3:ListBucket", "arn:aws:s3:::bucket")
+            .with_condition_key("s3:max-keys", ["10"])
+            .with_condition_key("aws:CurrentTime", ["2024-06-01T12:00:00Z"])
+            .with_condition_key("aws:EpochTime", ["1717243200"]);
+
+        check(
+            &ctx,
+            &[
+                ("NumericEquals", "s3:max-keys", &["10"], true),
+                ("NumericNotEquals", "s3:max-keys", &["10.0"], false),
+                ("NumericLessThanEquals", "s3:max-keys", &["10"], true),
+                ("NumericLessThan", "s3:max-keys", &["10"], false),
+                ("NumericGreaterThan", "s3:max-keys", &["5", "20"], true),
+                ("NumericGreaterThanIfExists", "s3:other", &["5"], true),
+                ("NumericGreaterThan", "s3:other", &["5"], false),
+                ("DateGreaterThan", "aws:CurrentTime", &["2024-01-01"], true),
+                ("DateLessThan", "aws:CurrentTime", &["2024-06-01T11:00:00-02:00"], true),
+                ("DateEquals", "aws:EpochTime", &["2024-06-01T12:00:00Z"], true),
+                ("DateGreaterThanEquals", "aws:EpochTime", &["1717243201"], false),
+            ],
+        );
+    }
+
+    #[test]
+    fn ip_addresses_and_arns() {
+        let ctx = RequestContext::new("s3:GetObject", "arn:aws:s3:::bucket/key")
+            .with_condition_key("aws:SourceIp", ["203.0.113.7"])
+            .with_condition_key("aws:SourceArn", ["arn:aws:cloudfront::123456789012:distribution/E1"]);
+
+        check(
+            &ctx,
+            &[
+                ("IpAddress", "aws:SourceIp", &["203.0.113.0/24"], true),
+                ("IpAddress", "aws:SourceIp", &["203.0.113.8"], false),
+                ("IpAddress", "aws:SourceIp", &["0.0.0.0/0"], true),
+                ("IpAddress", "aws:SourceIp", &["2001:db8::/32"], false),
+                ("NotIpAddress", "aws:SourceIp", &["198.51.100.0/24", "192.0.2.0/24"], true),
+                ("ArnLike", "aws:SourceArn", &["arn:aws:cloudfront::*:distribution/*"], true),
+                ("ArnLike", "aws:SourceArn", &["arn:aws:s3:::distribution/*"], false),
+                ("ArnEquals", "aws:SourceArn", &["arn:aws:cloudfront::123456789012:distribution/E1"], true),
+                ("ArnNotLike", "aws:SourceArn", &["arn:aws:s3:::*"], true),
+            ],
+        );
+
+        let v6 = RequestContext::new("s3:GetObject", "*").with_condition_key("aws:SourceIp", ["2001:db8::1"]);
+        check(
+            &v6,
+            &[
+                ("IpAddress", "aws:SourceIp", &["2001:db8::/32"], true),
+                ("IpAddress", "aws:SourceIp", &["203.0.113.0/24"], false),
+            ],
+        );
+    }
+
+    #[test]
+    fn variables() {
+        let ctx = RequestContext::new("s3:ListBucket", "arn:aws:s3:::bucket")
+            .with_condition_key("aws:username", ["alice"])
+            .with_condition_key("aws:PrincipalAccount", ["123456789012"])
+            .with_condition_key("s3:prefix", ["home/alice/docs/"])
+            .with_condition_key("aws:SourceArn", ["arn:aws:iam::123456789012:user/alice"]);
+
+        check(
+            &ctx,
+            &[
+                ("StringLike", "s3:prefix", &["home/${aws:username}/*"], true),
+                ("StringLike", "s3:prefix", &["public/*", "home/${aws:username}/*"], true),
+                ("StringLike", "s3:prefix", &["home/${aws:userid}/*"], false),
+                ("StringLike", "s3:prefix", &["home/${aws:userid, 'alice'}/*"], true),
+                ("StringNotLike", "s3:prefix", &["home/${aws:username}/*"], false),
+                ("StringEquals", "s3:prefix", &["home/${aws:username}/docs/"], true),
+                ("StringEqualsIgnoreCase", "s3:prefix", &["HOME/${aws:username}/DOCS/"], true),
+                ("StringEquals", "s3:prefix", &["home/${aws:username}/*"], false),
+                ("StringEquals", "aws:username", &["${*}"], false),
+                (
+                    "ArnEquals",
+                    "aws:SourceArn",
+                    &["arn:aws:iam::${aws:PrincipalAccount}:user/${aws:username}"],
+                    true,
+                ),
+                ("ArnLike", "aws:SourceArn", &["arn:aws:iam::${aws:PrincipalAccount}:user/*"], true),
+                ("ArnLike", "aws:SourceArn", &["arn:aws:iam::${aws:userid}:user/*"], false),
+            ],
+        );
+
+        let cond = condition("StringLike", "s3:prefix", &["${s3:ExistingObjectTag/team}/*"]).unwrap();
+        assert_eq!(cond.keys().collect::<Vec<_>>(), ["s3:prefix", "s3:ExistingObjectTag/team"]);
+
+        assert!(matches!(
+            condition("StringEquals", "s3:prefix", &["${aws:username"]),
+            Err(PolicyError::InvalidVariable(_))
+        ));
+    }
+
+    #[test]
+    fn set_qualifiers() {
+        let ctx =
+            RequestContext::new("s3:PutObject", "arn:aws:s3:::bucket/key").with_condition_key("aws:TagKeys", ["env", "team"]);
+
+        check(
+            &ctx,
+            &[
+                ("ForAllValues:StringEquals", "aws:TagKeys", &["env", "team", "owner"], true),
+                ("ForAllValues:StringEquals", "aws:TagKeys", &["env"], false),
+                ("ForAnyValue:StringEquals", "aws:TagKeys", &["team"], true),
+                ("ForAnyValue:StringEquals", "aws:TagKeys", &["owner"], false),
+                ("ForAnyValue:StringNotEquals", "aws:TagKeys", &["env"], true),
+                ("ForAllValues:StringNotEquals", "aws:TagKeys", &["env"], false),
+                ("ForAllValues:StringEquals", "aws:Missing", &["env"], true),
+                ("ForAnyValue:StringEquals", "aws:Missing", &["env"], false),
+                ("StringNotEquals", "aws:TagKeys", &["env"], false),
+            ],
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let cases = [
+            ("Bool", &["yes"][..]),
+            ("Bool", &["true", "false"]),
+            ("NumericEquals", &["ten"]),
+            ("DateLessThan", &["yesterday"]),
+            ("IpAddress", &["203.0.113.0/33"]),
+            ("IpAddress", &["example.com"]),
+            ("ArnLike", &["bucket"]),
+            ("ArnLike", &["arn:aws:*:distribution/E1"]),
+            ("StringLike", &[""]),
+        ];
+        for (operator, values) in cases {
+            assert!(
+                matches!(condition(operator, "key", values), Err(PolicyError::InvalidConditionValue { .. })),
+                "{operator} {values:?}"
+            );
+        }
+
+        match condition("StringLike", "s3:prefix", &["home/*", ""]) {
+            Err(PolicyError::InvalidConditionValue { operator, value }) => {
+                assert_eq!(operator, "StringLike");
+                assert_eq!(value, "");
+            }
+            other => panic!("{other:?}"),
+        }
+
+        assert!(matches!(
+            condition("NumberEquals", "s3:max-keys", &["10"]),
+            Err(PolicyError::UnsupportedConditionOperator(_))
+        ));
+    }
+}
@@ -0,0 +1,598 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Policy evaluation
+//!
+//! A [`Policy`] is compiled into an [`Evaluator`] once and evaluated against a
+//! [`RequestContext`] for every request:
+//!
+//! - A statement applies if its principal, action, resource and condition all match.
+//! - The decision is [`Decision::Deny`] if any applicable statement denies the request,
+//!   otherwise [`Decision::Allow`] if any applicable statement allows it,
+//!   otherwise [`Decision::NoMatch`] (an implicit deny).
+//!
+//! In a policy of version `2012-10-17`, resources and the values of string and ARN conditions
+//! can contain policy variables, such as `arn:aws:s3:::bucket/home/${aws:username}/*`.
+//! A variable is replaced with the value of the condition key in the [`RequestContext`]
+//! before matching. `${*}`, `${?}` and `${$}` stand for the literal characters, and
+//! `${key, 'default'}` is used when the key is missing. A value whose variable can not be
+//! resolved does not match. Other versions treat variables as plain text.
+//!
+//! <https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_evaluation-logic.html>
+
+use crate::condition::Condition;
+use crate::model::Version;
+use crate::model::{ActionRule, Effect, Policy, Principal, PrincipalRule, ResourceRule, WildcardOneOrMore};
+use crate::pattern::{PatternError, PatternSet, match_tokens};
+use crate::variable::Template;
+
+use indexmap::IndexMap;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PolicyError {
+    #[error("Invalid pattern: {0:?}")]
+    InvalidPattern(String),
+
+    #[error("Unsupported condition operator: {0}")]
+    UnsupportedConditionOperator(String),
+
+    #[error("Invalid value for condition operator {operator}: {value:?}")]
+    InvalidConditionValue { operator: String, value: String },
+
+    #[error("Invalid policy variable: {0:?}")]
+    InvalidVariable(String),
+}
+
+/// The principal of a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestPrincipal {
+    /// The principal type, such as `AWS`, `Service` or `CanonicalUser`.
+    pub kind: String,
+
+    /// The identifiers of the principal, such as the ARN of the user and the account ID.
+    /// A policy principal matches if it equals any of them.
+    pub ids: Vec<String>,
+}
+
+impl RequestPrincipal {
+    /// Creates an `AWS` principal.
+    #[must_use]
+    pub fn aws<I>(ids: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            kind: "AWS".to_owned(),
+            ids: ids.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The context of a request to evaluate.
+#[derive(Debug, Clone, Default)]
+pub struct RequestContext {
+    /// The principal of the request. `None` means an anonymous request.
+    pub principal: Option<RequestPrincipal>,
+
+    /// The action, such as `s3:GetObject`.
+    pub action: String,
+
+    /// The resource ARN, such as `arn:aws:s3:::bucket/key`.
+    pub resource: String,
+
+    /// Condition keys in lowercase, since they are case-insensitive.
+    condition_keys: IndexMap<String, Vec<String>>,
+}
+
+impl RequestContext {
+    #[must_use]
+    pub fn new(action: impl Into<String>, resource: impl Into<String>) -> Self {
+        Self {
+            principal: None,
+            action: action.into(),
+            resource: resource.into(),
+            condition_keys: IndexMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_principal(mut self, principal: RequestPrincipal) -> Self {
+        self.principal = Some(principal);
+        self
+    }
+
+    /// Sets the values of a condition key, such as `aws:SourceIp`.
+    #[must_use]
+    pub fn with_condition_key<I>(mut self, key: &str, values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.set_condition_key(key, values);
+        self
+    }
+
+    /// Sets the values of a condition key, such as `aws:SourceIp`.
+    pub fn set_condition_key<I>(&mut self, key: &str, values: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let values = values.into_iter().map(Into::into).collect();
+        self.condition_keys.insert(key.to_ascii_lowercase(), values);
+    }
+
+    /// Returns the values of a condition key, or `None` if the key is not present.
+    #[must_use]
+    pub fn condition_key(&self, key: &str) -> Option<&[String]> {
+        let values = match self.condition_keys.get(key) {
+            Some(values) => values,
+            None => self.condition_keys.get(&key.to_ascii_lowercase())?,
+        };
+        Some(values)
+    }
+}
+
+/// The decision of a policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    /// An applicable statement allows the request and no applicable statement denies it.
+    Allow,
+    /// An applicable statement explicitly denies the request.
+    Deny,
+    /// No statement applies to the request.
+    NoMatch,
+}
+
+/// Why a statement applies or does not apply to a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatementOutcome {
+    Applied,
+    PrincipalMismatch,
+    ActionMismatch,
+    ResourceMismatch,
+    ConditionMismatch { operator: String, key: String },
+}
+
+/// The evaluation result of a statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementResult {
+    /// The position of the statement in the policy.
+    pub index: usize,
+    pub sid: Option<String>,
+    pub effect: Effect,
+    pub outcome: StatementOutcome,
+}
+
+/// The evaluation result of a policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evaluation {
+    pub decision: Decision,
+
+    /// The results of all statements, in policy order.
+    pub statements: Vec<StatementResult>,
+}
+
+impl Evaluation {
+    /// Returns the statements that decided the evaluation.
+    ///
+    /// These are the applicable statements whose effect is the decision.
+    /// The iterator is empty if the decision is [`Decision::NoMatch`].
+    pub fn deciding_statements(&self) -> impl Iterator<Item = &StatementResult> {
+        let effect = match self.decision {
+            Decision::Allow => Some(Effect::Allow),
+            Decision::Deny => Some(Effect::Deny),
+            Decision::NoMatch => None,
+        };
+        self.statements
+            .iter()
+            .filter(move |s| s.outcome == StatementOutcome::Applied && Some(&s.effect) == effect.as_ref())
+    }
+}
+
+/// A compiled policy.
+#[derive(Debug)]
+pub struct Evaluator {
+    statements: Vec<CompiledStatement>,
+}
+
+#[derive(Debug)]
+struct CompiledStatement {
+    sid: Option<String>,
+    effect: Effect,
+    principal: Option<PrincipalRule>,
+    action: Matcher,
+    resource: Matcher,
+    condition: Option<Condition>,
+}
+
+/// Matches `Action`/`NotAction` or `Resource`/`NotResource`.
+#[derive(Debug)]
+struct Matcher {
+    /// `None` matches everything.
+    patterns: Option<PatternSet>,
+    /// Patterns with policy variables, which are resolved for every request.
+    /// Only resources can contain variables, so they are matched case-sensitively.
+    templates: Vec<Template>,
+    negated: bool,
+    ignore_case: bool,
+}
+
+impl Matcher {
+    fn new(values: &WildcardOneOrMore<String>, negated: bool, ignore_case: bool, variables: bool) -> Result<Self, PolicyError> {
+        let mut templates = Vec::new();
+        let patterns = match values.as_slice() {
+            None => None,
+            Some(values) => {
+                let mut static_values: Vec<String> = Vec::with_capacity(values.len());
+                for value in values {
+                    if value.is_empty() {
+                        return Err(PolicyError::InvalidPattern(value.clone()));
+                    }
+                    if variables && let Some(template) = Template::parse(value)? {
+                        templates.push(template);
+                    } else if ignore_case {
+                        static_values.push(value.to_ascii_lowercase());
+                    } else {
+                        static_values.push(value.clone());
+                    }
+                }
+                let patterns =
+                    PatternSet::new(static_values.iter().map(String::as_str)).map_err(|PatternError::InvalidPattern| {
+                        let invalid = static_values.iter().find(|v| PatternSet::new([v.as_str()]).is_err());
+                        PolicyError::InvalidPattern(invalid.cloned().unwrap_or_default())
+                    })?;
+                Some(patterns)
+            }
+        };
+        Ok(Self {
+            patterns,
+            templates,
+            negated,
+            ignore_case,
+        })
+    }
+
+    fn is_match(&self, input: &str, ctx: &RequestContext) -> bool {
+        let found = match &self.patterns {
+            None => true,
+            Some(patterns) if self.ignore_case => patterns.is_match(&input.to_ascii_lowercase()),
+            Some(patterns) => {
+                patterns.is_match(input)
+                    || self
+                        .templates
+                        .iter()
+                        .any(|t| t.resolve_pattern(ctx).is_some_and(|p| match_tokens(&p, input)))
+            }
+        };
+        found != self.negated
+    }
+}
+
+impl Evaluator {
+    /// Compiles a policy.
+    ///
+    /// # Errors
+    /// Returns an error if the policy contains an invalid pattern or an unsupported condition.
+    pub fn new(policy: &Policy) -> Result<Self, PolicyError> {
+        let variables = policy.version == Some(Version::V2012_10_17);
+        let statements = policy
+            .statement
+            .as_slice()
+            .iter()
+            .map(|s| {
+                let action = match &s.action {
+                    ActionRule::Action(v) => Matcher::new(v, false, true, false)?,
+                    ActionRule::NotAction(v) => Matcher::new(v, true, true, false)?,
+                };
+                let resource = match &s.resource {
+                    ResourceRule::Resource(v) => Matcher::new(v, false, false, variables)?,
+                    ResourceRule::NotResource(v) => Matcher::new(v, true, false, variables)?,
+                };
+                let condition = s.condition.as_ref().map(|c| Condition::new(c, variables)).transpose()?;
+                Ok(CompiledStatement {
+                    sid: s.sid.clone(),
+                    effect: s.effect.clone(),
+                    principal: s.principal.clone(),
+                    action,
+                    resource,
+                    condition,
+                })
+            })
+            .collect::<Result<_, PolicyError>>()?;
+        Ok(Self { statements })
+    }
+
+    /// Returns the condition keys that are referenced by the policy,
+    /// including the keys of policy variables.
+    ///
+    /// This can be used to compute expensive condition keys only when they are needed.
+    pub fn condition_keys(&self) -> impl Iterator<Item = &str> {
+        self.statements.iter().flat_map(|s| {
+            let resource_keys = s.resource.templates.iter().flat_map(Template::keys);
+            let condition_keys = s.condition.iter().flat_map(Condition::keys);
+            resource_keys.chain(condition_keys)
+        })
+    }
+
+    /// Evaluates the policy against a request.
+    #[must_use]
+    pub fn evaluate(&self, ctx: &RequestContext) -> Evaluation {
+        let statements: Vec<StatementResult> = self
+            .statements
+            .iter()
+            .enumerate()
+            .map(|(index, s)| StatementResult {
+                index,
+                sid: s.sid.clone(),
+                effect: s.effect.clone(),
+                outcome: s.evaluate(ctx),
+            })
+            .collect();
+
+        let applied = |effect: Effect| {
+            statements
+                .iter()
+                .any(|s| s.outcome == StatementOutcome::Applied && s.effect == effect)
+        };
+        let decision = if applied(Effect::Deny) {
+            Decision::Deny
+        } else if applied(Effect::Allow) {
+            Decision::Allow
+        } else {
+            Decision::NoMatch
+        };
+
+        Evaluation { decision, statements }
+    }
+}
+
+impl CompiledStatement {
+    fn evaluate(&self, ctx: &RequestContext) -> StatementOutcome {
+        let principal_match = match &self.principal {
+            None => true,
+            Some(PrincipalRule::Principal(p)) => principal_matches(p, ctx.principal.as_ref()),
+            Some(PrincipalRule::NotPrincipal(p)) => !principal_matches(p, ctx.principal.as_ref()),
+        };
+        if !principal_match {
+            return StatementOutcome::PrincipalMismatch;
+        }
+        if !self.action.is_match(&ctx.action, ctx) {
+            return StatementOutcome::ActionMismatch;
+        }
+        if !self.resource.is_match(&ctx.resource, ctx) {
+            return StatementOutcome::ResourceMismatch;
+        }
+        if let Some(condition) = &self.condition
+            && let Err((operator, key)) = condition.check(ctx)
+        {
+            return StatementOutcome::ConditionMismatch {
+                operator: operator.to_owned(),
+                key: key.to_owned(),
+            };
+        }
+        StatementOutcome::Applied
+    }
+}
+
+/// Matches a policy principal. `*` matches everyone, including anonymous requests.
+fn principal_matches(principal: &Principal, requester: Option<&RequestPrincipal>) -> bool {
+    match principal {
+        Principal::Wildcard => true,
+        Principal::Map(map) => map.iter().any(|(kind, ids)| {
+            ids.as_slice().iter().any(|id| {
+                if id == "*" {
+                    return true;
+                }
+                requester.is_some_and(|r| r.kind == *kind && r.ids.contains(id))
+            })
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluator(json: &str) -> Evaluator {
+        let policy: Policy = serde_json::from_str(json).unwrap();
+        Evaluator::new(&policy).unwrap()
+    }
+
+    #[test]
+    fn examples() {
+        let e = evaluator(crate::tests::example1_json());
+
+        let ctx = RequestContext::new("s3:GetObject", "arn:aws:s3:::confidential-data/file.txt");
+        let res = e.evaluate(&ctx);
+        assert_eq!(res.decision, Decision::NoMatch);
+        assert_eq!(
+            res.statements[2].outcome,
+            StatementOutcome::ConditionMismatch {
+                operator: "Bool".to_owned(),
+                key: "aws:MultiFactorAuthPresent".to_owned(),
+            }
+        );
+
+        let ctx = ctx.with_condition_key("aws:MultiFactorAuthPresent", ["true"]);
+        let res = e.evaluate(&ctx);
+        assert_eq!(res.decision, Decision::Allow);
+        let sids: Vec<_> = res.deciding_statements().map(|s| s.sid.as_deref()).collect();
+        assert_eq!(sids, [Some("ThirdStatement")]);
+
+        // Actions are case-insensitive
+        let ctx = RequestContext::new("S3:listallmybuckets", "*");
+        assert_eq!(e.evaluate(&ctx).decision, Decision::Allow);
+
+        let e = evaluator(crate::tests::example3_json());
+        let root = RequestPrincipal::aws(["arn:aws:iam::account-id:root"]);
+        let ctx = RequestContext::new("s3:PutObject", "arn:aws:s3:::mybucket/a").with_principal(root);
+        assert_eq!(e.evaluate(&ctx).decision, Decision::Allow);
+
+        let other = RequestPrincipal::aws(["arn:aws:iam::other:root"]);
+        let ctx = RequestContext::new("s3:PutObject", "arn:aws:s3:::mybucket/a").with_principal(other);
+        let res = e.evaluate(&ctx);
+        assert_eq!(res.decision, Decision::NoMatch);
+        assert_eq!(res.statements[0].outcome, StatementOutcome::PrincipalMismatch);
+
+        let ctx = RequestContext::new("s3:PutObject", "arn:aws:s3:::otherbucket/a");
+        assert_eq!(e.evaluate(&ctx).decision, Decision::NoMatch);
+    }
+
+    #[test]
+    fn explicit_deny_wins() {
+        let e = evaluator(
+            r#"{
+                "Version": "2012-10-17",
+                "Statement": [
+                    {"Sid": "AllowAll", "Effect": "Allow", "Principal": "*", "Action": "s3:*", "Resource": "*"},
+                    {"Sid": "DenyDelete", "Effect": "Deny", "Principal": "*", "Action": "s3:Delete*", "Resource": "arn:aws:s3:::bucket/*"}
+                ]
+            }"#,
+        );
+
+        let ctx = RequestContext::new("s3:DeleteObject", "arn:aws:s3:::bucket/key");
+        let res = e.evaluate(&ctx);
+        assert_eq!(res.decision, Decision::Deny);
+        let sids: Vec<_> = res.deciding_statements().map(|s| s.sid.as_deref()).collect();
+        assert_eq!(sids, [Some("DenyDelete")]);
+
+        let ctx = RequestContext::new("s3:GetObject", "arn:aws:s3:::bucket/key");
+        assert_eq!(e.evaluate(&ctx).decision, Decision::Allow);
+    }
+
+    #[test]
+    fn negations() {
+        let e = evaluator(
+            r#"{
+                "Version": "2012-10-17",
+                "Statement": [
+                    {
+                        "Sid": "DenyOthers",
+                        "Effect": "Deny",
+                        "NotPrincipal": {"AWS": "arn:aws:iam::123456789012:user/admin"},
+                        "NotAction": ["s3:Get*", "s3:List*"],
+                        "Resource": "*"
+                    },
+                    {
+                        "Sid": "AllowOutsidePrivate",
+                        "Effect": "Allow",
+                        "Principal": {"AWS": "*"},
+                        "Action": "*",
+                        "NotResource": "arn:aws:s3:::bucket/private/*"
+                    }
+                ]
+            }"#,
+        );
+
+        let admin = RequestPrincipal::aws(["arn:aws:iam::123456789012:user/admin", "123456789012"]);
+        let user = RequestPrincipal::aws(["arn:aws:iam::123456789012:user/user", "123456789012"]);
+
+        let cases = [
+            (Some(&admin), "s3:PutObject", "arn:aws:s3:::bucket/a", Decision::Allow),
+            (Some(&user), "s3:PutObject", "arn:aws:s3:::bucket/a", Decision::Deny),
+            (None, "s3:PutObject", "arn:aws:s3:::bucket/a", Decision::Deny),
+            (Some(&user), "s3:GetObject", "arn:aws:s3:::bucket/a", Decision::Allow),
+            (Some(&user), "s3:GetObject", "arn:aws:s3:::bucket/private/a", Decision::NoMatch),
+            (None, "s3:GetObject", "arn:aws:s3:::bucket/a", Decision::Allow),
+        ];
+
+        for (principal, action, resource, expected) in cases {
+            let mut ctx = RequestContext::new(action, resource);
+            ctx.principal = principal.cloned();
+            assert_eq!(e.evaluate(&ctx).decision, expected, "{principal:?} {action} {resource}");
+        }
+    }
+
+    #[test]
+    fn variables() {
+        let json = r#"{
+            "Version": "2012-10-17",
+            "Statement": [
+                {
+                    "Sid": "ListHome",
+                    "Effect": "Allow",
+                    "Principal": "*",
+                    "Action": "s3:ListBucket",
+                    "Resource": "arn:aws:s3:::bucket",
+                    "Condition": {"StringLike": {"s3:prefix": ["home/", "home/${aws:username}/*"]}}
+                },
+                {
+                    "Sid": "HomeObjects",
+                    "Effect": "Allow",
+                    "Principal": "*",
+                    "Action": "s3:*Object",
+                    "Resource": ["arn:aws:s3:::bucket/home/${aws:username}/*", "arn:aws:s3:::bucket/${*}literal"]
+                }
+            ]
+        }"#;
+        let e = evaluator(json);
+
+        let alice =
+            |action: &str, resource: &str| RequestContext::new(action, resource).with_condition_key("aws:username", ["alice"]);
+
+        let ctx = alice("s3:GetObject", "arn:aws:s3:::bucket/home/alice/a.txt");
+        assert_eq!(e.evaluate(&ctx).decision, Decision::Allow);
+
+        let ctx = alice("s3:GetObject", "arn:aws:s3:::bucket/home/bob/a.txt");
+        assert_eq!(e.evaluate(&ctx).decision, Decision::NoMatch);
+
+        let ctx = alice("s3:ListBucket", "arn:aws:s3:::bucket").with_condition_key("s3:prefix", ["home/alice/docs/"]);
+        assert_eq!(e.evaluate(&ctx).decision, Decision::Allow);
+
+        let ctx = alice("s3:ListBucket", "arn:aws:s3:::bucket").with_condition_key("s3:prefix", ["home/bob/"]);
+        assert_eq!(e.evaluate(&ctx).decision, Decision::NoMatch);
+
+        // An unresolved variable does not match
+        let ctx = RequestContext::new("s3:GetObject", "arn:aws:s3:::bucket/home//a.txt");
+        assert_eq!(e.evaluate(&ctx).decision, Decision::NoMatch);
+
+        // Substituted values are not wildcards
+        let ctx =
+            RequestContext::new("s3:GetObject", "arn:aws:s3:::bucket/home/bob/a.txt").with_condition_key("aws:username", ["*"]);
+        assert_eq!(e.evaluate(&ctx).decision, Decision::NoMatch);
+
+        let ctx = alice("s3:GetObject", "arn:aws:s3:::bucket/*literal");
+        assert_eq!(e.evaluate(&ctx).decision, Decision::Allow);
+        let ctx = alice("s3:GetObject", "arn:aws:s3:::bucket/a-literal");
+        assert_eq!(e.evaluate(&ctx).decision, Decision::NoMatch);
+
+        let keys: Vec<_> = e.condition_keys().collect();
+        assert_eq!(keys, ["s3:prefix", "aws:username", "aws:username"]);
+
+        // Variables are plain text in older versions
+        let e = evaluator(&json.replace("2012-10-17", "2008-10-17"));
+        let ctx = alice("s3:GetObject", "arn:aws:s3:::bucket/home/alice/a.txt");
+        assert_eq!(e.evaluate(&ctx).decision, Decision::NoMatch);
+        let ctx = alice("s3:GetObject", "arn:aws:s3:::bucket/home/${aws:username}/a.txt");
+        assert_eq!(e.evaluate(&ctx).decision, Decision::Allow);
+    }
+
+    #[test]
+    fn invalid_policy() {
+        let policy: Policy = serde_json::from_str(
+            r#"{"Statement": {"Effect": "Allow", "Action": "", "Resource": "*"}}"#, //
+        )
+        .unwrap();
+        assert!(matches!(Evaluator::new(&policy), Err(PolicyError::InvalidPattern(v)) if v.is_empty()));
+
+        let policy: Policy = serde_json::from_str(
+            r#"{"Statement": {"Effect": "Allow", "Action": "*", "Resource": ["arn:aws:s3:::bucket/*", ""]}}"#, //
+        )
+        .unwrap();
+        assert!(matches!(Evaluator::new(&policy), Err(PolicyError::InvalidPattern(v)) if v.is_empty()));
+
+        let policy: Policy = serde_json::from_str(
+            r#"{"Statement": {"Effect": "Allow", "Action": "*", "Resource": "*", "Condition": {"Foo": {"a": "b"}}}}"#,
+        )
+        .unwrap();
+        assert!(matches!(Evaluator::new(&policy), Err(PolicyError::UnsupportedConditionOperator(_))));
+
+        let policy: Policy = serde_json::from_str(
+            r#"{"Version": "2012-10-17", "Statement": {"Effect": "Allow", "Action": "*", "Resource": "arn:aws:s3:::bucket/${aws:username"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(Evaluator::new(&policy), Err(PolicyError::InvalidVariable(_))));
+    }
+}
@@ -1,8 +1,13 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023-2026 The s3s Authors
 
+pub mod access;
+pub mod condition;
+pub mod eval;
 pub mod model;
 pub mod pattern;
+pub mod validate;
+mod variable;
 
 #[cfg(test)]
 mod tests;
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023-2026 The s3s Authors
 
+#[derive(Debug)]
 pub struct PatternSet {
     // TODO: rewrite the naive implementation with something like Aho-Corasick
     patterns: Vec<Pattern>,
@@ -14,7 +15,18 @@ pub enum PatternError {
 
 #[derive(Debug)]
 struct Pattern {
-    bytes: Vec<u8>,
+    tokens: Vec<Token>,
+}
+
+/// A token of a wildcard pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Token {
+    /// Matches the byte itself.
+    Byte(u8),
+    /// `?` matches any single character.
+    AnyChar,
+    /// `*` matches any sequence of characters.
+    AnySequence,
 }
 
 impl PatternSet {
@@ -37,7 +49,7 @@ impl PatternSet {
             return Err(PatternError::InvalidPattern);
         }
         Ok(Pattern {
-            bytes: pattern.as_bytes().to_owned(),
+            tokens: tokenize(pattern).collect(),
         })
     }
 
@@ -45,7 +57,7 @@ impl PatternSet {
     #[must_use]
     pub fn is_match(&self, input: &str) -> bool {
         for pattern in &self.patterns {
-            if Self::match_pattern(&pattern.bytes, input.as_bytes()) {
+            if Self::match_pattern(&pattern.tokens, input.as_bytes()) {
                 return true;
             }
         }
@@ -53,7 +65,7 @@ impl PatternSet {
     }
 
     /// <https://leetcode.com/problems/wildcard-matching/>
-    fn match_pattern(pattern: &[u8], input: &[u8]) -> bool {
+    fn match_pattern(pattern: &[Token], input: &[u8]) -> bool {
         let mut p_idx = 0;
         let mut s_idx = 0;
 
@@ -63,7 +75,7 @@ impl PatternSet {
         loop {
             if p_idx < pattern.len() {
                 let p = pattern[p_idx];
-                if p == b'*' {
+                if p == Token::AnySequence {
                     p_idx += 1;
                     p_back = p_idx;
                     s_back = s_idx;
@@ -72,7 +84,7 @@ impl PatternSet {
 
                 if s_idx < input.len() {
                     let c = input[s_idx];
-                    if p == c || p == b'?' {
+                    if p == Token::Byte(c) || p == Token::AnyChar {
                         p_idx += 1;
                         s_idx += 1;
                         continue;
@@ -98,6 +110,20 @@ impl PatternSet {
     }
 }
 
+/// Splits a pattern into tokens.
+pub(crate) fn tokenize(pattern: &str) -> impl Iterator<Item = Token> + '_ {
+    pattern.bytes().map(|b| match b {
+        b'*' => Token::AnySequence,
+        b'?' => Token::AnyChar,
+        _ => Token::Byte(b),
+    })
+}
+
+/// Checks if the input matches a tokenized pattern, which may be empty.
+pub(crate) fn match_tokens(pattern: &[Token], input: &str) -> bool {
+    PatternSet::match_pattern(pattern, input.as_bytes())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,7 +146,7 @@ mod tests {
 
         for &(pattern, input, expected) in cases {
             let pattern = PatternSet::parse_pattern(pattern).unwrap();
-            let ans = PatternSet::match_pattern(&pattern.bytes, input.as_bytes());
+            let ans = PatternSet::match_pattern(&pattern.tokens, input.as_bytes());
             assert_eq!(ans, expected, "pattern: {pattern:?}, input: {input:?}");
         }
     }
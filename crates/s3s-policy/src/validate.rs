@@ -0,0 +1,681 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Policy validation
+//!
+//! A [`Validator`] checks a [`Policy`] before it is stored, for example in a
+//! `PutBucketPolicy` implementation, and reports the problems as [`Finding`]s:
+//!
+//! - Errors make the policy invalid: unknown actions, malformed ARNs, resources outside
+//!   of the target bucket, unsupported condition operators, keys or values, invalid
+//!   policy variables and empty statements.
+//! - Warnings point out policies that are valid but probably wrong, such as an `Allow`
+//!   statement that is always overridden by a `Deny` statement.
+//!
+//! Actions are checked against the IAM actions of all S3 operations (see [`action_name`])
+//! and the S3 actions that have no operation, such as `s3:BypassGovernanceRetention`.
+//! Condition keys are checked against the keys that are set by [`request_context`].
+//!
+//! # Example
+//!
+//! ```
+//! use s3s_policy::model::Policy;
+//! use s3s_policy::validate::Validator;
+//!
+//! let policy: Policy = serde_json::from_str(r#"{
+//!     "Version": "2012-10-17",
+//!     "Statement": {
+//!         "Effect": "Allow",
+//!         "Principal": "*",
+//!         "Action": "s3:GetObjekt",
+//!         "Resource": "arn:aws:s3:::other-bucket/*"
+//!     }
+//! }"#).unwrap();
+//!
+//! let report = Validator::new().bucket("my-bucket").validate(&policy);
+//! assert!(!report.is_valid());
+//! assert_eq!(report.errors().count(), 2);
+//!
+//! let err = report.to_s3_error().unwrap();
+//! assert_eq!(*err.code(), s3s::S3ErrorCode::MalformedPolicy);
+//! ```
+
+use crate::access::{action_name, is_supported_condition_key};
+use crate::condition::{Operator, validate_values};
+use crate::eval::PolicyError;
+use crate::model::{
+    ActionRule, Effect, OneOrMore, Policy, Principal, PrincipalRule, ResourceRule, Statement, Version, WildcardOneOrMore,
+};
+use crate::pattern::{match_tokens, tokenize};
+use crate::variable::Template;
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::OnceLock;
+
+use indexmap::IndexMap;
+use s3s::{S3Error, S3ErrorCode, S3Operation};
+
+/// Checks policies.
+#[derive(Debug, Default, Clone)]
+pub struct Validator {
+    bucket: Option<String>,
+    condition_keys: Vec<String>,
+}
+
+/// The result of a validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// The findings, in policy order.
+    pub findings: Vec<Finding>,
+}
+
+/// A problem of a policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub severity: Severity,
+    /// The position of the statement in the policy, or `None` for the whole policy.
+    pub statement: Option<usize>,
+    pub sid: Option<String>,
+    pub kind: FindingKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// The kind of a [`Finding`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FindingKind {
+    /// The policy has no statements.
+    EmptyPolicy,
+    /// An element of a statement, such as `Action` or `Resource`, is an empty list.
+    EmptyStatement {
+        element: &'static str,
+    },
+    /// An action does not match any action of an S3 operation.
+    UnknownAction(String),
+    /// A resource is neither `*` nor an ARN.
+    MalformedArn(String),
+    /// A resource does not belong to the target bucket.
+    ResourceOutsideBucket(String),
+    UnsupportedConditionOperator(String),
+    /// A condition key, or the key of a policy variable, is never set for a request.
+    UnsupportedConditionKey(String),
+    InvalidConditionValue {
+        operator: String,
+        value: String,
+    },
+    InvalidVariable(String),
+    /// An `Allow` statement never applies because a `Deny` statement always overrides it.
+    UnreachableAllow {
+        denied_by: usize,
+    },
+}
+
+impl Validator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the target bucket of a bucket policy.
+    ///
+    /// Resources that can not refer to the bucket or its objects are reported.
+    #[must_use]
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.bucket = Some(bucket.into());
+        self
+    }
+
+    /// Allows a condition key that is set by a custom [`RequestContext`](crate::eval::RequestContext).
+    #[must_use]
+    pub fn condition_key(mut self, key: impl Into<String>) -> Self {
+        self.condition_keys.push(key.into());
+        self
+    }
+
+    /// Validates a policy.
+    #[must_use]
+    pub fn validate(&self, policy: &Policy) -> Report {
+        let mut findings = Vec::new();
+        let statements = policy.statement.as_slice();
+        if statements.is_empty() {
+            findings.push(Finding {
+                severity: Severity::Error,
+                statement: None,
+                sid: None,
+                kind: FindingKind::EmptyPolicy,
+            });
+        }
+
+        let variables = policy.version == Some(Version::V2012_10_17);
+        for (index, statement) in statements.iter().enumerate() {
+            let mut kinds = Vec::new();
+            self.check_statement(statement, variables, &mut kinds);
+            if statement.effect == Effect::Allow
+                && let Some(denied_by) = statements.iter().position(|s| shadows(s, statement))
+            {
+                kinds.push(FindingKind::UnreachableAllow { denied_by });
+            }
+            findings.extend(kinds.into_iter().map(|kind| Finding {
+                severity: kind.severity(),
+                statement: Some(index),
+                sid: statement.sid.clone(),
+                kind,
+            }));
+        }
+
+        Report { findings }
+    }
+
+    fn check_statement(&self, statement: &Statement, variables: bool, kinds: &mut Vec<FindingKind>) {
+        let principal = match &statement.principal {
+            Some(PrincipalRule::Principal(p)) => Some(("Principal", p)),
+            Some(PrincipalRule::NotPrincipal(p)) => Some(("NotPrincipal", p)),
+            None => None,
+        };
+        if let Some((element, Principal::Map(map))) = principal
+            && map.values().all(|ids| ids.as_slice().is_empty())
+        {
+            kinds.push(FindingKind::EmptyStatement { element });
+        }
+
+        let (element, actions) = match &statement.action {
+            ActionRule::Action(v) => ("Action", v),
+            ActionRule::NotAction(v) => ("NotAction", v),
+        };
+        if let Some(actions) = actions.as_slice() {
+            if actions.is_empty() {
+                kinds.push(FindingKind::EmptyStatement { element });
+            }
+            for action in actions {
+                if !is_known_action(action) {
+                    kinds.push(FindingKind::UnknownAction(action.clone()));
+                }
+            }
+        }
+
+        let (element, resources, negated) = match &statement.resource {
+            ResourceRule::Resource(v) => ("Resource", v, false),
+            ResourceRule::NotResource(v) => ("NotResource", v, true),
+        };
+        if let Some(resources) = resources.as_slice() {
+            if resources.is_empty() {
+                kinds.push(FindingKind::EmptyStatement { element });
+            }
+            for resource in resources {
+                self.check_resource(resource, negated, variables, kinds);
+            }
+        }
+
+        if let Some(condition) = &statement.condition {
+            for (operator, key_values) in &condition.0 {
+                let Ok(parsed) = operator.parse::<Operator>() else {
+                    kinds.push(FindingKind::UnsupportedConditionOperator(operator.clone()));
+                    continue;
+                };
+                for (key, values) in &key_values.0 {
+                    self.check_condition_key(key, kinds);
+                    if let Err(err) = validate_values(operator, parsed, values.as_slice(), variables) {
+                        kinds.push(FindingKind::from_condition_error(operator, err));
+                    }
+                    if variables {
+                        for value in values.as_slice() {
+                            if let Ok(Some(template)) = Template::parse(value) {
+                                template.keys().for_each(|key| self.check_condition_key(key, kinds));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_resource(&self, resource: &str, negated: bool, variables: bool, kinds: &mut Vec<FindingKind>) {
+        if resource == "*" {
+            return;
+        }
+
+        let parts: Vec<&str> = resource.splitn(6, ':').collect();
+        let [arn, _partition, service, _region, _account, path] = parts[..] else {
+            kinds.push(FindingKind::MalformedArn(resource.to_owned()));
+            return;
+        };
+        if arn != "arn" || service.is_empty() || path.is_empty() {
+            kinds.push(FindingKind::MalformedArn(resource.to_owned()));
+            return;
+        }
+
+        if variables {
+            match Template::parse(resource) {
+                Ok(Some(template)) => template.keys().for_each(|key| self.check_condition_key(key, kinds)),
+                Ok(None) => {}
+                Err(_) => kinds.push(FindingKind::InvalidVariable(resource.to_owned())),
+            }
+        }
+
+        if let Some(bucket) = &self.bucket
+            && !negated
+        {
+            let bucket_pattern = path.split('/').next().unwrap_or(path);
+            let is_variable = variables && bucket_pattern.contains("${");
+            let in_bucket = wildcard_match(service, "s3") && (is_variable || wildcard_match(bucket_pattern, bucket));
+            if !in_bucket {
+                kinds.push(FindingKind::ResourceOutsideBucket(resource.to_owned()));
+            }
+        }
+    }
+
+    fn check_condition_key(&self, key: &str, kinds: &mut Vec<FindingKind>) {
+        let is_supported = is_supported_condition_key(key) || self.condition_keys.iter().any(|k| k.eq_ignore_ascii_case(key));
+        if !is_supported {
+            kinds.push(FindingKind::UnsupportedConditionKey(key.to_owned()));
+        }
+    }
+}
+
+impl Report {
+    /// Returns `true` if there are no errors.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(|f| f.severity == Severity::Error)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(|f| f.severity == Severity::Warning)
+    }
+
+    /// Returns a [`MalformedPolicy`](S3ErrorCode::MalformedPolicy) error
+    /// describing the errors, or `None` if the policy is valid.
+    #[must_use]
+    pub fn to_s3_error(&self) -> Option<S3Error> {
+        if self.is_valid() {
+            return None;
+        }
+        let message = self.errors().map(ToString::to_string).collect::<Vec<_>>().join("; ");
+        Some(S3Error::with_message(S3ErrorCode::MalformedPolicy, message))
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.statement, &self.sid) {
+            (Some(index), Some(sid)) => write!(f, "Statement {index} ({sid:?}): ")?,
+            (Some(index), None) => write!(f, "Statement {index}: ")?,
+            (None, _) => {}
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyPolicy => write!(f, "Policy has no statements"),
+            Self::EmptyStatement { element } => write!(f, "Empty {element}"),
+            Self::UnknownAction(action) => write!(f, "Unknown action: {action:?}"),
+            Self::MalformedArn(resource) => write!(f, "Malformed ARN: {resource:?}"),
+            Self::ResourceOutsideBucket(resource) => write!(f, "Resource outside of the bucket: {resource:?}"),
+            Self::UnsupportedConditionOperator(operator) => write!(f, "Unsupported condition operator: {operator}"),
+            Self::UnsupportedConditionKey(key) => write!(f, "Unsupported condition key: {key}"),
+            Self::InvalidConditionValue { operator, value } => {
+                write!(f, "Invalid value for condition operator {operator}: {value:?}")
+            }
+            Self::InvalidVariable(value) => write!(f, "Invalid policy variable: {value:?}"),
+            Self::UnreachableAllow { denied_by } => write!(f, "Allow statement is always denied by statement {denied_by}"),
+        }
+    }
+}
+
+impl FindingKind {
+    #[must_use]
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::UnreachableAllow { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl FindingKind {
+    fn from_condition_error(operator: &str, err: PolicyError) -> Self {
+        match err {
+            PolicyError::UnsupportedConditionOperator(operator) => Self::UnsupportedConditionOperator(operator),
+            PolicyError::InvalidVariable(value) => Self::InvalidVariable(value),
+            PolicyError::InvalidConditionValue { operator, value } => Self::InvalidConditionValue { operator, value },
+            PolicyError::InvalidPattern(value) => Self::InvalidConditionValue {
+                operator: operator.to_owned(),
+                value,
+            },
+        }
+    }
+}
+
+/// The S3 actions that only exist in IAM policies, without a matching operation.
+///
+/// <https://docs.aws.amazon.com/service-authorization/latest/reference/list_amazons3.html>
+const IAM_ONLY_ACTIONS: &[&str] = &[
+    "s3:BypassGovernanceRetention",
+    "s3:GetObjectVersionForReplication",
+    "s3:GetObjectVersionTorrent",
+    "s3:InitiateReplication",
+    "s3:ObjectOwnerOverrideToBucketOwner",
+    "s3:ReplicateDelete",
+    "s3:ReplicateObject",
+    "s3:ReplicateTags",
+];
+
+/// Returns `true` if the action matches the IAM action of an S3 operation or an IAM-only S3 action.
+fn is_known_action(action: &str) -> bool {
+    let pattern = action.to_ascii_lowercase();
+    known_actions().iter().any(|a| wildcard_match(&pattern, a))
+}
+
+/// The lowercase IAM actions of all S3 operations, and the IAM-only S3 actions.
+fn known_actions() -> &'static BTreeSet<String> {
+    static ACTIONS: OnceLock<BTreeSet<String>> = OnceLock::new();
+    ACTIONS.get_or_init(|| {
+        S3Operation::names()
+            .iter()
+            .flat_map(|op| [action_name(op, false), action_name(op, true)])
+            .chain(IAM_ONLY_ACTIONS.iter().map(|&a| a.to_owned()))
+            .map(|a| a.to_ascii_lowercase())
+            .collect()
+    })
+}
+
+fn wildcard_match(pattern: &str, input: &str) -> bool {
+    match_tokens(&tokenize(pattern).collect::<Vec<_>>(), input)
+}
+
+/// Returns `true` if `deny` is an unconditional `Deny` statement that applies to every request of `allow`.
+fn shadows(deny: &Statement, allow: &Statement) -> bool {
+    if deny.effect != Effect::Deny || deny.condition.is_some() {
+        return false;
+    }
+
+    let principal_covered = match (&deny.principal, &allow.principal) {
+        (None | Some(PrincipalRule::Principal(Principal::Wildcard)), _) => true,
+        (Some(PrincipalRule::Principal(Principal::Map(map))), _) if is_wildcard_map(map) => true,
+        (deny, allow) => deny == allow,
+    };
+    let action_covered = match (&deny.action, &allow.action) {
+        (ActionRule::Action(deny), ActionRule::Action(allow)) => covers_all(deny, allow, true),
+        _ => false,
+    };
+    let resource_covered = match (&deny.resource, &allow.resource) {
+        (ResourceRule::Resource(deny), ResourceRule::Resource(allow)) => covers_all(deny, allow, false),
+        _ => false,
+    };
+    principal_covered && action_covered && resource_covered
+}
+
+/// Returns `true` if the principal matches everyone, such as `{"AWS": "*"}`.
+fn is_wildcard_map(map: &IndexMap<String, OneOrMore<String>>) -> bool {
+    map.values().any(|ids| ids.as_slice().iter().any(|id| id == "*"))
+}
+
+/// Returns `true` if every value matched by `allow` is matched by `deny`.
+fn covers_all(deny: &WildcardOneOrMore<String>, allow: &WildcardOneOrMore<String>, ignore_case: bool) -> bool {
+    let Some(deny) = deny.as_slice() else { return true };
+    let normalize = |s: &str| if ignore_case { s.to_ascii_lowercase() } else { s.to_owned() };
+    let deny: Vec<String> = deny.iter().filter(|d| !d.contains("${")).map(|d| normalize(d)).collect();
+    let covers = |a: &str| {
+        if a.contains("${") {
+            return deny.iter().any(|d| d == "*");
+        }
+        deny.iter().any(|d| covers(d.as_bytes(), a.as_bytes()))
+    };
+    match allow.as_slice() {
+        None => covers("*"),
+        Some(allow) => !allow.is_empty() && allow.iter().all(|a| covers(&normalize(a))),
+    }
+}
+
+/// The maximum number of pattern position pairs that [`covers`] checks.
+const MAX_COVERS_WORK: usize = 1 << 20;
+
+/// Returns `true` if every string matched by the pattern `a` is matched by the pattern `p`.
+///
+/// The check is conservative: `*` in `a` must be covered by `*` in `p`,
+/// and `?` in `a` must be covered by `?` or `*` in `p`.
+///
+/// It takes `O(p.len() * a.len())` time. Patterns that exceed [`MAX_COVERS_WORK`] are not covered.
+fn covers(p: &[u8], a: &[u8]) -> bool {
+    if p.len().saturating_mul(a.len() + 1) > MAX_COVERS_WORK {
+        return false;
+    }
+
+    // `next[j]` is whether `p[i + 1..]` covers `a[j..]`, `cur[j]` is whether `p[i..]` covers `a[j..]`
+    let mut next = vec![false; a.len() + 1];
+    next[a.len()] = true;
+    let mut cur = vec![false; a.len() + 1];
+    for &pc in p.iter().rev() {
+        for j in (0..=a.len()).rev() {
+            cur[j] = match (pc, a.get(j)) {
+                (b'*', _) => next[j] || (j < a.len() && cur[j + 1]),
+                (b'?', Some(&c)) => c != b'*' && next[j + 1],
+                (pc, Some(&c)) => pc == c && !matches!(c, b'*' | b'?') && next[j + 1],
+                _ => false,
+            };
+        }
+        std::mem::swap(&mut cur, &mut next);
+    }
+    next[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(validator: &Validator, json: &str) -> Vec<(Option<usize>, FindingKind)> {
+        let policy: Policy = serde_json::from_str(json).unwrap();
+        let report = validator.validate(&policy);
+        report.findings.into_iter().map(|f| (f.statement, f.kind)).collect()
+    }
+
+    #[test]
+    fn valid_policies() {
+        let validator = Validator::new().bucket("bucket");
+        let json = r#"{
+            "Version": "2012-10-17",
+            "Statement": [
+                {
+                    "Effect": "Allow",
+                    "Principal": {"AWS": "arn:aws:iam::123456789012:user/alice"},
+                    "Action": ["s3:GetObject", "s3:GetObjectVersion", "s3:Put*", "s3:ListBucket"],
+                    "Resource": ["arn:aws:s3:::bucket", "arn:aws:s3:::bucket/*", "arn:aws:s3:::buck*/home/${aws:username}/*"],
+                    "Condition": {
+                        "StringLike": {"s3:prefix": "home/${aws:username}/*"},
+                        "Bool": {"aws:SecureTransport": "true"},
+                        "IpAddress": {"aws:SourceIp": "192.0.2.0/24"},
+                        "StringEquals": {"s3:ExistingObjectTag/team": "a", "s3:x-amz-acl": "private"}
+                    }
+                },
+                {"Effect": "Deny", "Principal": "*", "Action": "s3:*", "NotResource": "arn:aws:s3:::other/*"}
+            ]
+        }"#;
+        assert_eq!(validate(&validator, json), []);
+
+        let json = r#"{"Statement": {"Effect": "Allow", "Action": "*", "Resource": "*"}}"#;
+        assert_eq!(validate(&validator, json), []);
+
+        // IAM-only actions
+        let json = r#"{
+            "Statement": {
+                "Effect": "Allow",
+                "Principal": {"AWS": "arn:aws:iam::123456789012:role/replication"},
+                "Action": [
+                    "s3:ReplicateObject",
+                    "s3:ReplicateDelete",
+                    "s3:ReplicateTags",
+                    "s3:GetObjectVersionForReplication",
+                    "s3:ObjectOwnerOverrideToBucketOwner",
+                    "s3:BypassGovernanceRetention",
+                    "s3:GetObjectVersionTagging"
+                ],
+                "Resource": "arn:aws:s3:::bucket/*"
+            }
+        }"#;
+        assert_eq!(validate(&validator, json), []);
+    }
+
+    #[test]
+    fn errors() {
+        let validator = Validator::new().bucket("bucket");
+
+        let json = r#"{"Statement": []}"#;
+        assert_eq!(validate(&validator, json), [(None, FindingKind::EmptyPolicy)]);
+
+        let json = r#"{
+            "Version": "2012-10-17",
+            "Statement": [
+                {
+                    "Effect": "Allow",
+                    "Principal": {"AWS": []},
+                    "Action": ["s3:GetObjekt", "ec2:*", "S3:get*"],
+                    "Resource": ["arn:aws:s3:::other/*", "bucket/*", "arn:aws:ec2:::bucket", "arn:aws:s3:::bucket/${aws:username"]
+                },
+                {
+                    "Effect": "Deny",
+                    "Action": [],
+                    "Resource": "arn:aws:s3:::bucket/*",
+                    "Condition": {
+                        "StringEqualz": {"s3:prefix": "a"},
+                        "StringEquals": {"aws:PrincipalTag/team": "a", "s3:prefix": "${aws:PrincipalTag/team}"},
+                        "NumericLessThan": {"s3:max-keys": "ten"}
+                    }
+                }
+            ]
+        }"#;
+        let expected = [
+            (Some(0), FindingKind::EmptyStatement { element: "Principal" }),
+            (Some(0), FindingKind::UnknownAction("s3:GetObjekt".to_owned())),
+            (Some(0), FindingKind::UnknownAction("ec2:*".to_owned())),
+            (Some(0), FindingKind::ResourceOutsideBucket("arn:aws:s3:::other/*".to_owned())),
+            (Some(0), FindingKind::MalformedArn("bucket/*".to_owned())),
+            (Some(0), FindingKind::ResourceOutsideBucket("arn:aws:ec2:::bucket".to_owned())),
+            (Some(0), FindingKind::InvalidVariable("arn:aws:s3:::bucket/${aws:username".to_owned())),
+            (Some(1), FindingKind::EmptyStatement { element: "Action" }),
+            (Some(1), FindingKind::UnsupportedConditionOperator("StringEqualz".to_owned())),
+            (Some(1), FindingKind::UnsupportedConditionKey("aws:PrincipalTag/team".to_owned())),
+            (Some(1), FindingKind::UnsupportedConditionKey("aws:PrincipalTag/team".to_owned())),
+            (
+                Some(1),
+                FindingKind::InvalidConditionValue {
+                    operator: "NumericLessThan".to_owned(),
+                    value: "ten".to_owned(),
+                },
+            ),
+        ];
+        assert_eq!(validate(&validator, json), expected);
+
+        let validator = Validator::new().condition_key("aws:PrincipalTag/team");
+        let json = r#"{"Statement": {"Effect": "Allow", "Action": "s3:GetObject", "Resource": "arn:aws:s3:::other/*", "Condition": {"StringEquals": {"aws:principaltag/team": "a"}}}}"#;
+        assert_eq!(validate(&validator, json), []);
+    }
+
+    #[test]
+    fn unreachable_allow() {
+        let validator = Validator::new();
+        let json = r#"{
+            "Version": "2012-10-17",
+            "Statement": [
+                {"Sid": "ReadAll", "Effect": "Allow", "Principal": "*", "Action": "s3:Get*", "Resource": "arn:aws:s3:::bucket/*"},
+                {"Sid": "ReadPublic", "Effect": "Allow", "Principal": "*", "Action": "s3:GetObject", "Resource": "arn:aws:s3:::bucket/public/*"},
+                {"Sid": "ReadHome", "Effect": "Allow", "Principal": "*", "Action": "s3:GetObject", "Resource": "arn:aws:s3:::bucket/home/${aws:username}/*"},
+                {"Sid": "ListAll", "Effect": "Allow", "Principal": "*", "Action": "s3:ListBucket", "Resource": "arn:aws:s3:::bucket"},
+                {"Sid": "DenyRead", "Effect": "Deny", "Principal": {"AWS": "*"}, "Action": "s3:get*", "Resource": "arn:aws:s3:::bucket/*"},
+                {"Sid": "DenyListIfInsecure", "Effect": "Deny", "Principal": "*", "Action": "s3:ListBucket", "Resource": "*", "Condition": {"Bool": {"aws:SecureTransport": "false"}}}
+            ]
+        }"#;
+        let policy: Policy = serde_json::from_str(json).unwrap();
+        let report = validator.validate(&policy);
+        assert!(report.is_valid());
+        assert!(report.to_s3_error().is_none());
+
+        let warnings: Vec<_> = report.warnings().map(|f| (f.sid.as_deref(), &f.kind)).collect();
+        assert_eq!(
+            warnings,
+            [
+                (Some("ReadAll"), &FindingKind::UnreachableAllow { denied_by: 4 }),
+                (Some("ReadPublic"), &FindingKind::UnreachableAllow { denied_by: 4 }),
+            ]
+        );
+        assert_eq!(
+            report.warnings().next().unwrap().to_string(),
+            "Statement 0 (\"ReadAll\"): Allow statement is always denied by statement 4"
+        );
+    }
+
+    #[test]
+    fn covers_patterns() {
+        let cases = [
+            ("*", "*", true),
+            ("*", "a?b", true),
+            ("a*", "a*", true),
+            ("a*", "ab*", true),
+            ("ab*", "a*", false),
+            ("a?", "a*", false),
+            ("a?", "a?", true),
+            ("a?", "ab", true),
+            ("*b", "a*", false),
+            ("*b", "a*b", true),
+            ("a", "a", true),
+            ("a", "b", false),
+            ("", "", true),
+            ("*", "", true),
+            ("", "a", false),
+            ("a**b", "a*b", true),
+        ];
+        for (p, a, expected) in cases {
+            assert_eq!(covers(p.as_bytes(), a.as_bytes()), expected, "{p:?} {a:?}");
+        }
+    }
+
+    #[test]
+    fn covers_pathological_patterns() {
+        // Exponential for a backtracking matcher
+        let deny = format!("arn:aws:s3:::b/{}b", "*a".repeat(32));
+        let allow = format!("arn:aws:s3:::b/{}", "a".repeat(60));
+        assert!(!covers(deny.as_bytes(), allow.as_bytes()));
+        let allow = format!("arn:aws:s3:::b/{}*ab", "a".repeat(60));
+        assert!(covers(deny.as_bytes(), allow.as_bytes()));
+
+        // The work per pair of patterns is bounded
+        let deny = "*".repeat(4096);
+        let allow = "a".repeat(4096);
+        assert!(!covers(deny.as_bytes(), allow.as_bytes()));
+
+        let validator = Validator::new();
+        let json = format!(
+            r#"{{
+                "Statement": [
+                    {{"Effect": "Allow", "Principal": "*", "Action": "s3:GetObject", "Resource": "arn:aws:s3:::b/{}"}},
+                    {{"Effect": "Deny", "Principal": "*", "Action": "s3:GetObject", "Resource": "arn:aws:s3:::b/{}ab"}}
+                ]
+            }}"#,
+            "a".repeat(60),
+            "*a".repeat(32),
+        );
+        let policy: Policy = serde_json::from_str(&json).unwrap();
+        assert!(validator.validate(&policy).warnings().next().is_none());
+    }
+
+    #[test]
+    fn malformed_policy_error() {
+        let json = r#"{"Statement": {"Sid": "S", "Effect": "Allow", "Action": "s3:Nope", "Resource": "*"}}"#;
+        let policy: Policy = serde_json::from_str(json).unwrap();
+        let err = Validator::new().validate(&policy).to_s3_error().unwrap();
+        assert_eq!(*err.code(), S3ErrorCode::MalformedPolicy);
+        assert_eq!(err.message(), Some("Statement 0 (\"S\"): Unknown action: \"s3:Nope\""));
+    }
+}
@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Policy variables
+//!
+//! <https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_variables.html>
+//!
+//! A policy value can refer to a condition key of the request, such as
+//! `arn:aws:s3:::bucket/home/${aws:username}/*`:
+//!
+//! - `${key}` is replaced with the value of the condition key.
+//! - `${key, 'default'}` is replaced with `default` if the key is missing.
+//! - `${*}`, `${?}` and `${$}` are replaced with the literal characters `*`, `?` and `$`.
+//!
+//! The substituted values never act as wildcards.
+//! A value does not match anything if a variable has no value and no default,
+//! or if the condition key has multiple values.
+
+use crate::eval::{PolicyError, RequestContext};
+use crate::pattern::{Token, tokenize};
+
+/// A policy value with variables.
+#[derive(Debug)]
+pub(crate) struct Template {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug)]
+enum Part {
+    /// Text outside of variables, whose wildcards are kept.
+    Text(String),
+    /// A literal character from `${*}`, `${?}` or `${$}`.
+    Escaped(char),
+    Variable {
+        key: String,
+        default: Option<String>,
+    },
+}
+
+impl Template {
+    /// Parses a policy value.
+    ///
+    /// Returns `None` if the value does not contain any variable.
+    pub fn parse(s: &str) -> Result<Option<Self>, PolicyError> {
+        if !s.contains("${") {
+            return Ok(None);
+        }
+
+        let invalid = || PolicyError::InvalidVariable(s.to_owned());
+
+        let mut parts = Vec::new();
+        let mut rest = s;
+        while let Some(start) = rest.find("${") {
+            if start > 0 {
+                parts.push(Part::Text(rest[..start].to_owned()));
+            }
+            let after = &rest[start + 2..];
+            let end = after.find('}').ok_or_else(invalid)?;
+            parts.push(Self::parse_variable(&after[..end]).ok_or_else(invalid)?);
+            rest = &after[end + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Text(rest.to_owned()));
+        }
+        Ok(Some(Self { parts }))
+    }
+
+    fn parse_variable(s: &str) -> Option<Part> {
+        let s = s.trim();
+        match s {
+            "*" => return Some(Part::Escaped('*')),
+            "?" => return Some(Part::Escaped('?')),
+            "$" => return Some(Part::Escaped('$')),
+            _ => {}
+        }
+
+        let (key, default) = match s.split_once(',') {
+            Some((key, default)) => {
+                let default = default.trim().strip_prefix('\'')?.strip_suffix('\'')?;
+                (key.trim_end(), Some(default.to_owned()))
+            }
+            None => (s, None),
+        };
+        let is_valid_key = !key.is_empty() && !key.contains(|c: char| c.is_whitespace() || matches!(c, '$' | '{' | '\'' | ','));
+        is_valid_key.then(|| Part::Variable {
+            key: key.to_owned(),
+            default,
+        })
+    }
+
+    /// Returns the condition keys that are referenced by the variables.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(|p| match p {
+            Part::Variable { key, .. } => Some(key.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Resolves the value into a wildcard pattern.
+    ///
+    /// Returns `None` if a variable can not be resolved.
+    pub fn resolve_pattern(&self, ctx: &RequestContext) -> Option<Vec<Token>> {
+        let mut tokens = Vec::new();
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => tokens.extend(tokenize(text)),
+                Part::Escaped(c) => tokens.extend(literal(c.encode_utf8(&mut [0; 4]))),
+                Part::Variable { key, default } => tokens.extend(literal(resolve(ctx, key, default.as_deref())?)),
+            }
+        }
+        Some(tokens)
+    }
+
+    /// Resolves the value into a string.
+    ///
+    /// Returns `None` if a variable can not be resolved.
+    pub fn resolve_string(&self, ctx: &RequestContext) -> Option<String> {
+        let mut ans = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => ans.push_str(text),
+                Part::Escaped(c) => ans.push(*c),
+                Part::Variable { key, default } => ans.push_str(resolve(ctx, key, default.as_deref())?),
+            }
+        }
+        Some(ans)
+    }
+}
+
+fn resolve<'a>(ctx: &'a RequestContext, key: &str, default: Option<&'a str>) -> Option<&'a str> {
+    match ctx.condition_key(key) {
+        Some([value]) => Some(value),
+        None | Some([]) => default,
+        Some(_) => None,
+    }
+}
+
+fn literal(s: &str) -> impl Iterator<Item = Token> + '_ {
+    s.bytes().map(Token::Byte)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::pattern::match_tokens;
+
+    #[test]
+    fn parse() {
+        assert!(Template::parse("arn:aws:s3:::bucket/*").unwrap().is_none());
+        assert!(Template::parse("$100").unwrap().is_none());
+
+        let valid = [
+            "home/${aws:username}/*",
+            "${aws:username}",
+            "${ aws:username , 'anonymous' }",
+            "${aws:username,''}",
+            "${*}${?}${$}",
+            "a${s3:ExistingObjectTag/team}b",
+        ];
+        for s in valid {
+            assert!(Template::parse(s).unwrap().is_some(), "{s:?}");
+        }
+
+        let invalid = [
+            "${aws:username",
+            "${}",
+            "${ }",
+            "${aws:username, anonymous}",
+            "${aws:username, 'anonymous}",
+            "${a b}",
+            "${${aws:username}}",
+        ];
+        for s in invalid {
+            assert!(Template::parse(s).is_err(), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn resolve() {
+        let ctx = RequestContext::new("s3:GetObject", "arn:aws:s3:::bucket/home/alice/a.txt")
+            .with_condition_key("aws:username", ["alice"])
+            .with_condition_key("aws:SourceIp", Vec::<String>::new())
+            .with_condition_key("s3:prefix", ["*"])
+            .with_condition_key("aws:groups", ["a", "b"]);
+
+        let cases: &[(&str, Option<&str>)] = &[
+            ("home/${aws:username}/*", Some("home/alice/*")),
+            ("home/${AWS:UserName}/", Some("home/alice/")),
+            ("${aws:userid, 'nobody'}", Some("nobody")),
+            ("${aws:username, 'nobody'}", Some("alice")),
+            ("${aws:SourceIp, ''}x", Some("x")),
+            ("${*}${?}${$}", Some("*?$")),
+            ("${aws:userid}", None),
+            ("${aws:groups}", None),
+        ];
+        for &(s, expected) in cases {
+            let template = Template::parse(s).unwrap().unwrap();
+            assert_eq!(template.resolve_string(&ctx).as_deref(), expected, "{s:?}");
+        }
+
+        let cases: &[(&str, &str, bool)] = &[
+            ("home/${aws:username}/*", "home/alice/a.txt", true),
+            ("home/${aws:username}/*", "home/bob/a.txt", false),
+            ("home/${aws:username}/?", "home/alice/a", true),
+            ("${*}", "*", true),
+            ("${*}", "a", false),
+            ("a${?}", "a?", true),
+            ("a${?}", "ab", false),
+            ("${s3:prefix}", "*", true),
+            ("${s3:prefix}", "a", false),
+            ("${aws:userid}*", "a", false),
+        ];
+        for &(s, input, expected) in cases {
+            let template = Template::parse(s).unwrap().unwrap();
+            let ans = template.resolve_pattern(&ctx).is_some_and(|p| match_tokens(&p, input));
+            assert_eq!(ans, expected, "{s:?} {input:?}");
+        }
+    }
+}
@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+use super::S3AccessContext;
+
+use crate::error::S3Result;
+use crate::http::OrderedQs;
+use crate::path::S3Path;
+
+/// Access policy for anonymous requests.
+///
+/// When configured via
+/// [`S3ServiceBuilder::set_anonymous_access`](crate::service::S3ServiceBuilder::set_anonymous_access),
+/// `check` is called for every S3 operation request without credentials,
+/// whether or not an auth provider is configured.
+/// It replaces [`S3Access::check`](super::S3Access::check) for these requests.
+/// Signed requests are not affected.
+#[async_trait::async_trait]
+pub trait AnonymousAccess: Send + Sync + 'static {
+    /// Checks whether the anonymous request is allowed.
+    ///
+    /// Returning an error denies the request.
+    async fn check(&self, cx: &mut S3AccessContext<'_>) -> S3Result<()>;
+}
+
+/// An [`AnonymousAccess`] that allows public reads on specific buckets and prefixes.
+///
+/// The allowed operations are:
+///
+/// - `GetObject` and `HeadObject` on the matching objects
+/// - `HeadBucket` on the buckets that are public as a whole
+/// - `ListObjects` and `ListObjectsV2` on the public buckets, or with a `prefix`
+///   parameter that starts with a public prefix
+///
+/// All other anonymous requests are denied with `AccessDenied`.
+///
+/// # Example
+///
+/// ```
+/// use s3s::access::PublicReadAccess;
+///
+/// let mut public = PublicReadAccess::new();
+/// public.add_bucket("website");
+/// public.add_prefix("assets", "public/");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct PublicReadAccess {
+    /// `(bucket, prefix)`, where an empty prefix matches the whole bucket
+    rules: Vec<(String, String)>,
+}
+
+impl PublicReadAccess {
+    /// Constructs an empty `PublicReadAccess`, which denies all anonymous requests.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows anonymous reads on the whole bucket.
+    pub fn add_bucket(&mut self, bucket: impl Into<String>) {
+        self.rules.push((bucket.into(), String::new()));
+    }
+
+    /// Allows anonymous reads on the objects whose keys start with `prefix`.
+    pub fn add_prefix(&mut self, bucket: impl Into<String>, prefix: impl Into<String>) {
+        self.rules.push((bucket.into(), prefix.into()));
+    }
+
+    fn matches(&self, bucket: &str, key: &str) -> bool {
+        self.rules.iter().any(|(b, p)| b == bucket && key.starts_with(p.as_str()))
+    }
+
+    fn is_allowed(&self, cx: &S3AccessContext<'_>) -> bool {
+        match (cx.s3_op().name(), cx.s3_path()) {
+            ("GetObject" | "HeadObject", S3Path::Object { bucket, key }) => self.matches(bucket, key),
+            ("HeadBucket", S3Path::Bucket { bucket }) => self.matches(bucket, ""),
+            ("ListObjects" | "ListObjectsV2", S3Path::Bucket { bucket }) => {
+                let qs = cx.uri().query().and_then(|q| OrderedQs::parse(q).ok());
+                let prefix = qs.as_ref().and_then(|qs| qs.get_unique("prefix")).unwrap_or_default();
+                self.matches(bucket, prefix)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AnonymousAccess for PublicReadAccess {
+    async fn check(&self, cx: &mut S3AccessContext<'_>) -> S3Result<()> {
+        if self.is_allowed(cx) {
+            Ok(())
+        } else {
+            Err(s3_error!(AccessDenied, "Signature is required"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::S3Operation;
+
+    use hyper::HeaderMap;
+    use hyper::Method;
+    use hyper::Uri;
+    use hyper::http::Extensions;
+
+    fn is_allowed(access: &PublicReadAccess, op: &'static str, path: &S3Path, uri: &str) -> bool {
+        let uri: Uri = uri.parse().unwrap();
+        let headers = HeaderMap::new();
+        let mut extensions = Extensions::new();
+        let cx = S3AccessContext {
+            credentials: None,
+            s3_path: path,
+            s3_op: &S3Operation { name: op },
+            method: &Method::GET,
+            uri: &uri,
+            headers: &headers,
+            extensions: &mut extensions,
+        };
+        access.is_allowed(&cx)
+    }
+
+    #[test]
+    fn public_read_rules() {
+        let mut access = PublicReadAccess::new();
+        access.add_bucket("website");
+        access.add_prefix("assets", "public/");
+
+        let obj = |b, k| S3Path::object(b, k);
+        let bucket = S3Path::bucket;
+
+        assert!(is_allowed(&access, "GetObject", &obj("website", "index.html"), "/website/index.html"));
+        assert!(is_allowed(&access, "HeadObject", &obj("assets", "public/a.png"), "/assets/public/a.png"));
+        assert!(!is_allowed(
+            &access,
+            "GetObject",
+            &obj("assets", "private/a.png"),
+            "/assets/private/a.png"
+        ));
+        assert!(!is_allowed(&access, "PutObject", &obj("website", "index.html"), "/website/index.html"));
+        assert!(!is_allowed(&access, "GetObject", &obj("other", "index.html"), "/other/index.html"));
+
+        assert!(is_allowed(&access, "HeadBucket", &bucket("website"), "/website"));
+        assert!(!is_allowed(&access, "HeadBucket", &bucket("assets"), "/assets"));
+
+        assert!(is_allowed(&access, "ListObjectsV2", &bucket("website"), "/website?list-type=2"));
+        assert!(is_allowed(
+            &access,
+            "ListObjectsV2",
+            &bucket("assets"),
+            "/assets?list-type=2&prefix=public%2Fimg"
+        ));
+        assert!(!is_allowed(&access, "ListObjectsV2", &bucket("assets"), "/assets?list-type=2"));
+        assert!(!is_allowed(&access, "ListObjects", &bucket("assets"), "/assets?prefix=private%2F"));
+
+        assert!(!is_allowed(
+            &PublicReadAccess::new(),
+            "GetObject",
+            &obj("website", "index.html"),
+            "/website/index.html"
+        ));
+    }
+}
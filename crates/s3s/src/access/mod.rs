@@ -31,6 +31,19 @@
 //! > return `None`, and your `S3Access` implementation is responsible for deciding
 //! > whether to allow or deny the operation.
 //!
+//! # Anonymous access
+//!
+//! An [`AnonymousAccess`] policy can be configured via
+//! [`S3ServiceBuilder::set_anonymous_access`](crate::service::S3ServiceBuilder::set_anonymous_access)
+//! to decide which requests are allowed without a signature, for example public reads
+//! on specific buckets or prefixes ([`PublicReadAccess`]).
+//!
+//! When an anonymous access policy is configured, every S3 operation request without
+//! credentials is checked by it instead of `S3Access::check`, whether or not an auth
+//! provider is configured. Requests with credentials are checked by `S3Access::check` as
+//! usual. The per-operation `S3Access` methods still run for all requests.
+//! Custom routes are not affected; they use [`S3Route::check_access`](crate::route::S3Route::check_access).
+//!
 //! # Example
 //!
 //! ```
@@ -110,6 +123,9 @@ pub use self::generated::S3Access;
 mod context;
 pub use self::context::S3AccessContext;
 
+mod anonymous;
+pub use self::anonymous::{AnonymousAccess, PublicReadAccess};
+
 use crate::error::S3Result;
 
 pub(crate) fn default_check(cx: &mut S3AccessContext<'_>) -> S3Result<()> {
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+use super::SecretKey;
+
+/// The identity of an authenticated principal.
+///
+/// All fields are optional: an [`S3Auth`](super::S3Auth) implementation fills in
+/// whatever its credential store knows about the owner of an access key.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Identity {
+    /// The unique identifier of the principal (`aws:userid`).
+    pub user_id: Option<String>,
+    /// The friendly name of the principal (`aws:username`).
+    pub username: Option<String>,
+    /// The account that owns the principal (`aws:PrincipalAccount`).
+    pub account_id: Option<String>,
+    /// The ARN of the principal (`aws:PrincipalArn`).
+    pub arn: Option<String>,
+    /// The groups that the principal belongs to.
+    pub groups: Vec<String>,
+    /// The principal that requested the temporary credentials of this identity,
+    /// such as the caller of STS `AssumeRole`. `None` for long-term credentials.
+    pub parent: Option<Box<ParentPrincipal>>,
+}
+
+/// The principal that requested temporary credentials.
+///
+/// Access control should apply the restrictions of the parent principal to the temporary credentials,
+/// so that they cannot be used to escape a deny of the parent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParentPrincipal {
+    /// The access key that signed the request for the temporary credentials.
+    pub access_key: String,
+    /// The identity of the access key. `None` if unknown.
+    pub identity: Option<Identity>,
+}
+
+/// The secret key of an access key, together with the identity of its owner.
+///
+/// Returned by [`S3Auth::get_access_key_info`](super::S3Auth::get_access_key_info).
+#[derive(Debug, Clone)]
+pub struct AccessKeyInfo {
+    /// The secret key associated with the access key.
+    pub secret_key: SecretKey,
+    /// The identity of the principal. `None` if unknown.
+    pub identity: Option<Identity>,
+}
+
+impl From<SecretKey> for AccessKeyInfo {
+    fn from(secret_key: SecretKey) -> Self {
+        Self {
+            secret_key,
+            identity: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_secret_key() {
+        let info = AccessKeyInfo::from(SecretKey::from("secret"));
+        assert_eq!(info.secret_key.expose(), "secret");
+        assert!(info.identity.is_none());
+    }
+
+    #[test]
+    fn debug_hides_secret_key() {
+        let info = AccessKeyInfo {
+            secret_key: SecretKey::from("hunter2"),
+            identity: Some(Identity {
+                user_id: Some("AIDAEXAMPLE".to_owned()),
+                ..Default::default()
+            }),
+        };
+        let debug = format!("{info:?}");
+        assert!(debug.contains("AIDAEXAMPLE"));
+        assert!(!debug.contains("hunter2"));
+    }
+}
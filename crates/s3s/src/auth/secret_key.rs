@@ -1,7 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023-2026 The s3s Authors
 
+use super::Identity;
+
 use std::fmt;
+use std::sync::Arc;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -10,13 +13,38 @@ use zeroize::Zeroize;
 
 /// Authenticated S3 credentials.
 ///
-/// Contains the access key ID and the corresponding [`SecretKey`].
+/// Contains the access key ID, the corresponding [`SecretKey`],
+/// and the [`Identity`] of the principal if the auth provider returned one.
+///
+/// This struct is non-exhaustive. Use [`Credentials::new`] to construct it.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct Credentials {
     /// The access key ID.
     pub access_key: String,
     /// The secret key associated with the access key.
     pub secret_key: SecretKey,
+    /// The identity of the principal that owns the access key.
+    pub identity: Option<Arc<Identity>>,
+}
+
+impl Credentials {
+    /// Creates credentials without an identity.
+    #[must_use]
+    pub fn new(access_key: impl Into<String>, secret_key: impl Into<SecretKey>) -> Self {
+        Self {
+            access_key: access_key.into(),
+            secret_key: secret_key.into(),
+            identity: None,
+        }
+    }
+
+    /// Sets the identity of the principal that owns the access key.
+    #[must_use]
+    pub fn with_identity(mut self, identity: impl Into<Arc<Identity>>) -> Self {
+        self.identity = Some(identity.into());
+        self
+    }
 }
 
 /// An S3 secret key.
@@ -170,6 +198,7 @@ mod tests {
         let creds = Credentials {
             access_key: "AKID".to_owned(),
             secret_key: SecretKey::from("hunter2"),
+            identity: None,
         };
         let debug = format!("{creds:?}");
         assert!(debug.contains("AKID"));
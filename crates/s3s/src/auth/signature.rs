@@ -55,10 +55,10 @@ impl Signature {
         (base64_simd::STANDARD.decoded_length(value.as_bytes()).ok()? == 20).then(|| Self(value.into()))
     }
 
-    /// Wraps a signature produced by `sig_v4::calculate_signature` /
+    /// Wraps a signature produced by `sig_v4::calculate_signature_with_key` /
     /// `sig_v2::calculate_signature`, whose output is canonical by construction.
     ///
-    /// Called only inside the two `calculate_signature` functions.
+    /// Called only inside these two functions.
     /// Do not use for data received from the wire; use [`Self::from_hex`] /
     /// [`Self::from_base64`] instead.
     #[must_use]
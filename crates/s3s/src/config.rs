@@ -42,6 +42,7 @@
 //! ```
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use arc_swap::ArcSwap;
 use serde::{Deserialize, Serialize};
@@ -146,6 +147,7 @@ pub struct S3Config {
     ///
     /// When set, requests signed for another region are rejected with
     /// `AuthorizationHeaderMalformed`. When unset, any region is accepted.
+    /// For `SigV4A`, the signed `x-amz-region-set` must include this region.
     ///
     /// Default: None
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -187,6 +189,55 @@ pub struct S3Config {
     ///
     /// Default: false
     pub normalize_forward_slash_path: bool,
+
+    /// Whether to include signature diagnostics in `SignatureDoesNotMatch` errors.
+    ///
+    /// If enabled, the error body contains the `StringToSign`, `CanonicalRequest` and
+    /// `SignatureProvided` elements computed by the server, like AWS S3 does.
+    /// This helps debugging client signing bugs, but the canonical request exposes
+    /// the signed headers and query parameters of the request to the client.
+    ///
+    /// Default: false
+    pub signature_error_details: bool,
+
+    /// Maximum time in seconds to wait for the next chunk of the request body.
+    ///
+    /// If the client stops sending the body for longer than this, reading the body fails
+    /// and the request is rejected with `RequestTimeout`.
+    /// This bounds slow uploads that would otherwise hold a handler forever.
+    ///
+    /// Default: None (no timeout)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_idle_timeout_secs: Option<u32>,
+
+    /// Maximum time in seconds to complete an operation.
+    ///
+    /// The timeout starts when the operation is resolved, after the signature check,
+    /// and covers the body deserialization, the handler and the response serialization.
+    /// If it expires, the handler is cancelled and the request is rejected with `RequestTimeout`.
+    /// Streaming response bodies are not covered.
+    ///
+    /// This applies to operations whose class has no specific timeout. See [`OperationClass`].
+    ///
+    /// Default: None (no timeout)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_timeout_secs: Option<u32>,
+
+    /// Maximum time in seconds to complete a [listing operation](OperationClass::List).
+    ///
+    /// Falls back to [`request_timeout_secs`](Self::request_timeout_secs) if unset.
+    ///
+    /// Default: None
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_request_timeout_secs: Option<u32>,
+
+    /// Maximum time in seconds to complete a [data-plane operation](OperationClass::Data).
+    ///
+    /// Falls back to [`request_timeout_secs`](Self::request_timeout_secs) if unset.
+    ///
+    /// Default: None
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_request_timeout_secs: Option<u32>,
 }
 
 impl Default for S3Config {
@@ -202,6 +253,73 @@ impl Default for S3Config {
             enable_sig_v2: true,
             presigned_url_max_expires_secs: DEFAULT_PRESIGNED_URL_MAX_EXPIRES_SECS,
             normalize_forward_slash_path: false,
+            signature_error_details: false,
+            body_idle_timeout_secs: None,
+            request_timeout_secs: None,
+            list_request_timeout_secs: None,
+            data_request_timeout_secs: None,
+        }
+    }
+}
+
+impl S3Config {
+    /// Returns the idle timeout of request bodies.
+    #[must_use]
+    pub fn body_idle_timeout(&self) -> Option<Duration> {
+        self.body_idle_timeout_secs.map(|secs| Duration::from_secs(u64::from(secs)))
+    }
+
+    /// Returns the request timeout of an operation class.
+    #[must_use]
+    pub fn request_timeout(&self, class: OperationClass) -> Option<Duration> {
+        let secs = match class {
+            OperationClass::List => self.list_request_timeout_secs,
+            OperationClass::Data => self.data_request_timeout_secs,
+            OperationClass::Control => None,
+        };
+        secs.or(self.request_timeout_secs)
+            .map(|secs| Duration::from_secs(u64::from(secs)))
+    }
+}
+
+/// A class of S3 operations, used to select the request timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationClass {
+    /// Listing operations, like `ListObjectsV2` and `ListParts`.
+    List,
+    /// Operations that transfer object data, like `GetObject`, `PutObject` and `UploadPart`.
+    Data,
+    /// All other operations.
+    Control,
+}
+
+impl OperationClass {
+    /// Returns the class of an operation.
+    ///
+    /// # Example
+    /// ```
+    /// use s3s::config::OperationClass;
+    ///
+    /// assert_eq!(OperationClass::of("ListObjectsV2"), OperationClass::List);
+    /// assert_eq!(OperationClass::of("PutObject"), OperationClass::Data);
+    /// assert_eq!(OperationClass::of("CreateBucket"), OperationClass::Control);
+    /// ```
+    #[must_use]
+    pub fn of(op_name: &str) -> Self {
+        match op_name {
+            "GetObject"
+            | "PutObject"
+            | "PostObject"
+            | "CopyObject"
+            | "UploadPart"
+            | "UploadPartCopy"
+            | "CompleteMultipartUpload"
+            | "SelectObjectContent"
+            | "GetObjectTorrent"
+            | "WriteGetObjectResponse" => Self::Data,
+            _ if op_name.starts_with("List") => Self::List,
+            _ => Self::Control,
         }
     }
 }
@@ -321,6 +439,27 @@ mod tests {
         assert_eq!(config.presigned_url_max_skew_time_secs, 900);
         assert_eq!(config.expected_region, None);
         assert_eq!(config.presigned_url_max_expires_secs, DEFAULT_PRESIGNED_URL_MAX_EXPIRES_SECS);
+        assert!(!config.signature_error_details);
+        assert_eq!(config.body_idle_timeout(), None);
+        assert_eq!(config.request_timeout(OperationClass::Data), None);
+    }
+
+    #[test]
+    fn test_request_timeout() {
+        let config = S3Config {
+            body_idle_timeout_secs: Some(20),
+            request_timeout_secs: Some(90),
+            list_request_timeout_secs: Some(30),
+            ..Default::default()
+        };
+        assert_eq!(config.body_idle_timeout(), Some(Duration::from_secs(20)));
+        assert_eq!(config.request_timeout(OperationClass::List), Some(Duration::from_secs(30)));
+        assert_eq!(config.request_timeout(OperationClass::Data), Some(Duration::from_secs(90)));
+        assert_eq!(config.request_timeout(OperationClass::Control), Some(Duration::from_secs(90)));
+
+        assert_eq!(OperationClass::of("ListParts"), OperationClass::List);
+        assert_eq!(OperationClass::of("UploadPart"), OperationClass::Data);
+        assert_eq!(OperationClass::of("DeleteObjects"), OperationClass::Control);
     }
 
     #[test]
@@ -413,6 +552,11 @@ mod tests {
             enable_sig_v2: true,
             presigned_url_max_expires_secs: 86_400,
             normalize_forward_slash_path: false,
+            signature_error_details: true,
+            body_idle_timeout_secs: Some(20),
+            request_timeout_secs: Some(300),
+            list_request_timeout_secs: Some(30),
+            data_request_timeout_secs: None,
         };
 
         let json = serde_json::to_string(&config).expect("serialize failed");
@@ -0,0 +1,487 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Cross-origin resource sharing (CORS)
+//!
+//! When a CORS provider is configured via
+//! [`S3ServiceBuilder::set_cors`](crate::service::S3ServiceBuilder::set_cors) or
+//! [`S3ServiceBuilder::set_cors_from_s3`](crate::service::S3ServiceBuilder::set_cors_from_s3),
+//! [`S3Service`](crate::service::S3Service) handles CORS like Amazon S3:
+//!
+//! - `OPTIONS` requests to a bucket or an object are answered as preflight requests,
+//!   before authentication, unless they match the custom route. A preflight request is
+//!   rejected with `403 Forbidden` if the bucket has no CORS configuration or no rule
+//!   matches the origin, the requested method and the requested headers.
+//! - Responses to requests with an `Origin` header are decorated with the
+//!   `Access-Control-*` headers of the first rule that matches the origin and the method.
+//!   Headers that are already set by the S3 implementation are kept.
+//!
+//! Without a CORS provider, CORS requests are passed to the S3 implementation unchanged.
+//!
+//! # Rule matching
+//!
+//! - Allowed origins are compared case-sensitively and may contain one `*` wildcard.
+//! - Allowed methods are compared exactly.
+//! - Allowed headers are compared case-insensitively and may contain one `*` wildcard.
+//!   Every header in `Access-Control-Request-Headers` must be allowed.
+
+use crate::S3Request;
+use crate::dto::{CORSConfiguration, CORSRule, DeleteBucketCorsInput, GetBucketCorsInput, PutBucketCorsInput};
+use crate::error::{S3Error, S3ErrorCode, S3Result};
+use crate::intercept::{AfterContext, BeforeContext, S3Interceptor};
+use crate::s3_trait::S3;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use hyper::HeaderMap;
+use hyper::Method;
+use hyper::StatusCode;
+use hyper::Uri;
+use hyper::header::{self, HeaderName, HeaderValue};
+use hyper::http::Extensions;
+use tracing::warn;
+
+/// CORS configuration provider
+#[async_trait::async_trait]
+pub trait S3Cors: Send + Sync + 'static {
+    /// Returns the CORS configuration of the bucket.
+    ///
+    /// Returns `None` if CORS is not enabled for the bucket.
+    async fn get_cors_configuration(&self, bucket: &str) -> S3Result<Option<CORSConfiguration>>;
+}
+
+/// Applies the same CORS configuration to every bucket.
+#[async_trait::async_trait]
+impl S3Cors for CORSConfiguration {
+    async fn get_cors_configuration(&self, _bucket: &str) -> S3Result<Option<CORSConfiguration>> {
+        Ok(Some(self.clone()))
+    }
+}
+
+/// How long a CORS configuration resolved by [`CorsFromS3`] is cached
+const CACHE_TTL: Duration = Duration::from_mins(1);
+
+/// The maximum number of buckets whose CORS configuration is cached
+const CACHE_CAPACITY: usize = 1024;
+
+/// Resolves CORS configurations with `GetBucketCors` of the S3 implementation.
+///
+/// The configurations are cached per bucket until they expire,
+/// or until they are changed by `PutBucketCors` or `DeleteBucketCors` through the service.
+pub(crate) struct CorsFromS3 {
+    s3: Arc<dyn S3>,
+    cache: Arc<CorsCache>,
+}
+
+impl CorsFromS3 {
+    pub fn new(s3: Arc<dyn S3>) -> Self {
+        Self {
+            s3,
+            cache: Arc::default(),
+        }
+    }
+
+    /// Returns an interceptor that invalidates the cache when a CORS configuration is changed.
+    pub fn invalidation(&self) -> CorsInvalidation {
+        CorsInvalidation(Arc::clone(&self.cache))
+    }
+
+    async fn fetch(&self, bucket: &str) -> S3Result<Option<CORSConfiguration>> {
+        let input = GetBucketCorsInput::builder()
+            .bucket(bucket.to_owned())
+            .build()
+            .map_err(S3Error::internal_error)?;
+        let uri = Uri::try_from(format!("/{bucket}?cors")).map_err(S3Error::internal_error)?;
+
+        // An internal request without credentials
+        let req = S3Request {
+            input,
+            method: Method::GET,
+            uri,
+            headers: HeaderMap::new(),
+            extensions: Extensions::new(),
+            credentials: None,
+            region: None,
+            service: None,
+            trailing_headers: None,
+        };
+
+        match self.s3.get_bucket_cors(req).await {
+            Ok(resp) => Ok(resp.output.cors_rules.map(|cors_rules| CORSConfiguration { cors_rules })),
+            Err(e) => match e.code() {
+                S3ErrorCode::NoSuchCORSConfiguration | S3ErrorCode::NoSuchBucket | S3ErrorCode::NotImplemented => Ok(None),
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl S3Cors for CorsFromS3 {
+    async fn get_cors_configuration(&self, bucket: &str) -> S3Result<Option<CORSConfiguration>> {
+        if let Some(entry) = self.cache.get(bucket, Instant::now()) {
+            return Ok(entry.config);
+        }
+        let config = self.fetch(bucket).await?;
+        self.cache.insert(bucket, config.clone(), Instant::now());
+        Ok(config)
+    }
+}
+
+#[derive(Default)]
+struct CorsCache {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+#[derive(Clone)]
+struct CacheEntry {
+    config: Option<CORSConfiguration>,
+    expires_at: Instant,
+}
+
+impl CorsCache {
+    fn entries(&self) -> MutexGuard<'_, HashMap<String, CacheEntry>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get(&self, bucket: &str, now: Instant) -> Option<CacheEntry> {
+        let entries = self.entries();
+        entries.get(bucket).filter(|e| now < e.expires_at).cloned()
+    }
+
+    fn insert(&self, bucket: &str, config: Option<CORSConfiguration>, now: Instant) {
+        let mut entries = self.entries();
+        if entries.len() >= CACHE_CAPACITY && !entries.contains_key(bucket) {
+            entries.retain(|_, e| now < e.expires_at);
+            if entries.len() >= CACHE_CAPACITY {
+                let oldest = entries.iter().min_by_key(|(_, e)| e.expires_at).map(|(b, _)| b.clone());
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        let expires_at = now + CACHE_TTL;
+        entries.insert(bucket.to_owned(), CacheEntry { config, expires_at });
+    }
+
+    fn remove(&self, bucket: &str) {
+        self.entries().remove(bucket);
+    }
+}
+
+/// Invalidates the cached CORS configuration of a bucket that is changed through the service.
+pub(crate) struct CorsInvalidation(Arc<CorsCache>);
+
+/// The bucket whose CORS configuration is being changed
+#[derive(Clone)]
+struct ChangedBucket(String);
+
+#[async_trait::async_trait]
+impl S3Interceptor for CorsInvalidation {
+    async fn before(&self, cx: &mut BeforeContext<'_>) -> S3Result<()> {
+        let bucket = match (cx.input::<PutBucketCorsInput>(), cx.input::<DeleteBucketCorsInput>()) {
+            (Some(input), _) => input.bucket.clone(),
+            (_, Some(input)) => input.bucket.clone(),
+            _ => return Ok(()),
+        };
+        cx.state_mut().insert(ChangedBucket(bucket));
+        Ok(())
+    }
+
+    async fn after(&self, cx: &mut AfterContext<'_>) {
+        if let Some(ChangedBucket(bucket)) = cx.state().get() {
+            self.0.remove(bucket);
+        }
+    }
+}
+
+/// Finds the first rule that allows the origin, the method and all request headers.
+///
+/// The request headers are the values of `Access-Control-Request-Headers`.
+#[must_use]
+pub fn find_rule<'a>(
+    config: &'a CORSConfiguration,
+    origin: &str,
+    method: &str,
+    request_headers: &[&str],
+) -> Option<&'a CORSRule> {
+    config.cors_rules.iter().find(|rule| {
+        rule.allowed_origins.iter().any(|o| wildcard_match(o, origin))
+            && rule.allowed_methods.iter().any(|m| m == method)
+            && request_headers.iter().all(|h| {
+                let h = h.to_ascii_lowercase();
+                rule.allowed_headers
+                    .iter()
+                    .flatten()
+                    .any(|a| wildcard_match(&a.to_ascii_lowercase(), &h))
+            })
+    })
+}
+
+/// Matches `s` against a pattern with at most one `*` wildcard.
+fn wildcard_match(pattern: &str, s: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => s.len() >= prefix.len() + suffix.len() && s.starts_with(prefix) && s.ends_with(suffix),
+        None => pattern == s,
+    }
+}
+
+/// The CORS headers of a request.
+pub(crate) struct CorsRequest {
+    origin: HeaderValue,
+    method: String,
+    request_headers: Vec<String>,
+    preflight: bool,
+}
+
+impl CorsRequest {
+    /// Extracts the CORS headers of a request.
+    ///
+    /// Returns `None` if the request is not a CORS request.
+    pub fn extract(method: &Method, headers: &HeaderMap) -> S3Result<Option<Self>> {
+        let origin = headers.get(header::ORIGIN);
+
+        if *method != Method::OPTIONS {
+            return Ok(origin.map(|origin| Self {
+                origin: origin.clone(),
+                method: method.as_str().to_owned(),
+                request_headers: Vec::new(),
+                preflight: false,
+            }));
+        }
+
+        let request_method = headers
+            .get(header::ACCESS_CONTROL_REQUEST_METHOD)
+            .and_then(|v| v.to_str().ok());
+        let (Some(origin), Some(request_method)) = (origin, request_method) else {
+            let mut err = S3Error::with_message(
+                S3ErrorCode::Custom("BadRequest".into()),
+                "Insufficient information. Origin request header needed.",
+            );
+            err.set_status_code(StatusCode::BAD_REQUEST);
+            return Err(err);
+        };
+
+        let request_headers = headers
+            .get_all(header::ACCESS_CONTROL_REQUEST_HEADERS)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .map(str::trim)
+            .filter(|h| !h.is_empty())
+            .map(str::to_ascii_lowercase)
+            .collect();
+
+        Ok(Some(Self {
+            origin: origin.clone(),
+            method: request_method.to_owned(),
+            request_headers,
+            preflight: true,
+        }))
+    }
+
+    pub fn is_preflight(&self) -> bool {
+        self.preflight
+    }
+}
+
+fn forbidden(msg: &'static str) -> S3Error {
+    let mut err = S3Error::with_message(S3ErrorCode::Custom("AccessForbidden".into()), msg);
+    err.set_status_code(StatusCode::FORBIDDEN);
+    err
+}
+
+/// Resolves the CORS response headers of a request.
+///
+/// A preflight request fails if it is not allowed.
+/// For other requests, `None` is returned if no rule matches.
+pub(crate) async fn resolve(cors: &dyn S3Cors, bucket: &str, req: CorsRequest) -> S3Result<Option<HeaderMap>> {
+    let config = match cors.get_cors_configuration(bucket).await {
+        Ok(config) => config,
+        Err(err) if !req.preflight => {
+            warn!(?err, bucket, "failed to get CORS configuration");
+            return Ok(None);
+        }
+        Err(err) => return Err(err),
+    };
+
+    let Some(config) = config else {
+        if req.preflight {
+            return Err(forbidden("CORSResponse: CORS is not enabled for this bucket."));
+        }
+        return Ok(None);
+    };
+
+    let origin = req.origin.to_str().unwrap_or_default();
+    let request_headers: Vec<&str> = req.request_headers.iter().map(String::as_str).collect();
+    let Some(rule) = find_rule(&config, origin, &req.method, &request_headers) else {
+        if req.preflight {
+            return Err(forbidden(
+                "CORSResponse: This CORS request is not allowed. This is usually because the evalution of Origin, request method / Access-Control-Request-Method or Access-Control-Request-Headers are not whitelisted by the resource's CORS spec.",
+            ));
+        }
+        return Ok(None);
+    };
+
+    let mut headers = HeaderMap::new();
+    if rule.allowed_origins.iter().any(|o| o == "*") {
+        headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, HeaderValue::from_static("*"));
+    } else {
+        headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, req.origin);
+        headers.insert(header::ACCESS_CONTROL_ALLOW_CREDENTIALS, HeaderValue::from_static("true"));
+    }
+    insert_list(&mut headers, header::ACCESS_CONTROL_ALLOW_METHODS, &rule.allowed_methods);
+    if req.preflight {
+        insert_list(&mut headers, header::ACCESS_CONTROL_ALLOW_HEADERS, &req.request_headers);
+    }
+    if let Some(expose_headers) = &rule.expose_headers {
+        insert_list(&mut headers, header::ACCESS_CONTROL_EXPOSE_HEADERS, expose_headers);
+    }
+    if let Some(max_age) = rule.max_age_seconds {
+        headers.insert(header::ACCESS_CONTROL_MAX_AGE, HeaderValue::from(max_age));
+    }
+    headers.insert(
+        header::VARY,
+        HeaderValue::from_static("Origin, Access-Control-Request-Headers, Access-Control-Request-Method"),
+    );
+    Ok(Some(headers))
+}
+
+fn insert_list(headers: &mut HeaderMap, name: HeaderName, values: &[String]) {
+    if values.is_empty() {
+        return;
+    }
+    if let Ok(val) = HeaderValue::try_from(values.join(", ")) {
+        headers.insert(name, val);
+    }
+}
+
+/// Adds the CORS headers to a response, keeping the headers that are already set.
+pub(crate) fn set_response_headers(headers: &mut HeaderMap, cors: HeaderMap) {
+    for (name, val) in cors {
+        let Some(name) = name else { continue };
+        if name == header::VARY {
+            headers.append(name, val);
+        } else if !headers.contains_key(&name) {
+            headers.insert(name, val);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(origins: &[&str], methods: &[&str], headers: Option<&[&str]>) -> CORSRule {
+        let list = |xs: &[&str]| xs.iter().map(|&x| x.to_owned()).collect::<Vec<_>>();
+        CORSRule {
+            allowed_origins: list(origins),
+            allowed_methods: list(methods),
+            allowed_headers: headers.map(list),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn match_rules() {
+        let config = CORSConfiguration {
+            cors_rules: vec![
+                rule(&["https://example.com"], &["GET", "PUT"], Some(&["Content-*", "x-amz-date"])),
+                rule(&["https://*.example.org"], &["GET"], None),
+                rule(&["*"], &["HEAD"], Some(&["*"])),
+            ],
+        };
+
+        let find = |origin, method, headers| find_rule(&config, origin, method, headers).map(|r| r.allowed_origins[0].as_str());
+
+        assert_eq!(
+            find("https://example.com", "PUT", &["content-type", "X-Amz-Date"]),
+            Some("https://example.com")
+        );
+        assert_eq!(find("https://example.com", "PUT", &["x-amz-acl"]), None);
+        assert_eq!(find("https://example.com", "DELETE", &[]), None);
+        assert_eq!(find("https://Example.com", "GET", &[]), None);
+        assert_eq!(find("https://a.example.org", "GET", &[]), Some("https://*.example.org"));
+        assert_eq!(find("https://a.example.org", "GET", &["content-type"]), None);
+        assert_eq!(find("https://example.org", "GET", &[]), None);
+        assert_eq!(find("http://anything", "HEAD", &["x-custom"]), Some("*"));
+        assert_eq!(find("http://anything", "get", &[]), None);
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("a*a", "aa"));
+        assert!(!wildcard_match("a*a", "a"));
+        assert!(wildcard_match("http://*.com", "http://x.com"));
+        assert!(!wildcard_match("http://*.com", "https://x.com"));
+    }
+
+    #[test]
+    fn cache() {
+        let cache = CorsCache::default();
+        let t0 = Instant::now();
+        let config = CORSConfiguration {
+            cors_rules: vec![rule(&["*"], &["GET"], None)],
+        };
+
+        assert!(cache.get("a", t0).is_none());
+        cache.insert("a", Some(config), t0);
+        cache.insert("b", None, t0);
+        assert!(cache.get("a", t0).unwrap().config.is_some());
+        assert!(cache.get("b", t0).unwrap().config.is_none());
+        assert!(cache.get("a", t0 + CACHE_TTL).is_none());
+
+        cache.remove("a");
+        assert!(cache.get("a", t0).is_none());
+
+        for i in 0..=CACHE_CAPACITY {
+            cache.insert(&i.to_string(), None, t0 + Duration::from_millis(i as u64));
+        }
+        assert_eq!(cache.entries().len(), CACHE_CAPACITY);
+        assert!(cache.get("b", t0).is_none());
+        assert!(cache.get(&CACHE_CAPACITY.to_string(), t0).is_some());
+    }
+
+    #[tokio::test]
+    async fn resolve_headers() {
+        let config = CORSConfiguration {
+            cors_rules: vec![CORSRule {
+                expose_headers: Some(vec!["ETag".to_owned()]),
+                max_age_seconds: Some(600),
+                ..rule(&["https://example.com"], &["GET", "PUT"], Some(&["*"]))
+            }],
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ORIGIN, HeaderValue::from_static("https://example.com"));
+        headers.insert(header::ACCESS_CONTROL_REQUEST_METHOD, HeaderValue::from_static("PUT"));
+        headers.insert(
+            header::ACCESS_CONTROL_REQUEST_HEADERS,
+            HeaderValue::from_static("Content-Type, X-Amz-Date"),
+        );
+
+        let req = CorsRequest::extract(&Method::OPTIONS, &headers).unwrap().unwrap();
+        assert!(req.is_preflight());
+        let res = resolve(&config, "bucket", req).await.unwrap().unwrap();
+        assert_eq!(res[header::ACCESS_CONTROL_ALLOW_ORIGIN], "https://example.com");
+        assert_eq!(res[header::ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+        assert_eq!(res[header::ACCESS_CONTROL_ALLOW_METHODS], "GET, PUT");
+        assert_eq!(res[header::ACCESS_CONTROL_ALLOW_HEADERS], "content-type, x-amz-date");
+        assert_eq!(res[header::ACCESS_CONTROL_EXPOSE_HEADERS], "ETag");
+        assert_eq!(res[header::ACCESS_CONTROL_MAX_AGE], "600");
+
+        // Actual request with a method that is not allowed
+        let req = CorsRequest::extract(&Method::DELETE, &headers).unwrap().unwrap();
+        assert!(resolve(&config, "bucket", req).await.unwrap().is_none());
+
+        // Not a CORS request
+        assert!(CorsRequest::extract(&Method::GET, &HeaderMap::new()).unwrap().is_none());
+
+        // Preflight without Origin
+        let err = CorsRequest::extract(&Method::OPTIONS, &HeaderMap::new()).err().unwrap();
+        assert_eq!(err.status_code(), Some(StatusCode::BAD_REQUEST));
+    }
+}
@@ -47,6 +47,7 @@ struct Inner {
     status_code: Option<StatusCode>,
     source: Option<StdError>,
     headers: Option<HeaderMap>,
+    details: Vec<(Cow<'static, str>, String)>,
 }
 
 impl S3Error {
@@ -60,6 +61,7 @@ impl S3Error {
             status_code: None,
             source: None,
             headers: None,
+            details: Vec::new(),
         }))
     }
 
@@ -112,6 +114,13 @@ impl S3Error {
         self.0.headers = Some(val);
     }
 
+    /// Appends an extra element to the serialized error body.
+    ///
+    /// The elements are written after `Message`, in the order they were added.
+    pub fn add_detail(&mut self, name: impl Into<Cow<'static, str>>, value: impl Into<String>) {
+        self.0.details.push((name.into(), value.into()));
+    }
+
     #[must_use]
     pub fn code(&self) -> &S3ErrorCode {
         &self.0.code
@@ -142,6 +151,11 @@ impl S3Error {
         self.0.headers.as_ref()
     }
 
+    /// Returns the extra elements of the serialized error body.
+    pub fn details(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.details.iter().map(|(name, value)| (name.as_ref(), value.as_str()))
+    }
+
     #[must_use]
     pub(crate) fn take_headers(&mut self) -> Option<HeaderMap> {
         self.0.headers.take()
@@ -179,6 +193,9 @@ impl xml::SerializeContent for S3Error {
         if let Some(val) = self.0.message.as_deref() {
             s.content("Message", val)?;
         }
+        for (name, value) in &self.0.details {
+            s.content(name, value.as_str())?;
+        }
         // if let Some(val) = self.0.resource.as_deref() {
         //     s.content("Resource", val)?;
         // }
@@ -273,4 +290,28 @@ mod tests {
 
         assert_eq!(e.message(), Some("access denied for bucket my_bucket"));
     }
+
+    #[test]
+    fn s3_error_details() {
+        use crate::xml;
+
+        let mut e = s3_error!(SignatureDoesNotMatch, "signature mismatch");
+        e.set_request_id("req-1");
+        e.add_detail("StringToSign", "AWS4-HMAC-SHA256\n<&>");
+        e.add_detail("SignatureProvided", "abc");
+        assert_eq!(e.details().count(), 2);
+
+        let mut buf = Vec::new();
+        let mut ser = xml::Serializer::new(&mut buf);
+        xml::Serialize::serialize(&e, &mut ser).unwrap();
+        let body = String::from_utf8(buf).unwrap();
+        assert_eq!(
+            body,
+            concat!(
+                "<Error><Code>SignatureDoesNotMatch</Code><Message>signature mismatch</Message>",
+                "<StringToSign>AWS4-HMAC-SHA256\n&lt;&amp;&gt;</StringToSign>",
+                "<SignatureProvided>abc</SignatureProvided><RequestId>req-1</RequestId></Error>"
+            )
+        );
+    }
 }
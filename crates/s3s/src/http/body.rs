@@ -10,9 +10,12 @@ use crate::stream::RemainingLength;
 use std::fmt;
 use std::mem;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
 
 use bytes::Bytes;
 use futures::Stream;
@@ -282,7 +285,73 @@ pub struct BodySizeLimitExceeded {
     pub limit: usize,
 }
 
+/// Error returned when the body is idle for longer than the timeout.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("body was idle for more than {timeout:?}")]
+pub struct BodyIdleTimeout {
+    /// The idle timeout.
+    pub timeout: Duration,
+}
+
+pin_project_lite::pin_project! {
+    struct IdleTimeout {
+        #[pin]
+        inner: Body,
+        timeout: Duration,
+        sleep: Option<Pin<Box<tokio::time::Sleep>>>,
+        expired: Arc<AtomicBool>,
+    }
+}
+
+impl http_body::Body for IdleTimeout {
+    type Data = Bytes;
+
+    type Error = StdError;
+
+    fn poll_frame(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        if let Poll::Ready(frame) = http_body::Body::poll_frame(this.inner, cx) {
+            if let Some(sleep) = this.sleep {
+                sleep.as_mut().reset(tokio::time::Instant::now() + *this.timeout);
+            }
+            return Poll::Ready(frame);
+        }
+
+        // The timer is created lazily, so that bodies which are never polled need no timer.
+        let timeout = *this.timeout;
+        let sleep = this.sleep.get_or_insert_with(|| Box::pin(tokio::time::sleep(timeout)));
+        std::task::ready!(sleep.as_mut().poll(cx));
+
+        this.expired.store(true, Ordering::Relaxed);
+        Poll::Ready(Some(Err(Box::new(BodyIdleTimeout { timeout }))))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        http_body::Body::is_end_stream(&self.inner)
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        http_body::Body::size_hint(&self.inner)
+    }
+}
+
 impl Body {
+    /// Fails reading with [`BodyIdleTimeout`] if no data arrives within `timeout`.
+    ///
+    /// `expired` is set when the timeout expires, so that the error can be detected
+    /// even if the reader of the body discards it.
+    pub(crate) fn with_idle_timeout(self, timeout: Duration, expired: Arc<AtomicBool>) -> Self {
+        if self.bytes().is_some() {
+            return self;
+        }
+        Self::http_body(IdleTimeout {
+            inner: self,
+            timeout,
+            sleep: None,
+            expired,
+        })
+    }
+
     /// Stores all bytes in memory with a size limit.
     ///
     /// # Errors
@@ -33,10 +33,12 @@ pub use self::keep_alive_body::KeepAliveBody;
 mod etag;
 
 mod request;
+pub(crate) use self::request::ObservedRequest;
 pub use self::request::Request;
 
 mod response;
 pub use self::response::Response;
+pub(crate) use self::response::ResponseErrorCode;
 
 pub use hyper::header::{HeaderName, HeaderValue, InvalidHeaderValue};
 pub use hyper::http::StatusCode;
@@ -7,12 +7,17 @@ use super::OrderedQs;
 
 use crate::HttpRequest;
 use crate::auth::Credentials;
+use crate::limit::RequestLimits;
 use crate::path::S3Path;
 use crate::post_policy::PostPolicy;
 use crate::protocol::TrailingHeaders;
 use crate::region::Region;
+use crate::request_id::RequestId;
 use crate::stream::DynByteStream;
 
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
 use hyper::HeaderMap;
 use hyper::Method;
 use hyper::Uri;
@@ -33,7 +38,7 @@ pub(crate) struct S3Extensions {
     pub s3_path: Option<S3Path>,
     pub qs: Option<OrderedQs>,
 
-    pub multipart: Option<Multipart>,
+    pub multipart: Option<Box<Multipart>>,
     pub post_object_stream: Option<DynByteStream>,
 
     pub credentials: Option<Credentials>,
@@ -41,7 +46,41 @@ pub(crate) struct S3Extensions {
     pub service: Option<String>,
     pub trailing_headers: Option<TrailingHeaders>,
 
-    pub post_policy: Option<PostPolicy>,
+    pub post_policy: Option<Box<PostPolicy>>,
+
+    /// Assigned by `S3Service`
+    pub request_id: Option<RequestId>,
+
+    /// Set when the body idle timeout expires
+    pub body_timed_out: Option<Arc<AtomicBool>>,
+
+    /// Present if concurrency limits are enabled
+    pub limits: Option<Box<RequestLimits>>,
+
+    /// The CORS response headers, present if a CORS rule matches the request
+    pub cors: Option<Box<HeaderMap>>,
+
+    /// Present if access logging or metrics are enabled
+    pub observed: Option<Box<ObservedRequest>>,
+}
+
+/// The request information recorded by `ops::call` for access logs and metrics.
+#[derive(Default)]
+pub(crate) struct ObservedRequest {
+    pub op_name: Option<&'static str>,
+    pub requester: Option<String>,
+    pub bucket: Option<String>,
+    pub key: Option<String>,
+}
+
+impl ObservedRequest {
+    pub fn record_path(&mut self, s3_path: &S3Path) {
+        (self.bucket, self.key) = match s3_path {
+            S3Path::Root => (None, None),
+            S3Path::Bucket { bucket } => (Some(bucket.to_string()), None),
+            S3Path::Object { bucket, key } => (Some(bucket.to_string()), Some(key.to_string())),
+        };
+    }
 }
 
 impl From<HttpRequest> for Request {
@@ -2,6 +2,7 @@
 // SPDX-FileCopyrightText: 2023-2026 The s3s Authors
 
 use crate::HttpResponse;
+use crate::error::S3ErrorCode;
 
 use super::Body;
 
@@ -17,6 +18,10 @@ pub struct Response {
     pub extensions: Extensions,
 }
 
+/// The error code of an error response, recorded for access logs and metrics.
+#[derive(Clone)]
+pub(crate) struct ResponseErrorCode(pub S3ErrorCode);
+
 impl From<Response> for HttpResponse {
     fn from(res: Response) -> Self {
         let mut ans = HttpResponse::default();
@@ -0,0 +1,554 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Operation interceptors.
+//!
+//! An [`S3Interceptor`] runs around the invocation of every [`S3`](crate::S3) operation.
+//! Interceptors are registered via
+//! [`S3ServiceBuilder::add_interceptor`](crate::service::S3ServiceBuilder::add_interceptor)
+//! and can be stacked.
+//!
+//! For each operation:
+//!
+//! 1. The operation input is deserialized and checked by [`S3Access`](crate::access::S3Access).
+//! 2. [`S3Interceptor::before`] is called on each interceptor in registration order.
+//!    Returning an error aborts the request: the operation is not invoked, and the `after`
+//!    hooks of the interceptors whose `before` hook was called, including the failing one,
+//!    are called with the error.
+//! 3. The [`S3`](crate::S3) operation is invoked.
+//! 4. [`S3Interceptor::after`] is called on each interceptor in reverse registration order.
+//! 5. The operation output or error is serialized.
+//!
+//! An [`S3Interceptor`] runs for all operations, so the input and output are passed
+//! as type-erased values. Use [`BeforeContext::input`] and [`AfterContext::output`]
+//! with the DTO type of the operation to access them.
+//!
+//! An [`OperationInterceptor`] runs for a single operation, identified by its input type,
+//! and gets the typed input and output. Register it via
+//! [`S3ServiceBuilder::add_operation_interceptor`](crate::service::S3ServiceBuilder::add_operation_interceptor).
+//!
+//! Data can be passed from `before` to `after` through the per-request
+//! [`state`](BeforeContext::state_mut), which is shared by all interceptors.
+//! Use a private type as the key to avoid conflicts between interceptors.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::dto::{PutObjectInput, PutObjectOutput};
+//! use s3s::intercept::{AfterContext, BeforeContext, OperationInterceptor, S3Interceptor};
+//! use s3s::{S3Result, s3_error};
+//!
+//! use hyper::header::HeaderValue;
+//!
+//! /// Tags every successful response.
+//! struct Tag;
+//!
+//! #[async_trait::async_trait]
+//! impl S3Interceptor for Tag {
+//!     async fn after(&self, cx: &mut AfterContext<'_>) {
+//!         if let Some(headers) = cx.response_headers_mut() {
+//!             headers.insert("x-tagged", HeaderValue::from_static("true"));
+//!         }
+//!     }
+//! }
+//!
+//! /// Rejects objects larger than 1 GiB.
+//! struct Quota;
+//!
+//! #[async_trait::async_trait]
+//! impl OperationInterceptor<PutObjectInput> for Quota {
+//!     async fn before(&self, cx: &mut BeforeContext<'_, PutObjectInput>) -> S3Result<()> {
+//!         if cx.input().content_length.unwrap_or(0) > (1 << 30) {
+//!             return Err(s3_error!(EntityTooLarge));
+//!         }
+//!         Ok(())
+//!     }
+//!
+//!     async fn after(&self, cx: &mut AfterContext<'_, PutObjectOutput>) {
+//!         if let Some(output) = cx.output() {
+//!             tracing::debug!(e_tag = ?output.e_tag, "object stored");
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::S3Operation;
+use crate::auth::Credentials;
+use crate::error::{S3Error, S3Result};
+use crate::protocol::{S3Request, S3Response};
+
+use std::any::Any;
+use std::marker::PhantomData;
+
+use hyper::HeaderMap;
+use hyper::Method;
+use hyper::Uri;
+use hyper::http::Extensions;
+
+/// Operation interceptor
+#[async_trait::async_trait]
+pub trait S3Interceptor: Send + Sync + 'static {
+    /// Called before the S3 operation is invoked.
+    ///
+    /// Returning an error aborts the request with that error.
+    ///
+    /// The default implementation does nothing.
+    async fn before(&self, cx: &mut BeforeContext<'_>) -> S3Result<()> {
+        let _ = cx;
+        Ok(())
+    }
+
+    /// Called after the S3 operation returns, before the result is serialized.
+    ///
+    /// The default implementation does nothing.
+    async fn after(&self, cx: &mut AfterContext<'_>) {
+        let _ = cx;
+    }
+}
+
+/// The input type of an S3 operation.
+///
+/// It is implemented for the input DTO of every operation,
+/// such as [`PutObjectInput`](crate::dto::PutObjectInput).
+pub trait OperationInput: Any + Send {
+    /// The output type of the operation
+    type Output: Any + Send;
+
+    /// The name of the operation
+    const NAME: &'static str;
+}
+
+/// Operation interceptor of a single S3 operation, whose input type is `I`
+///
+/// The hooks are called at the same points as the hooks of [`S3Interceptor`],
+/// but only for the operation of `I`.
+#[async_trait::async_trait]
+pub trait OperationInterceptor<I: OperationInput>: Send + Sync + 'static {
+    /// Called before the S3 operation is invoked.
+    ///
+    /// Returning an error aborts the request with that error.
+    ///
+    /// The default implementation does nothing.
+    async fn before(&self, cx: &mut BeforeContext<'_, I>) -> S3Result<()> {
+        let _ = cx;
+        Ok(())
+    }
+
+    /// Called after the S3 operation returns, before the result is serialized.
+    ///
+    /// The default implementation does nothing.
+    async fn after(&self, cx: &mut AfterContext<'_, I::Output>) {
+        let _ = cx;
+    }
+}
+
+/// Adapts an [`OperationInterceptor`] to an [`S3Interceptor`].
+pub(crate) struct Typed<I, T> {
+    interceptor: T,
+    _marker: PhantomData<fn() -> I>,
+}
+
+impl<I, T> Typed<I, T> {
+    pub(crate) fn new(interceptor: T) -> Self {
+        Self {
+            interceptor,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<I: OperationInput, T: OperationInterceptor<I>> S3Interceptor for Typed<I, T> {
+    async fn before(&self, cx: &mut BeforeContext<'_>) -> S3Result<()> {
+        match cx.downcast::<I>() {
+            Some(mut cx) => self.interceptor.before(&mut cx).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn after(&self, cx: &mut AfterContext<'_>) {
+        if cx.s3_op.name != I::NAME {
+            return;
+        }
+        let replacement = {
+            let Some(mut cx) = cx.downcast::<I::Output>() else { return };
+            self.interceptor.after(&mut cx).await;
+            cx.replacement
+        };
+        if replacement.is_some() {
+            cx.replacement = replacement;
+        }
+    }
+}
+
+/// The request information passed to [`S3Interceptor::before`] and [`OperationInterceptor::before`].
+///
+/// `I` is the input type of the operation, which is type-erased for [`S3Interceptor`].
+pub struct BeforeContext<'a, I: ?Sized = dyn Any + Send> {
+    s3_op: &'a S3Operation,
+    input: &'a mut I,
+    method: &'a Method,
+    uri: &'a Uri,
+    headers: &'a mut HeaderMap,
+    extensions: &'a mut Extensions,
+    credentials: Option<&'a Credentials>,
+    state: &'a mut Extensions,
+}
+
+impl BeforeContext<'_> {
+    /// Returns the operation input if its type is `T`.
+    #[must_use]
+    pub fn input<T: Any>(&self) -> Option<&T> {
+        self.input.downcast_ref()
+    }
+
+    /// Returns the mutable operation input if its type is `T`.
+    pub fn input_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.input.downcast_mut()
+    }
+
+    fn downcast<T: Any>(&mut self) -> Option<BeforeContext<'_, T>> {
+        Some(BeforeContext {
+            s3_op: self.s3_op,
+            input: self.input.downcast_mut()?,
+            method: self.method,
+            uri: self.uri,
+            headers: self.headers,
+            extensions: self.extensions,
+            credentials: self.credentials,
+            state: self.state,
+        })
+    }
+}
+
+impl<I: OperationInput> BeforeContext<'_, I> {
+    /// Returns the operation input.
+    #[must_use]
+    pub fn input(&self) -> &I {
+        self.input
+    }
+
+    /// Returns the mutable operation input.
+    pub fn input_mut(&mut self) -> &mut I {
+        self.input
+    }
+}
+
+impl<I: ?Sized> BeforeContext<'_, I> {
+    /// Returns the S3 operation of current request.
+    #[must_use]
+    pub fn s3_op(&self) -> &S3Operation {
+        self.s3_op
+    }
+
+    #[must_use]
+    pub fn method(&self) -> &Method {
+        self.method
+    }
+
+    #[must_use]
+    pub fn uri(&self) -> &Uri {
+        self.uri
+    }
+
+    #[must_use]
+    pub fn headers(&self) -> &HeaderMap {
+        self.headers
+    }
+
+    /// Returns the mutable request headers, which are passed to the S3 operation.
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        self.headers
+    }
+
+    #[must_use]
+    pub fn extensions(&self) -> &Extensions {
+        self.extensions
+    }
+
+    /// Returns the mutable request extensions, which are passed to the S3 operation.
+    pub fn extensions_mut(&mut self) -> &mut Extensions {
+        self.extensions
+    }
+
+    /// Returns the credentials of current request.
+    ///
+    /// `None` means anonymous request.
+    #[must_use]
+    pub fn credentials(&self) -> Option<&Credentials> {
+        self.credentials
+    }
+
+    /// Returns the interceptor state of current request.
+    #[must_use]
+    pub fn state(&self) -> &Extensions {
+        self.state
+    }
+
+    /// Returns the mutable interceptor state of current request.
+    ///
+    /// The state is also available in [`AfterContext`].
+    pub fn state_mut(&mut self) -> &mut Extensions {
+        self.state
+    }
+}
+
+/// The result information passed to [`S3Interceptor::after`] and [`OperationInterceptor::after`].
+///
+/// `O` is the output type of the operation, which is type-erased for [`S3Interceptor`].
+pub struct AfterContext<'a, O: ?Sized = dyn Any + Send> {
+    s3_op: &'a S3Operation,
+    method: &'a Method,
+    uri: &'a Uri,
+    credentials: Option<&'a Credentials>,
+    state: &'a mut Extensions,
+    outcome: Outcome<'a, O>,
+    replacement: Option<S3Error>,
+}
+
+enum Outcome<'a, O: ?Sized> {
+    Output {
+        output: &'a mut O,
+        headers: &'a mut HeaderMap,
+        extensions: &'a mut Extensions,
+    },
+    Error(&'a mut S3Error),
+}
+
+impl AfterContext<'_> {
+    /// Returns the operation output if the operation succeeded and the output type is `T`.
+    #[must_use]
+    pub fn output<T: Any>(&self) -> Option<&T> {
+        match &self.outcome {
+            Outcome::Output { output, .. } => output.downcast_ref(),
+            Outcome::Error(_) => None,
+        }
+    }
+
+    /// Returns the mutable operation output if the operation succeeded and the output type is `T`.
+    pub fn output_mut<T: Any>(&mut self) -> Option<&mut T> {
+        match &mut self.outcome {
+            Outcome::Output { output, .. } => output.downcast_mut(),
+            Outcome::Error(_) => None,
+        }
+    }
+
+    fn downcast<T: Any>(&mut self) -> Option<AfterContext<'_, T>> {
+        let outcome = match &mut self.outcome {
+            Outcome::Output {
+                output,
+                headers,
+                extensions,
+            } => Outcome::Output {
+                output: output.downcast_mut()?,
+                headers,
+                extensions,
+            },
+            Outcome::Error(err) => Outcome::Error(err),
+        };
+        Some(AfterContext {
+            s3_op: self.s3_op,
+            method: self.method,
+            uri: self.uri,
+            credentials: self.credentials,
+            state: self.state,
+            outcome,
+            replacement: None,
+        })
+    }
+}
+
+impl<O: Any + Send> AfterContext<'_, O> {
+    /// Returns the operation output if the operation succeeded.
+    #[must_use]
+    pub fn output(&self) -> Option<&O> {
+        match &self.outcome {
+            Outcome::Output { output, .. } => Some(output),
+            Outcome::Error(_) => None,
+        }
+    }
+
+    /// Returns the mutable operation output if the operation succeeded.
+    pub fn output_mut(&mut self) -> Option<&mut O> {
+        match &mut self.outcome {
+            Outcome::Output { output, .. } => Some(output),
+            Outcome::Error(_) => None,
+        }
+    }
+}
+
+impl<O: ?Sized> AfterContext<'_, O> {
+    /// Returns the S3 operation of current request.
+    #[must_use]
+    pub fn s3_op(&self) -> &S3Operation {
+        self.s3_op
+    }
+
+    #[must_use]
+    pub fn method(&self) -> &Method {
+        self.method
+    }
+
+    #[must_use]
+    pub fn uri(&self) -> &Uri {
+        self.uri
+    }
+
+    /// Returns the credentials of current request.
+    ///
+    /// `None` means anonymous request.
+    #[must_use]
+    pub fn credentials(&self) -> Option<&Credentials> {
+        self.credentials
+    }
+
+    /// Returns the interceptor state of current request.
+    #[must_use]
+    pub fn state(&self) -> &Extensions {
+        self.state
+    }
+
+    /// Returns the mutable interceptor state of current request.
+    pub fn state_mut(&mut self) -> &mut Extensions {
+        self.state
+    }
+
+    /// Returns `true` if the operation succeeded.
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        matches!(self.outcome, Outcome::Output { .. })
+    }
+
+    /// Returns the mutable response headers if the operation succeeded.
+    pub fn response_headers_mut(&mut self) -> Option<&mut HeaderMap> {
+        match &mut self.outcome {
+            Outcome::Output { headers, .. } => Some(headers),
+            Outcome::Error(_) => None,
+        }
+    }
+
+    /// Returns the mutable response extensions if the operation succeeded.
+    pub fn response_extensions_mut(&mut self) -> Option<&mut Extensions> {
+        match &mut self.outcome {
+            Outcome::Output { extensions, .. } => Some(extensions),
+            Outcome::Error(_) => None,
+        }
+    }
+
+    /// Returns the error if the operation failed.
+    #[must_use]
+    pub fn error(&self) -> Option<&S3Error> {
+        match &self.outcome {
+            Outcome::Output { .. } => None,
+            Outcome::Error(err) => Some(err),
+        }
+    }
+
+    /// Returns the mutable error if the operation failed.
+    pub fn error_mut(&mut self) -> Option<&mut S3Error> {
+        match &mut self.outcome {
+            Outcome::Output { .. } => None,
+            Outcome::Error(err) => Some(err),
+        }
+    }
+
+    /// Replaces the result of the operation with `err`.
+    ///
+    /// The replacement takes effect after this interceptor returns,
+    /// so the remaining interceptors see the new error.
+    pub fn set_error(&mut self, err: S3Error) {
+        self.replacement = Some(err);
+    }
+}
+
+/// The request information kept between the `before` and `after` hooks.
+pub(crate) struct Intercepted<'a> {
+    interceptors: &'a [Box<dyn S3Interceptor>],
+    s3_op: S3Operation,
+    method: Method,
+    uri: Uri,
+    credentials: Option<Credentials>,
+    state: Extensions,
+}
+
+/// Calls the `before` hooks of `interceptors`.
+///
+/// Returns `None` if there is no interceptor.
+pub(crate) async fn before<'a, T: Any + Send>(
+    interceptors: &'a [Box<dyn S3Interceptor>],
+    op_name: &'static str,
+    req: &mut S3Request<T>,
+) -> S3Result<Option<Intercepted<'a>>> {
+    if interceptors.is_empty() {
+        return Ok(None);
+    }
+
+    let s3_op = S3Operation { name: op_name };
+    let mut state = Extensions::new();
+
+    for (i, interceptor) in interceptors.iter().enumerate() {
+        let mut cx: BeforeContext<'_> = BeforeContext {
+            s3_op: &s3_op,
+            input: &mut req.input,
+            method: &req.method,
+            uri: &req.uri,
+            headers: &mut req.headers,
+            extensions: &mut req.extensions,
+            credentials: req.credentials.as_ref(),
+            state: &mut state,
+        };
+        if let Err(err) = interceptor.before(&mut cx).await {
+            let intercepted = Intercepted {
+                interceptors: &interceptors[..=i],
+                s3_op,
+                method: req.method.clone(),
+                uri: req.uri.clone(),
+                credentials: req.credentials.clone(),
+                state,
+            };
+            let mut result: S3Result<S3Response<()>> = Err(err);
+            after(Some(intercepted), &mut result).await;
+            let Err(err) = result else { unreachable!("after hooks can only replace the error") };
+            return Err(err);
+        }
+    }
+
+    Ok(Some(Intercepted {
+        interceptors,
+        s3_op,
+        method: req.method.clone(),
+        uri: req.uri.clone(),
+        credentials: req.credentials.clone(),
+        state,
+    }))
+}
+
+/// Calls the `after` hooks in reverse order.
+pub(crate) async fn after<T: Any + Send>(intercepted: Option<Intercepted<'_>>, result: &mut S3Result<S3Response<T>>) {
+    let Some(mut intercepted) = intercepted else { return };
+
+    for interceptor in intercepted.interceptors.iter().rev() {
+        let outcome: Outcome<'_, dyn Any + Send> = match result {
+            Ok(resp) => Outcome::Output {
+                output: &mut resp.output,
+                headers: &mut resp.headers,
+                extensions: &mut resp.extensions,
+            },
+            Err(err) => Outcome::Error(err),
+        };
+        let mut cx = AfterContext {
+            s3_op: &intercepted.s3_op,
+            method: &intercepted.method,
+            uri: &intercepted.uri,
+            credentials: intercepted.credentials.as_ref(),
+            state: &mut intercepted.state,
+            outcome,
+            replacement: None,
+        };
+        interceptor.after(&mut cx).await;
+
+        if let Some(err) = cx.replacement.take() {
+            *result = Err(err);
+        }
+    }
+}
@@ -13,7 +13,9 @@
 //! - **S3 REST API Implementation**: Comprehensive support for Amazon S3 REST API
 //! - **HTTP Layer Abstraction**: Built on top of [hyper](https://github.com/hyperium/hyper) and [tower](https://github.com/tower-rs/tower)
 //! - **Type Safety**: Generated data types from AWS Smithy models
-//! - **Authentication**: Support for AWS Signature Version 4 and Version 2
+//! - **Authentication**: Support for AWS Signature Version 4, Version 4A (ECDSA P-256) and Version 2
+//! - **Request Signing**: Client-side Signature Version 4 signing, presigned URLs and POST policies in [`sign`]
+//! - **Temporary Credentials**: Built-in STS `AssumeRole` and `GetSessionToken` in [`sts`]
 //! - **Flexible Configuration**: Customizable service configuration with hot-reload support
 //! - **Extensibility**: Custom routes, access control, and validation
 //!
@@ -72,9 +74,14 @@
 //! - [`service`]: Core service implementation and builder
 //! - [`auth`]: S3 authentication (Signature V4, Signature V2)
 //! - [`access`]: Access control and authorization
+//! - [`access_log`]: Server access logging
 //! - [`config`]: Service configuration and settings
+//! - [`cors`]: Cross-origin resource sharing
 //! - [`dto`]: Data transfer objects (generated from AWS Smithy models)
 //! - [`host`]: Virtual host parsing and handling
+//! - [`intercept`]: Operation interceptors
+//! - [`limit`]: Concurrency and memory limits
+//! - [`metrics`]: Request metrics
 //! - [`route`]: Custom route support
 //! - [`validation`]: Bucket and object name validation
 //! - [`stream`]: Streaming utilities
@@ -84,6 +91,9 @@
 //! - [`path`]: S3 path handling
 //! - [`post_policy`]: POST object policy support
 //! - [`region`]: AWS region name type
+//! - [`request_id`]: Request ID generation
+//! - [`throttle`]: Request rate limiting
+//! - [`trace`]: Tracing spans and W3C trace context
 //! - [`xml`]: XML serialization/deserialization
 //!
 //! # Security
@@ -93,8 +103,8 @@
 //!
 //! It is the user's responsibility to implement security enhancements such as:
 //! - HTTP body length limits
-//! - Rate limiting
-//! - Back pressure
+//! - Rate limiting (an optional [`throttle::RateLimiter`] is provided)
+//! - Back pressure (an optional [`limit::ConcurrencyLimiter`] is provided)
 //! - Network-level security (firewalls, VPNs, etc.)
 //!
 //! # Examples
@@ -141,22 +151,33 @@ mod s3_op;
 mod s3_trait;
 mod sig_v2;
 mod sig_v4;
+mod sig_v4a;
 mod time;
 
 pub mod access;
+pub mod access_log;
 pub mod auth;
 pub mod checksum;
 pub mod config;
+pub mod cors;
 pub mod crypto;
 pub mod dto;
 pub mod header;
 pub mod host;
+pub mod intercept;
+pub mod limit;
+pub mod metrics;
 pub mod path;
 pub mod post_policy;
 pub mod region;
+pub mod request_id;
 pub mod route;
 pub mod service;
+pub mod sign;
 pub mod stream;
+pub mod sts;
+pub mod throttle;
+pub mod trace;
 pub mod validation;
 pub mod xml;
 
@@ -0,0 +1,299 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Concurrency and memory limits.
+//!
+//! A [`ConcurrencyLimiter`] can be configured via
+//! [`S3ServiceBuilder::set_concurrency_limiter`](crate::service::S3ServiceBuilder::set_concurrency_limiter)
+//! to apply back pressure to bursts of requests. It caps
+//!
+//! - the number of requests that are processed concurrently,
+//! - the number of body bytes that are buffered concurrently for operations with XML bodies,
+//!   which are read into memory before parsing (up to
+//!   [`S3Config::xml_max_body_size`](crate::config::S3Config::xml_max_body_size) per request),
+//! - the number of POST object forms that are parsed concurrently.
+//!
+//! The limits are held until the response is produced.
+//! Streaming response bodies are not counted.
+//!
+//! When a limit is reached, requests are rejected with `SlowDown` (503) or queued,
+//! depending on the [`Overflow`] policy.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::limit::{ConcurrencyLimiter, Overflow};
+//! use std::time::Duration;
+//!
+//! let limiter = ConcurrencyLimiter::new()
+//!     .with_max_requests(1024)
+//!     .with_max_buffered_body_bytes(256 * 1024 * 1024)
+//!     .with_max_multipart_forms(64)
+//!     .with_overflow(Overflow::Queue { max_wait: Duration::from_secs(5) });
+//! ```
+
+use crate::error::{S3Error, S3Result};
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::{BoxFuture, Either, Ready, ready};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, TryAcquireError};
+
+/// What to do with a request that exceeds a limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Overflow {
+    /// Rejects the request with `SlowDown`.
+    #[default]
+    Reject,
+    /// Waits until the request is within the limit.
+    /// The request is rejected with `SlowDown` if it waits longer than `max_wait`.
+    Queue {
+        /// The maximum time to wait.
+        max_wait: Duration,
+    },
+}
+
+/// A limit on a shared resource.
+struct Limit {
+    semaphore: Arc<Semaphore>,
+    capacity: usize,
+}
+
+impl Limit {
+    fn new(capacity: usize) -> Self {
+        let capacity = capacity.clamp(1, Semaphore::MAX_PERMITS);
+        Self {
+            semaphore: Arc::new(Semaphore::new(capacity)),
+            capacity,
+        }
+    }
+
+    fn in_use(&self) -> usize {
+        self.capacity - self.semaphore.available_permits()
+    }
+}
+
+/// Holds a share of a limit until it is dropped.
+pub(crate) struct Permit(#[allow(dead_code)] OwnedSemaphorePermit);
+
+/// The limits applied to a request.
+///
+/// The permits are released when the request is dropped.
+#[derive(Default)]
+pub(crate) struct RequestLimits {
+    pub request: Option<Permit>,
+    pub body_bytes: Option<Permit>,
+    pub multipart_form: Option<Permit>,
+}
+
+/// Concurrency and memory limiter.
+///
+/// No limits are applied by default.
+pub struct ConcurrencyLimiter {
+    requests: Option<Limit>,
+    body_bytes: Option<Limit>,
+    multipart_forms: Option<Limit>,
+    overflow: Overflow,
+}
+
+impl fmt::Debug for ConcurrencyLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcurrencyLimiter")
+            .field("max_requests", &self.requests.as_ref().map(|l| l.capacity))
+            .field("max_buffered_body_bytes", &self.body_bytes.as_ref().map(|l| l.capacity))
+            .field("max_multipart_forms", &self.multipart_forms.as_ref().map(|l| l.capacity))
+            .field("overflow", &self.overflow)
+            .finish()
+    }
+}
+
+impl Default for ConcurrencyLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConcurrencyLimiter {
+    /// Creates a limiter without limits.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            requests: None,
+            body_bytes: None,
+            multipart_forms: None,
+            overflow: Overflow::Reject,
+        }
+    }
+
+    /// Limits the number of requests that are processed concurrently.
+    #[must_use]
+    pub fn with_max_requests(mut self, max: usize) -> Self {
+        self.requests = Some(Limit::new(max));
+        self
+    }
+
+    /// Limits the number of request body bytes that are buffered concurrently for XML parsing.
+    ///
+    /// A request reserves its `Content-Length`, or the maximum XML body size if the length is unknown.
+    /// A request larger than the whole limit reserves the whole limit.
+    #[must_use]
+    pub fn with_max_buffered_body_bytes(mut self, max: usize) -> Self {
+        self.body_bytes = Some(Limit::new(max));
+        self
+    }
+
+    /// Limits the number of POST object forms that are parsed concurrently.
+    #[must_use]
+    pub fn with_max_multipart_forms(mut self, max: usize) -> Self {
+        self.multipart_forms = Some(Limit::new(max));
+        self
+    }
+
+    /// Sets what to do with requests that exceed a limit.
+    ///
+    /// The default is [`Overflow::Reject`].
+    #[must_use]
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Returns the number of requests that are being processed.
+    #[must_use]
+    pub fn requests_in_use(&self) -> usize {
+        self.requests.as_ref().map_or(0, Limit::in_use)
+    }
+
+    /// Returns the number of body bytes that are reserved for buffering.
+    #[must_use]
+    pub fn buffered_body_bytes_in_use(&self) -> usize {
+        self.body_bytes.as_ref().map_or(0, Limit::in_use)
+    }
+
+    /// Returns the number of multipart forms that are being parsed.
+    #[must_use]
+    pub fn multipart_forms_in_use(&self) -> usize {
+        self.multipart_forms.as_ref().map_or(0, Limit::in_use)
+    }
+
+    pub(crate) fn acquire_request(&self) -> Acquire {
+        self.acquire(self.requests.as_ref(), 1)
+    }
+
+    pub(crate) fn acquire_body_bytes(&self, len: usize) -> Acquire {
+        self.acquire(self.body_bytes.as_ref(), len)
+    }
+
+    pub(crate) fn acquire_multipart_form(&self) -> Acquire {
+        self.acquire(self.multipart_forms.as_ref(), 1)
+    }
+
+    fn acquire(&self, limit: Option<&Limit>, n: usize) -> Acquire {
+        let Some(limit) = limit else { return Either::Left(ready(Ok(None))) };
+        let n = u32::try_from(n.min(limit.capacity)).unwrap_or(u32::MAX);
+
+        match Arc::clone(&limit.semaphore).try_acquire_many_owned(n) {
+            Ok(permit) => return Either::Left(ready(Ok(Some(Permit(permit))))),
+            Err(TryAcquireError::NoPermits) => {}
+            Err(TryAcquireError::Closed) => return Either::Left(ready(Err(slow_down()))),
+        }
+
+        let Overflow::Queue { max_wait } = self.overflow else {
+            return Either::Left(ready(Err(slow_down())));
+        };
+
+        // Only waiting requests are boxed, which keeps the futures of callers small
+        let wait = tokio::time::timeout(max_wait, Arc::clone(&limit.semaphore).acquire_many_owned(n));
+        Either::Right(Box::pin(async move {
+            match wait.await {
+                Ok(Ok(permit)) => Ok(Some(Permit(permit))),
+                _ => Err(slow_down()),
+            }
+        }))
+    }
+}
+
+/// The future of acquiring a share of a limit.
+pub(crate) type Acquire = Either<Ready<S3Result<Option<Permit>>>, BoxFuture<'static, S3Result<Option<Permit>>>>;
+
+fn slow_down() -> S3Error {
+    s3_error!(SlowDown, "Please reduce your request rate.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::S3ErrorCode;
+
+    #[tokio::test]
+    async fn reject() {
+        let limiter = ConcurrencyLimiter::new().with_max_requests(2);
+
+        let a = limiter.acquire_request().await.unwrap();
+        let b = limiter.acquire_request().await.unwrap();
+        assert!(a.is_some() && b.is_some());
+        assert_eq!(limiter.requests_in_use(), 2);
+
+        let err = limiter.acquire_request().await.err().unwrap();
+        assert_eq!(*err.code(), S3ErrorCode::SlowDown);
+
+        drop(a);
+        assert_eq!(limiter.requests_in_use(), 1);
+        assert!(limiter.acquire_request().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn unlimited() {
+        let limiter = ConcurrencyLimiter::new();
+        assert!(limiter.acquire_request().await.unwrap().is_none());
+        assert!(limiter.acquire_body_bytes(1 << 30).await.unwrap().is_none());
+        assert!(limiter.acquire_multipart_form().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn body_bytes() {
+        let limiter = ConcurrencyLimiter::new().with_max_buffered_body_bytes(100);
+
+        let a = limiter.acquire_body_bytes(60).await.unwrap();
+        assert_eq!(limiter.buffered_body_bytes_in_use(), 60);
+        let err = limiter.acquire_body_bytes(60).await.err().unwrap();
+        assert_eq!(*err.code(), S3ErrorCode::SlowDown);
+        drop(a);
+
+        // Larger than the whole limit
+        let b = limiter.acquire_body_bytes(1000).await.unwrap();
+        assert_eq!(limiter.buffered_body_bytes_in_use(), 100);
+        drop(b);
+        assert_eq!(limiter.buffered_body_bytes_in_use(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn queue() {
+        let limiter = Arc::new(
+            ConcurrencyLimiter::new()
+                .with_max_multipart_forms(1)
+                .with_overflow(Overflow::Queue {
+                    max_wait: Duration::from_secs(5),
+                }),
+        );
+
+        let a = limiter.acquire_multipart_form().await.unwrap();
+
+        // Times out while the permit is held
+        let err = limiter.acquire_multipart_form().await.err().unwrap();
+        assert_eq!(*err.code(), S3ErrorCode::SlowDown);
+
+        // Proceeds once the permit is released
+        let waiter = tokio::spawn({
+            let limiter = Arc::clone(&limiter);
+            async move { limiter.acquire_multipart_form().await.map(|p| p.is_some()) }
+        });
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        drop(a);
+        assert!(waiter.await.unwrap().unwrap());
+    }
+}
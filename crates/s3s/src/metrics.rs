@@ -0,0 +1,575 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Request metrics.
+//!
+//! An [`S3Metrics`] recorder can be configured via
+//! [`S3ServiceBuilder::set_metrics`](crate::service::S3ServiceBuilder::set_metrics)
+//! to observe every request handled by [`S3Service`](crate::service::S3Service).
+//! The recorder is registry-agnostic: it receives a [`RequestMetrics`] for each request
+//! and can forward it to any metrics library.
+//!
+//! [`PrometheusMetrics`] is a built-in recorder that keeps the following metrics in memory
+//! and renders them in the Prometheus text exposition format:
+//!
+//! | Metric                          | Type      | Labels                                   |
+//! | ------------------------------- | --------- | ---------------------------------------- |
+//! | `s3s_requests_total`            | counter   | `operation`, `status_class`, `error_code` |
+//! | `s3s_request_duration_seconds`  | histogram | `operation`                              |
+//! | `s3s_request_bytes_total`       | counter   | `operation`                              |
+//! | `s3s_response_bytes_total`      | counter   | `operation`                              |
+//! | `s3s_requests_in_flight`        | gauge     |                                          |
+//! | `s3s_auth_failures_total`       | counter   | `error_code`                             |
+//!
+//! Requests that are not S3 operations (for example, custom routes) have the operation `unknown`.
+//!
+//! [`MetricsRoute`] serves the rendered metrics at `/?x-s3s-metrics`.
+//!
+//! # Limitations
+//!
+//! The metrics are recorded when the response head is ready. For streaming bodies,
+//! the byte counts are taken from the body size or the `Content-Length` header.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::metrics::{MetricsRoute, PrometheusMetrics};
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::{S3, S3Request, S3Response, S3Result};
+//! use s3s::dto::{GetObjectInput, GetObjectOutput};
+//!
+//! #[derive(Clone)]
+//! struct MyS3;
+//!
+//! #[async_trait::async_trait]
+//! impl S3 for MyS3 {
+//! #   async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+//! #       Err(s3s::s3_error!(NotImplemented))
+//! #   }
+//!     // Implement S3 operations
+//! }
+//!
+//! let metrics = PrometheusMetrics::new();
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_metrics(metrics.clone());
+//! builder.set_route(MetricsRoute::new(metrics));
+//! let service = builder.build();
+//! ```
+
+use crate::error::S3ErrorCode;
+use crate::header;
+use crate::http::{ObservedRequest, Request, ResponseErrorCode};
+use crate::protocol::{HttpError, HttpResponse};
+use crate::route::S3Route;
+use crate::{Body, S3Request, S3Response, S3Result};
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use hyper::HeaderMap;
+use hyper::Method;
+use hyper::StatusCode;
+use hyper::Uri;
+use hyper::header::HeaderValue;
+use hyper::http::Extensions;
+
+/// Request metrics recorder
+pub trait S3Metrics: Send + Sync + 'static {
+    /// Called when a request is received.
+    ///
+    /// The default implementation does nothing.
+    fn on_request_start(&self) {}
+
+    /// Called when a request is finished or cancelled.
+    ///
+    /// This method is called on the request path, so it should not block.
+    fn on_request_end(&self, metrics: &RequestMetrics<'_>);
+}
+
+/// The metrics of a finished request.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct RequestMetrics<'a> {
+    /// The name of the S3 operation, if the request is an S3 operation.
+    pub operation: Option<&'static str>,
+    /// The HTTP status code of the response. `None` if the request is cancelled.
+    pub status: Option<StatusCode>,
+    /// The S3 error code, if any.
+    pub error_code: Option<&'a S3ErrorCode>,
+    /// The time between receiving the request and producing the response.
+    pub duration: Duration,
+    /// The number of request body bytes, if known.
+    pub bytes_in: Option<u64>,
+    /// The number of response body bytes, if known.
+    pub bytes_out: Option<u64>,
+}
+
+impl RequestMetrics<'_> {
+    /// Returns the status class of the response, such as `2xx`, or `cancelled`.
+    #[must_use]
+    pub fn status_class(&self) -> &'static str {
+        let Some(status) = self.status else { return "cancelled" };
+        match status.as_u16() {
+            100..=199 => "1xx",
+            200..=299 => "2xx",
+            300..=399 => "3xx",
+            400..=499 => "4xx",
+            _ => "5xx",
+        }
+    }
+
+    /// Returns `true` if the request failed to authenticate.
+    #[must_use]
+    pub fn is_auth_failure(&self) -> bool {
+        matches!(
+            self.error_code,
+            Some(
+                S3ErrorCode::SignatureDoesNotMatch
+                    | S3ErrorCode::InvalidAccessKeyId
+                    | S3ErrorCode::NotSignedUp
+                    | S3ErrorCode::ExpiredToken
+                    | S3ErrorCode::InvalidToken
+                    | S3ErrorCode::AuthorizationHeaderMalformed
+                    | S3ErrorCode::RequestTimeTooSkewed
+            )
+        )
+    }
+}
+
+/// The default buckets of the request duration histogram, in seconds.
+pub const DEFAULT_DURATION_BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// A metrics recorder that renders the Prometheus text exposition format.
+///
+/// Cloning a `PrometheusMetrics` shares the underlying metrics.
+#[derive(Debug, Clone)]
+pub struct PrometheusMetrics {
+    inner: Arc<PrometheusInner>,
+}
+
+#[derive(Debug)]
+struct PrometheusInner {
+    buckets: Vec<f64>,
+    in_flight: AtomicI64,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// `(operation, status_class, error_code)` -> count
+    requests: BTreeMap<(&'static str, &'static str, String), u64>,
+    durations: BTreeMap<&'static str, Histogram>,
+    bytes_in: BTreeMap<&'static str, u64>,
+    bytes_out: BTreeMap<&'static str, u64>,
+    /// `error_code` -> count
+    auth_failures: BTreeMap<String, u64>,
+}
+
+#[derive(Debug)]
+struct Histogram {
+    /// Non-cumulative counts per bucket, with a trailing `+Inf` bucket
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Default for PrometheusMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrometheusMetrics {
+    /// Creates a recorder with [`DEFAULT_DURATION_BUCKETS`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_buckets(DEFAULT_DURATION_BUCKETS.to_vec())
+    }
+
+    /// Creates a recorder with the given upper bounds of the request duration histogram, in seconds.
+    ///
+    /// The bounds are sorted and deduplicated.
+    #[must_use]
+    pub fn with_buckets(mut buckets: Vec<f64>) -> Self {
+        buckets.retain(|b| b.is_finite());
+        buckets.sort_by(f64::total_cmp);
+        buckets.dedup();
+        Self {
+            inner: Arc::new(PrometheusInner {
+                buckets,
+                in_flight: AtomicI64::new(0),
+                state: Mutex::default(),
+            }),
+        }
+    }
+
+    /// Renders the metrics in the Prometheus text exposition format.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let _ = self.write_to(&mut out);
+        out
+    }
+
+    fn write_to(&self, out: &mut String) -> fmt::Result {
+        let buckets = &self.inner.buckets;
+        let in_flight = self.inner.in_flight.load(Ordering::Relaxed);
+        let state = self.inner.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        header(out, "s3s_requests_total", "counter", "Total number of requests.")?;
+        for ((op, class, code), n) in &state.requests {
+            writeln!(
+                out,
+                "s3s_requests_total{{operation=\"{}\",status_class=\"{class}\",error_code=\"{}\"}} {n}",
+                Escaped(op),
+                Escaped(code)
+            )?;
+        }
+
+        header(out, "s3s_request_duration_seconds", "histogram", "Request duration in seconds.")?;
+        for (op, h) in &state.durations {
+            let op = Escaped(op);
+            let mut cumulative = 0;
+            for (i, n) in h.counts.iter().enumerate() {
+                cumulative += n;
+                let le = buckets.get(i).map_or_else(|| "+Inf".to_owned(), f64::to_string);
+                writeln!(out, "s3s_request_duration_seconds_bucket{{operation=\"{op}\",le=\"{le}\"}} {cumulative}")?;
+            }
+            writeln!(out, "s3s_request_duration_seconds_sum{{operation=\"{op}\"}} {}", h.sum)?;
+            writeln!(out, "s3s_request_duration_seconds_count{{operation=\"{op}\"}} {}", h.count)?;
+        }
+
+        header(out, "s3s_request_bytes_total", "counter", "Total number of request body bytes.")?;
+        for (op, n) in &state.bytes_in {
+            writeln!(out, "s3s_request_bytes_total{{operation=\"{}\"}} {n}", Escaped(op))?;
+        }
+
+        header(out, "s3s_response_bytes_total", "counter", "Total number of response body bytes.")?;
+        for (op, n) in &state.bytes_out {
+            writeln!(out, "s3s_response_bytes_total{{operation=\"{}\"}} {n}", Escaped(op))?;
+        }
+
+        header(out, "s3s_requests_in_flight", "gauge", "Number of requests being processed.")?;
+        writeln!(out, "s3s_requests_in_flight {in_flight}")?;
+
+        header(out, "s3s_auth_failures_total", "counter", "Total number of authentication failures.")?;
+        for (code, n) in &state.auth_failures {
+            writeln!(out, "s3s_auth_failures_total{{error_code=\"{}\"}} {n}", Escaped(code))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn header(out: &mut String, name: &str, ty: &str, help: &str) -> fmt::Result {
+    writeln!(out, "# HELP {name} {help}")?;
+    writeln!(out, "# TYPE {name} {ty}")
+}
+
+/// Escapes a label value.
+struct Escaped<'a>(&'a str);
+
+impl fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '\\' => f.write_str("\\\\")?,
+                '"' => f.write_str("\\\"")?,
+                '\n' => f.write_str("\\n")?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl S3Metrics for PrometheusMetrics {
+    fn on_request_start(&self) {
+        self.inner.in_flight.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_request_end(&self, m: &RequestMetrics<'_>) {
+        self.inner.in_flight.fetch_sub(1, Ordering::Relaxed);
+
+        let op = m.operation.unwrap_or("unknown");
+        let code = m.error_code.map_or("", S3ErrorCode::as_str);
+        let secs = m.duration.as_secs_f64();
+        let bucket = self.inner.buckets.partition_point(|&le| le < secs);
+
+        let mut state = self.inner.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        *state.requests.entry((op, m.status_class(), code.to_owned())).or_default() += 1;
+
+        let h = state.durations.entry(op).or_insert_with(|| Histogram {
+            counts: vec![0; self.inner.buckets.len() + 1],
+            sum: 0.0,
+            count: 0,
+        });
+        h.counts[bucket] += 1;
+        h.sum += secs;
+        h.count += 1;
+
+        if let Some(n) = m.bytes_in {
+            *state.bytes_in.entry(op).or_default() += n;
+        }
+        if let Some(n) = m.bytes_out {
+            *state.bytes_out.entry(op).or_default() += n;
+        }
+        if m.is_auth_failure() {
+            *state.auth_failures.entry(code.to_owned()).or_default() += 1;
+        }
+    }
+}
+
+/// A route that serves [`PrometheusMetrics`] in the text exposition format.
+///
+/// By default, it matches `GET /?x-s3s-metrics` and requires a signed request.
+///
+/// Routes are matched before S3 operations, and every path that reaches a route is also
+/// a valid S3 path. A plain `GET /metrics` would shadow `ListObjects` of a bucket named `metrics`
+/// and `GetObject` of the key `metrics` in every virtual-hosted bucket. The route therefore
+/// only matches requests with the [`QUERY_PARAM`](Self::QUERY_PARAM) query parameter,
+/// which is not used by any S3 operation.
+#[derive(Debug, Clone)]
+pub struct MetricsRoute {
+    metrics: PrometheusMetrics,
+    path: String,
+    anonymous: bool,
+}
+
+impl MetricsRoute {
+    /// The query parameter that marks a metrics request
+    pub const QUERY_PARAM: &str = "x-s3s-metrics";
+
+    /// Creates a route that serves `metrics` at `GET /?x-s3s-metrics`.
+    #[must_use]
+    pub fn new(metrics: PrometheusMetrics) -> Self {
+        Self {
+            metrics,
+            path: "/".to_owned(),
+            anonymous: false,
+        }
+    }
+
+    /// Sets the path of the route.
+    ///
+    /// Requests must still have the [`QUERY_PARAM`](Self::QUERY_PARAM) query parameter.
+    #[must_use]
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Allows unsigned requests, for scrapers that cannot sign requests.
+    #[must_use]
+    pub fn with_anonymous_access(mut self) -> Self {
+        self.anonymous = true;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl S3Route for MetricsRoute {
+    fn is_match(&self, method: &Method, uri: &Uri, _: &HeaderMap, _: &mut Extensions) -> bool {
+        method == Method::GET && uri.path() == self.path && has_query_param(uri, Self::QUERY_PARAM)
+    }
+
+    async fn check_access(&self, req: &mut S3Request<Body>) -> S3Result<()> {
+        if self.anonymous || req.credentials.is_some() {
+            return Ok(());
+        }
+        Err(s3_error!(AccessDenied, "Signature is required"))
+    }
+
+    async fn call(&self, _: S3Request<Body>) -> S3Result<S3Response<Body>> {
+        let mut resp = S3Response::new(Body::from(self.metrics.render()));
+        resp.headers
+            .insert(header::CONTENT_TYPE, HeaderValue::from_static("text/plain; version=0.0.4; charset=utf-8"));
+        Ok(resp)
+    }
+}
+
+fn has_query_param(uri: &Uri, name: &str) -> bool {
+    let Some(query) = uri.query() else { return false };
+    url::form_urlencoded::parse(query.as_bytes()).any(|(key, _)| key == name)
+}
+
+fn content_length(headers: &HeaderMap) -> Option<u64> {
+    headers.get(header::CONTENT_LENGTH)?.to_str().ok()?.parse().ok()
+}
+
+/// An in-flight request. Dropping it without calling [`finish`](Self::finish)
+/// reports the request as cancelled.
+pub(crate) struct InFlight<'a> {
+    metrics: &'a dyn S3Metrics,
+    start: Instant,
+    bytes_in: Option<u64>,
+    operation: Option<&'static str>,
+    finished: bool,
+}
+
+impl<'a> InFlight<'a> {
+    pub(crate) fn start(metrics: &'a dyn S3Metrics, req: &Request) -> Self {
+        metrics.on_request_start();
+        let bytes_in = req
+            .headers
+            .get(header::X_AMZ_DECODED_CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok()?.parse().ok())
+            .or_else(|| content_length(&req.headers));
+        Self {
+            metrics,
+            start: Instant::now(),
+            bytes_in,
+            operation: None,
+            finished: false,
+        }
+    }
+
+    /// Sets the operation, which is reported if the request is cancelled.
+    pub(crate) fn set_operation(&mut self, operation: Option<&'static str>) {
+        self.operation = operation;
+    }
+
+    pub(crate) fn finish(mut self, observed: Option<&ObservedRequest>, result: &Result<HttpResponse, HttpError>) {
+        self.finished = true;
+
+        let internal_error = S3ErrorCode::InternalError;
+        let (status, error_code, bytes_out) = match result {
+            Ok(resp) => {
+                let error_code = resp.extensions().get::<ResponseErrorCode>().map(|c| &c.0);
+                let body_size = http_body::Body::size_hint(resp.body()).exact();
+                let bytes_out = body_size.or_else(|| content_length(resp.headers()));
+                (resp.status(), error_code, bytes_out)
+            }
+            Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, Some(&internal_error), None),
+        };
+
+        self.metrics.on_request_end(&RequestMetrics {
+            operation: observed.and_then(|o| o.op_name),
+            status: Some(status),
+            error_code,
+            duration: self.start.elapsed(),
+            bytes_in: self.bytes_in,
+            bytes_out,
+        });
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.metrics.on_request_end(&RequestMetrics {
+            operation: self.operation,
+            status: None,
+            error_code: None,
+            duration: self.start.elapsed(),
+            bytes_in: self.bytes_in,
+            bytes_out: None,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request<'a>(op: &'static str, status: u16, code: Option<&'a S3ErrorCode>, millis: u64) -> RequestMetrics<'a> {
+        RequestMetrics {
+            operation: Some(op),
+            status: Some(StatusCode::from_u16(status).unwrap()),
+            error_code: code,
+            duration: Duration::from_millis(millis),
+            bytes_in: Some(10),
+            bytes_out: Some(100),
+        }
+    }
+
+    #[test]
+    fn status_class_and_auth_failure() {
+        let m = request("GetObject", 403, Some(&S3ErrorCode::SignatureDoesNotMatch), 1);
+        assert_eq!(m.status_class(), "4xx");
+        assert!(m.is_auth_failure());
+
+        let m = request("GetObject", 403, Some(&S3ErrorCode::AccessDenied), 1);
+        assert!(!m.is_auth_failure());
+
+        let m = RequestMetrics { status: None, ..m };
+        assert_eq!(m.status_class(), "cancelled");
+    }
+
+    #[test]
+    fn prometheus_render() {
+        let metrics = PrometheusMetrics::with_buckets(vec![1.0, 0.1]);
+
+        metrics.on_request_start();
+        metrics.on_request_start();
+        metrics.on_request_start();
+        metrics.on_request_end(&request("GetObject", 200, None, 50));
+        metrics.on_request_end(&request("GetObject", 200, None, 500));
+        metrics.on_request_end(&request("PutObject", 403, Some(&S3ErrorCode::InvalidAccessKeyId), 2000));
+
+        let text = metrics.render();
+        let expected = [
+            "# TYPE s3s_requests_total counter",
+            "s3s_requests_total{operation=\"GetObject\",status_class=\"2xx\",error_code=\"\"} 2",
+            "s3s_requests_total{operation=\"PutObject\",status_class=\"4xx\",error_code=\"InvalidAccessKeyId\"} 1",
+            "# TYPE s3s_request_duration_seconds histogram",
+            "s3s_request_duration_seconds_bucket{operation=\"GetObject\",le=\"0.1\"} 1",
+            "s3s_request_duration_seconds_bucket{operation=\"GetObject\",le=\"1\"} 2",
+            "s3s_request_duration_seconds_bucket{operation=\"GetObject\",le=\"+Inf\"} 2",
+            "s3s_request_duration_seconds_sum{operation=\"GetObject\"} 0.55",
+            "s3s_request_duration_seconds_count{operation=\"GetObject\"} 2",
+            "s3s_request_duration_seconds_bucket{operation=\"PutObject\",le=\"1\"} 0",
+            "s3s_request_duration_seconds_bucket{operation=\"PutObject\",le=\"+Inf\"} 1",
+            "s3s_request_bytes_total{operation=\"GetObject\"} 20",
+            "s3s_response_bytes_total{operation=\"PutObject\"} 100",
+            "s3s_requests_in_flight 0",
+            "s3s_auth_failures_total{error_code=\"InvalidAccessKeyId\"} 1",
+        ];
+        for line in expected {
+            assert!(text.lines().any(|l| l == line), "missing {line:?} in\n{text}");
+        }
+    }
+
+    #[test]
+    fn label_escaping() {
+        assert_eq!(Escaped("a\"b\\c\nd").to_string(), "a\\\"b\\\\c\\nd");
+    }
+
+    #[test]
+    fn cancelled_request() {
+        let metrics = PrometheusMetrics::new();
+        let req = Request::from(
+            hyper::Request::builder()
+                .header(header::CONTENT_LENGTH, "42")
+                .body(Body::empty())
+                .unwrap(),
+        );
+
+        let in_flight = InFlight::start(&metrics, &req);
+        assert!(metrics.render().contains("\ns3s_requests_in_flight 1\n"));
+        drop(in_flight);
+
+        let mut in_flight = InFlight::start(&metrics, &req);
+        in_flight.set_operation(Some("GetObject"));
+        drop(in_flight);
+
+        let text = metrics.render();
+        assert!(text.contains("\ns3s_requests_in_flight 0\n"), "{text}");
+        assert!(
+            text.contains("s3s_requests_total{operation=\"unknown\",status_class=\"cancelled\",error_code=\"\"} 1"),
+            "{text}"
+        );
+        assert!(text.contains("s3s_request_bytes_total{operation=\"unknown\"} 42"), "{text}");
+        assert!(
+            text.contains("s3s_requests_total{operation=\"GetObject\",status_class=\"cancelled\",error_code=\"\"} 1"),
+            "{text}"
+        );
+    }
+}
@@ -119,6 +119,111 @@ use crate::path::S3Path;
 
 use std::borrow::Cow;
 
+use tracing::{Instrument, debug_span};
+
+/// The names of all operations, in alphabetical order.
+pub const NAMES: &[&str] = &[
+    "AbortMultipartUpload",
+    "CompleteMultipartUpload",
+    "CopyObject",
+    "CreateBucket",
+    "CreateBucketMetadataTableConfiguration",
+    "CreateMultipartUpload",
+    "CreateSession",
+    "DeleteBucket",
+    "DeleteBucketAnalyticsConfiguration",
+    "DeleteBucketCors",
+    "DeleteBucketEncryption",
+    "DeleteBucketIntelligentTieringConfiguration",
+    "DeleteBucketInventoryConfiguration",
+    "DeleteBucketLifecycle",
+    "DeleteBucketMetadataTableConfiguration",
+    "DeleteBucketMetricsConfiguration",
+    "DeleteBucketOwnershipControls",
+    "DeleteBucketPolicy",
+    "DeleteBucketReplication",
+    "DeleteBucketTagging",
+    "DeleteBucketWebsite",
+    "DeleteObject",
+    "DeleteObjectTagging",
+    "DeleteObjects",
+    "DeletePublicAccessBlock",
+    "GetBucketAccelerateConfiguration",
+    "GetBucketAcl",
+    "GetBucketAnalyticsConfiguration",
+    "GetBucketCors",
+    "GetBucketEncryption",
+    "GetBucketIntelligentTieringConfiguration",
+    "GetBucketInventoryConfiguration",
+    "GetBucketLifecycleConfiguration",
+    "GetBucketLocation",
+    "GetBucketLogging",
+    "GetBucketMetadataTableConfiguration",
+    "GetBucketMetricsConfiguration",
+    "GetBucketNotificationConfiguration",
+    "GetBucketOwnershipControls",
+    "GetBucketPolicy",
+    "GetBucketPolicyStatus",
+    "GetBucketReplication",
+    "GetBucketRequestPayment",
+    "GetBucketTagging",
+    "GetBucketVersioning",
+    "GetBucketWebsite",
+    "GetObject",
+    "GetObjectAcl",
+    "GetObjectAttributes",
+    "GetObjectLegalHold",
+    "GetObjectLockConfiguration",
+    "GetObjectRetention",
+    "GetObjectTagging",
+    "GetObjectTorrent",
+    "GetPublicAccessBlock",
+    "HeadBucket",
+    "HeadObject",
+    "ListBucketAnalyticsConfigurations",
+    "ListBucketIntelligentTieringConfigurations",
+    "ListBucketInventoryConfigurations",
+    "ListBucketMetricsConfigurations",
+    "ListBuckets",
+    "ListDirectoryBuckets",
+    "ListMultipartUploads",
+    "ListObjectVersions",
+    "ListObjects",
+    "ListObjectsV2",
+    "ListParts",
+    "PostObject",
+    "PutBucketAccelerateConfiguration",
+    "PutBucketAcl",
+    "PutBucketAnalyticsConfiguration",
+    "PutBucketCors",
+    "PutBucketEncryption",
+    "PutBucketIntelligentTieringConfiguration",
+    "PutBucketInventoryConfiguration",
+    "PutBucketLifecycleConfiguration",
+    "PutBucketLogging",
+    "PutBucketMetricsConfiguration",
+    "PutBucketNotificationConfiguration",
+    "PutBucketOwnershipControls",
+    "PutBucketPolicy",
+    "PutBucketReplication",
+    "PutBucketRequestPayment",
+    "PutBucketTagging",
+    "PutBucketVersioning",
+    "PutBucketWebsite",
+    "PutObject",
+    "PutObjectAcl",
+    "PutObjectLegalHold",
+    "PutObjectLockConfiguration",
+    "PutObjectRetention",
+    "PutObjectTagging",
+    "PutPublicAccessBlock",
+    "RestoreObject",
+    "SelectObjectContent",
+    "UploadPart",
+    "UploadPartCopy",
+    "WriteGetObjectResponse",
+];
+
 impl http::TryIntoHeaderValue for ArchiveStatus {
     type Error = http::InvalidHeaderValue;
     fn try_into_header_value(self) -> Result<http::HeaderValue, Self::Error> {
@@ -536,18 +641,20 @@ impl super::Operation for AbortMultipartUpload {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.abort_multipart_upload(&mut s3_req).await?;
         }
-        let result = s3.abort_multipart_upload(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.abort_multipart_upload(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -645,18 +752,20 @@ impl super::Operation for CompleteMultipartUpload {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.complete_multipart_upload(&mut s3_req).await?;
         }
-        let result = s3.complete_multipart_upload(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.complete_multipart_upload(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -831,18 +940,20 @@ impl super::Operation for CopyObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.copy_object(&mut s3_req).await?;
         }
-        let result = s3.copy_object(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.copy_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -902,18 +1013,20 @@ impl super::Operation for CreateBucket {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.create_bucket(&mut s3_req).await?;
         }
-        let result = s3.create_bucket(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.create_bucket(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -955,18 +1068,23 @@ impl super::Operation for CreateBucketMetadataTableConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.create_bucket_metadata_table_configuration(&mut s3_req).await?;
         }
-        let result = s3.create_bucket_metadata_table_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .create_bucket_metadata_table_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1104,18 +1222,20 @@ impl super::Operation for CreateMultipartUpload {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.create_multipart_upload(&mut s3_req).await?;
         }
-        let result = s3.create_multipart_upload(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.create_multipart_upload(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1168,18 +1288,20 @@ impl super::Operation for CreateSession {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.create_session(&mut s3_req).await?;
         }
-        let result = s3.create_session(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.create_session(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1212,18 +1334,20 @@ impl super::Operation for DeleteBucket {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1259,18 +1383,23 @@ impl super::Operation for DeleteBucketAnalyticsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_analytics_configuration(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_analytics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_analytics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1303,18 +1432,20 @@ impl super::Operation for DeleteBucketCors {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_cors(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_cors(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_cors(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1347,18 +1478,20 @@ impl super::Operation for DeleteBucketEncryption {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_encryption(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_encryption(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_encryption(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1388,18 +1521,23 @@ impl super::Operation for DeleteBucketIntelligentTieringConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_intelligent_tiering_configuration(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_intelligent_tiering_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_intelligent_tiering_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1435,18 +1573,23 @@ impl super::Operation for DeleteBucketInventoryConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_inventory_configuration(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_inventory_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_inventory_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1479,18 +1622,20 @@ impl super::Operation for DeleteBucketLifecycle {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_lifecycle(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_lifecycle(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_lifecycle(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1523,18 +1668,23 @@ impl super::Operation for DeleteBucketMetadataTableConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_metadata_table_configuration(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_metadata_table_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_metadata_table_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1570,18 +1720,23 @@ impl super::Operation for DeleteBucketMetricsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_metrics_configuration(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_metrics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_metrics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1614,18 +1769,23 @@ impl super::Operation for DeleteBucketOwnershipControls {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_ownership_controls(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_ownership_controls(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_ownership_controls(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1658,18 +1818,20 @@ impl super::Operation for DeleteBucketPolicy {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_policy(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_policy(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_policy(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1702,18 +1864,20 @@ impl super::Operation for DeleteBucketReplication {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_replication(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_replication(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_replication(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1746,18 +1910,20 @@ impl super::Operation for DeleteBucketTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_tagging(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1790,18 +1956,20 @@ impl super::Operation for DeleteBucketWebsite {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_website(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_website(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_website(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1862,18 +2030,20 @@ impl super::Operation for DeleteObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_object(&mut s3_req).await?;
         }
-        let result = s3.delete_object(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1912,18 +2082,20 @@ impl super::Operation for DeleteObjectTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_object_tagging(&mut s3_req).await?;
         }
-        let result = s3.delete_object_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_object_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1975,18 +2147,20 @@ impl super::Operation for DeleteObjects {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_objects(&mut s3_req).await?;
         }
-        let result = s3.delete_objects(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_objects(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2019,18 +2193,20 @@ impl super::Operation for DeletePublicAccessBlock {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_public_access_block(&mut s3_req).await?;
         }
-        let result = s3.delete_public_access_block(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_public_access_block(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2069,18 +2245,23 @@ impl super::Operation for GetBucketAccelerateConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_accelerate_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_accelerate_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_accelerate_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2115,18 +2296,20 @@ impl super::Operation for GetBucketAcl {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_acl(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_acl(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_acl(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2166,18 +2349,23 @@ impl super::Operation for GetBucketAnalyticsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_analytics_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_analytics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_analytics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2212,18 +2400,20 @@ impl super::Operation for GetBucketCors {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_cors(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_cors(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_cors(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2260,18 +2450,20 @@ impl super::Operation for GetBucketEncryption {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_encryption(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_encryption(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_encryption(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2305,18 +2497,23 @@ impl super::Operation for GetBucketIntelligentTieringConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_intelligent_tiering_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_intelligent_tiering_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_intelligent_tiering_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2356,18 +2553,23 @@ impl super::Operation for GetBucketInventoryConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_inventory_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_inventory_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_inventory_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2407,18 +2609,23 @@ impl super::Operation for GetBucketLifecycleConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_lifecycle_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_lifecycle_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_lifecycle_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2453,18 +2660,20 @@ impl super::Operation for GetBucketLocation {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_location(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_location(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_location(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2499,18 +2708,20 @@ impl super::Operation for GetBucketLogging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_logging(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_logging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_logging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2547,18 +2758,23 @@ impl super::Operation for GetBucketMetadataTableConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_metadata_table_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_metadata_table_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_metadata_table_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2598,18 +2814,23 @@ impl super::Operation for GetBucketMetricsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_metrics_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_metrics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_metrics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2644,18 +2865,23 @@ impl super::Operation for GetBucketNotificationConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_notification_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_notification_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_notification_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2692,18 +2918,23 @@ impl super::Operation for GetBucketOwnershipControls {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_ownership_controls(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_ownership_controls(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_ownership_controls(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2740,18 +2971,20 @@ impl super::Operation for GetBucketPolicy {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_policy(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_policy(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_policy(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2788,18 +3021,20 @@ impl super::Operation for GetBucketPolicyStatus {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_policy_status(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_policy_status(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_policy_status(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2836,18 +3071,20 @@ impl super::Operation for GetBucketReplication {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_replication(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_replication(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_replication(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2882,18 +3119,20 @@ impl super::Operation for GetBucketRequestPayment {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_request_payment(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_request_payment(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_request_payment(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2928,18 +3167,20 @@ impl super::Operation for GetBucketTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_tagging(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2974,18 +3215,20 @@ impl super::Operation for GetBucketVersioning {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_versioning(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_versioning(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_versioning(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3020,18 +3263,20 @@ impl super::Operation for GetBucketWebsite {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_website(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_website(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_website(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3179,19 +3424,21 @@ impl super::Operation for GetObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object(&mut s3_req).await?;
         }
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
         let overridden_headers = super::get_object::extract_overridden_response_headers(&s3_req)?;
-        let result = s3.get_object(s3_req).await;
+        let mut result = s3.get_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(overridden_headers);
         super::get_object::merge_custom_headers(&mut resp, s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -3235,18 +3482,20 @@ impl super::Operation for GetObjectAcl {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_acl(&mut s3_req).await?;
         }
-        let result = s3.get_object_acl(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_acl(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3312,18 +3561,20 @@ impl super::Operation for GetObjectAttributes {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_attributes(&mut s3_req).await?;
         }
-        let result = s3.get_object_attributes(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_attributes(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3367,18 +3618,20 @@ impl super::Operation for GetObjectLegalHold {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_legal_hold(&mut s3_req).await?;
         }
-        let result = s3.get_object_legal_hold(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_legal_hold(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3415,18 +3668,23 @@ impl super::Operation for GetObjectLockConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_lock_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_object_lock_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_object_lock_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3470,18 +3728,20 @@ impl super::Operation for GetObjectRetention {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_retention(&mut s3_req).await?;
         }
-        let result = s3.get_object_retention(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_retention(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3524,18 +3784,20 @@ impl super::Operation for GetObjectTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_tagging(&mut s3_req).await?;
         }
-        let result = s3.get_object_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3577,18 +3839,20 @@ impl super::Operation for GetObjectTorrent {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_torrent(&mut s3_req).await?;
         }
-        let result = s3.get_object_torrent(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_torrent(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3625,18 +3889,20 @@ impl super::Operation for GetPublicAccessBlock {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_public_access_block(&mut s3_req).await?;
         }
-        let result = s3.get_public_access_block(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_public_access_block(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3674,18 +3940,20 @@ impl super::Operation for HeadBucket {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.head_bucket(&mut s3_req).await?;
         }
-        let result = s3.head_bucket(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.head_bucket(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3827,18 +4095,20 @@ impl super::Operation for HeadObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.head_object(&mut s3_req).await?;
         }
-        let result = s3.head_object(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.head_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3876,18 +4146,23 @@ impl super::Operation for ListBucketAnalyticsConfigurations {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_bucket_analytics_configurations(&mut s3_req).await?;
         }
-        let result = s3.list_bucket_analytics_configurations(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .list_bucket_analytics_configurations(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3922,18 +4197,23 @@ impl super::Operation for ListBucketIntelligentTieringConfigurations {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_bucket_intelligent_tiering_configurations(&mut s3_req).await?;
         }
-        let result = s3.list_bucket_intelligent_tiering_configurations(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .list_bucket_intelligent_tiering_configurations(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3971,18 +4251,23 @@ impl super::Operation for ListBucketInventoryConfigurations {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_bucket_inventory_configurations(&mut s3_req).await?;
         }
-        let result = s3.list_bucket_inventory_configurations(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .list_bucket_inventory_configurations(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4020,18 +4305,23 @@ impl super::Operation for ListBucketMetricsConfigurations {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_bucket_metrics_configurations(&mut s3_req).await?;
         }
-        let result = s3.list_bucket_metrics_configurations(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .list_bucket_metrics_configurations(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4072,18 +4362,20 @@ impl super::Operation for ListBuckets {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_buckets(&mut s3_req).await?;
         }
-        let result = s3.list_buckets(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_buckets(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4118,18 +4410,20 @@ impl super::Operation for ListDirectoryBuckets {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_directory_buckets(&mut s3_req).await?;
         }
-        let result = s3.list_directory_buckets(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_directory_buckets(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4186,18 +4480,20 @@ impl super::Operation for ListMultipartUploads {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_multipart_uploads(&mut s3_req).await?;
         }
-        let result = s3.list_multipart_uploads(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_multipart_uploads(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4258,18 +4554,20 @@ impl super::Operation for ListObjectVersions {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_object_versions(&mut s3_req).await?;
         }
-        let result = s3.list_object_versions(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_object_versions(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4327,18 +4625,20 @@ impl super::Operation for ListObjects {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_objects(&mut s3_req).await?;
         }
-        let result = s3.list_objects(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_objects(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4402,18 +4702,20 @@ impl super::Operation for ListObjectsV2 {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_objects_v2(&mut s3_req).await?;
         }
-        let result = s3.list_objects_v2(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_objects_v2(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4475,18 +4777,20 @@ impl super::Operation for ListParts {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_parts(&mut s3_req).await?;
         }
-        let result = s3.list_parts(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_parts(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4525,18 +4829,23 @@ impl super::Operation for PutBucketAccelerateConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_accelerate_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_accelerate_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_accelerate_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4596,18 +4905,20 @@ impl super::Operation for PutBucketAcl {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_acl(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_acl(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_acl(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4646,18 +4957,23 @@ impl super::Operation for PutBucketAnalyticsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_analytics_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_analytics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_analytics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4699,18 +5015,20 @@ impl super::Operation for PutBucketCors {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_cors(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_cors(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_cors(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4752,18 +5070,20 @@ impl super::Operation for PutBucketEncryption {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_encryption(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_encryption(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_encryption(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4799,18 +5119,23 @@ impl super::Operation for PutBucketIntelligentTieringConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_intelligent_tiering_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_intelligent_tiering_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_intelligent_tiering_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4849,18 +5174,23 @@ impl super::Operation for PutBucketInventoryConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_inventory_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_inventory_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_inventory_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4909,18 +5239,23 @@ impl super::Operation for PutBucketLifecycleConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_lifecycle_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_lifecycle_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_lifecycle_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4962,18 +5297,20 @@ impl super::Operation for PutBucketLogging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_logging(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_logging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_logging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5012,18 +5349,23 @@ impl super::Operation for PutBucketMetricsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_metrics_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_metrics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_metrics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5063,18 +5405,23 @@ impl super::Operation for PutBucketNotificationConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_notification_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_notification_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_notification_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5113,18 +5460,23 @@ impl super::Operation for PutBucketOwnershipControls {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_ownership_controls(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_ownership_controls(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_ownership_controls(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5170,18 +5522,20 @@ impl super::Operation for PutBucketPolicy {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_policy(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_policy(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_policy(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5226,18 +5580,20 @@ impl super::Operation for PutBucketReplication {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_replication(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_replication(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_replication(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5279,18 +5635,20 @@ impl super::Operation for PutBucketRequestPayment {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_request_payment(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_request_payment(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_request_payment(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5332,18 +5690,20 @@ impl super::Operation for PutBucketTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_tagging(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5388,18 +5748,20 @@ impl super::Operation for PutBucketVersioning {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_versioning(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_versioning(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_versioning(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5441,18 +5803,20 @@ impl super::Operation for PutBucketWebsite {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_website(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_website(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_website(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5464,7 +5828,7 @@ pub struct PutObject;
 impl PutObject {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutObjectInput> {
         if let Some(m) = req.s3ext.multipart.take() {
-            return Self::deserialize_http_multipart(req, m);
+            return Self::deserialize_http_multipart(req, *m);
         }
 
         let (bucket, key) = http::unwrap_object(req);
@@ -5817,18 +6181,20 @@ impl super::Operation for PutObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object(&mut s3_req).await?;
         }
-        let result = s3.put_object(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5897,18 +6263,20 @@ impl super::Operation for PutObjectAcl {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_acl(&mut s3_req).await?;
         }
-        let result = s3.put_object_acl(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_object_acl(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5965,18 +6333,20 @@ impl super::Operation for PutObjectLegalHold {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_legal_hold(&mut s3_req).await?;
         }
-        let result = s3.put_object_legal_hold(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_object_legal_hold(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6026,18 +6396,23 @@ impl super::Operation for PutObjectLockConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_lock_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_object_lock_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_object_lock_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6098,18 +6473,20 @@ impl super::Operation for PutObjectRetention {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_retention(&mut s3_req).await?;
         }
-        let result = s3.put_object_retention(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_object_retention(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6160,18 +6537,20 @@ impl super::Operation for PutObjectTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_tagging(&mut s3_req).await?;
         }
-        let result = s3.put_object_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_object_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6213,18 +6592,20 @@ impl super::Operation for PutPublicAccessBlock {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_public_access_block(&mut s3_req).await?;
         }
-        let result = s3.put_public_access_block(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_public_access_block(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6273,18 +6654,20 @@ impl super::Operation for RestoreObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.restore_object(&mut s3_req).await?;
         }
-        let result = s3.restore_object(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.restore_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6336,18 +6719,20 @@ impl super::Operation for SelectObjectContent {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.select_object_content(&mut s3_req).await?;
         }
-        let result = s3.select_object_content(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.select_object_content(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6461,18 +6846,20 @@ impl super::Operation for UploadPart {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.upload_part(&mut s3_req).await?;
         }
-        let result = s3.upload_part(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.upload_part(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6573,18 +6960,20 @@ impl super::Operation for UploadPartCopy {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.upload_part_copy(&mut s3_req).await?;
         }
-        let result = s3.upload_part_copy(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.upload_part_copy(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6764,18 +7153,20 @@ impl super::Operation for WriteGetObjectResponse {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.write_get_object_response(&mut s3_req).await?;
         }
-        let result = s3.write_get_object_response(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.write_get_object_response(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6786,7 +7177,7 @@ pub struct PostObject;
 
 impl PostObject {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PostObjectInput> {
-        let Some(m) = req.s3ext.multipart.take() else {
+        let Some(m) = req.s3ext.multipart.take().map(|m| *m) else {
             return Err(invalid_request!("missing multipart form"));
         };
 
@@ -6798,7 +7189,7 @@ impl PostObject {
         let success_action_status: Option<i32> = http::parse_field_value(&m, "success_action_status")?;
 
         // Get the validated POST policy from request extensions
-        let policy = req.s3ext.post_policy.take();
+        let policy = req.s3ext.post_policy.take().map(|p| *p);
 
         let put_input = PutObject::deserialize_http_multipart(req, m)?;
         let mut post_input = put_object_input_into_post_object_input(put_input);
@@ -6873,7 +7264,7 @@ impl super::Operation for PostObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let post_input = Self::deserialize_http(req)?;
+        let post_input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         // Save POST-specific fields before conversion
         let success_action_redirect = post_input.success_action_redirect.clone();
         let success_action_status = post_input.success_action_status;
@@ -6895,20 +7286,518 @@ impl super::Operation for PostObject {
             // New hook for POST object (optional).
             access.post_object(&mut post_req).await?;
         }
-        let result = s3.post_object(post_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut post_req).await?;
+        let mut result = s3.post_object(post_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
         // Serialize with POST-specific response behavior
-        let mut resp =
-            Self::serialize_http(&bucket, &key, success_action_redirect.as_deref(), success_action_status, &s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| {
+            Self::serialize_http(&bucket, &key, success_action_redirect.as_deref(), success_action_status, &s3_resp.output)
+        })?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
     }
 }
 
+impl crate::intercept::OperationInput for AbortMultipartUploadInput {
+    type Output = AbortMultipartUploadOutput;
+    const NAME: &'static str = "AbortMultipartUpload";
+}
+
+impl crate::intercept::OperationInput for CompleteMultipartUploadInput {
+    type Output = CompleteMultipartUploadOutput;
+    const NAME: &'static str = "CompleteMultipartUpload";
+}
+
+impl crate::intercept::OperationInput for CopyObjectInput {
+    type Output = CopyObjectOutput;
+    const NAME: &'static str = "CopyObject";
+}
+
+impl crate::intercept::OperationInput for CreateBucketInput {
+    type Output = CreateBucketOutput;
+    const NAME: &'static str = "CreateBucket";
+}
+
+impl crate::intercept::OperationInput for CreateBucketMetadataTableConfigurationInput {
+    type Output = CreateBucketMetadataTableConfigurationOutput;
+    const NAME: &'static str = "CreateBucketMetadataTableConfiguration";
+}
+
+impl crate::intercept::OperationInput for CreateMultipartUploadInput {
+    type Output = CreateMultipartUploadOutput;
+    const NAME: &'static str = "CreateMultipartUpload";
+}
+
+impl crate::intercept::OperationInput for CreateSessionInput {
+    type Output = CreateSessionOutput;
+    const NAME: &'static str = "CreateSession";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketInput {
+    type Output = DeleteBucketOutput;
+    const NAME: &'static str = "DeleteBucket";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketAnalyticsConfigurationInput {
+    type Output = DeleteBucketAnalyticsConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketAnalyticsConfiguration";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketCorsInput {
+    type Output = DeleteBucketCorsOutput;
+    const NAME: &'static str = "DeleteBucketCors";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketEncryptionInput {
+    type Output = DeleteBucketEncryptionOutput;
+    const NAME: &'static str = "DeleteBucketEncryption";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketIntelligentTieringConfigurationInput {
+    type Output = DeleteBucketIntelligentTieringConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketIntelligentTieringConfiguration";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketInventoryConfigurationInput {
+    type Output = DeleteBucketInventoryConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketInventoryConfiguration";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketLifecycleInput {
+    type Output = DeleteBucketLifecycleOutput;
+    const NAME: &'static str = "DeleteBucketLifecycle";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketMetadataTableConfigurationInput {
+    type Output = DeleteBucketMetadataTableConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketMetadataTableConfiguration";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketMetricsConfigurationInput {
+    type Output = DeleteBucketMetricsConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketMetricsConfiguration";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketOwnershipControlsInput {
+    type Output = DeleteBucketOwnershipControlsOutput;
+    const NAME: &'static str = "DeleteBucketOwnershipControls";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketPolicyInput {
+    type Output = DeleteBucketPolicyOutput;
+    const NAME: &'static str = "DeleteBucketPolicy";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketReplicationInput {
+    type Output = DeleteBucketReplicationOutput;
+    const NAME: &'static str = "DeleteBucketReplication";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketTaggingInput {
+    type Output = DeleteBucketTaggingOutput;
+    const NAME: &'static str = "DeleteBucketTagging";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketWebsiteInput {
+    type Output = DeleteBucketWebsiteOutput;
+    const NAME: &'static str = "DeleteBucketWebsite";
+}
+
+impl crate::intercept::OperationInput for DeleteObjectInput {
+    type Output = DeleteObjectOutput;
+    const NAME: &'static str = "DeleteObject";
+}
+
+impl crate::intercept::OperationInput for DeleteObjectTaggingInput {
+    type Output = DeleteObjectTaggingOutput;
+    const NAME: &'static str = "DeleteObjectTagging";
+}
+
+impl crate::intercept::OperationInput for DeleteObjectsInput {
+    type Output = DeleteObjectsOutput;
+    const NAME: &'static str = "DeleteObjects";
+}
+
+impl crate::intercept::OperationInput for DeletePublicAccessBlockInput {
+    type Output = DeletePublicAccessBlockOutput;
+    const NAME: &'static str = "DeletePublicAccessBlock";
+}
+
+impl crate::intercept::OperationInput for GetBucketAccelerateConfigurationInput {
+    type Output = GetBucketAccelerateConfigurationOutput;
+    const NAME: &'static str = "GetBucketAccelerateConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketAclInput {
+    type Output = GetBucketAclOutput;
+    const NAME: &'static str = "GetBucketAcl";
+}
+
+impl crate::intercept::OperationInput for GetBucketAnalyticsConfigurationInput {
+    type Output = GetBucketAnalyticsConfigurationOutput;
+    const NAME: &'static str = "GetBucketAnalyticsConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketCorsInput {
+    type Output = GetBucketCorsOutput;
+    const NAME: &'static str = "GetBucketCors";
+}
+
+impl crate::intercept::OperationInput for GetBucketEncryptionInput {
+    type Output = GetBucketEncryptionOutput;
+    const NAME: &'static str = "GetBucketEncryption";
+}
+
+impl crate::intercept::OperationInput for GetBucketIntelligentTieringConfigurationInput {
+    type Output = GetBucketIntelligentTieringConfigurationOutput;
+    const NAME: &'static str = "GetBucketIntelligentTieringConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketInventoryConfigurationInput {
+    type Output = GetBucketInventoryConfigurationOutput;
+    const NAME: &'static str = "GetBucketInventoryConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketLifecycleConfigurationInput {
+    type Output = GetBucketLifecycleConfigurationOutput;
+    const NAME: &'static str = "GetBucketLifecycleConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketLocationInput {
+    type Output = GetBucketLocationOutput;
+    const NAME: &'static str = "GetBucketLocation";
+}
+
+impl crate::intercept::OperationInput for GetBucketLoggingInput {
+    type Output = GetBucketLoggingOutput;
+    const NAME: &'static str = "GetBucketLogging";
+}
+
+impl crate::intercept::OperationInput for GetBucketMetadataTableConfigurationInput {
+    type Output = GetBucketMetadataTableConfigurationOutput;
+    const NAME: &'static str = "GetBucketMetadataTableConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketMetricsConfigurationInput {
+    type Output = GetBucketMetricsConfigurationOutput;
+    const NAME: &'static str = "GetBucketMetricsConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketNotificationConfigurationInput {
+    type Output = GetBucketNotificationConfigurationOutput;
+    const NAME: &'static str = "GetBucketNotificationConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketOwnershipControlsInput {
+    type Output = GetBucketOwnershipControlsOutput;
+    const NAME: &'static str = "GetBucketOwnershipControls";
+}
+
+impl crate::intercept::OperationInput for GetBucketPolicyInput {
+    type Output = GetBucketPolicyOutput;
+    const NAME: &'static str = "GetBucketPolicy";
+}
+
+impl crate::intercept::OperationInput for GetBucketPolicyStatusInput {
+    type Output = GetBucketPolicyStatusOutput;
+    const NAME: &'static str = "GetBucketPolicyStatus";
+}
+
+impl crate::intercept::OperationInput for GetBucketReplicationInput {
+    type Output = GetBucketReplicationOutput;
+    const NAME: &'static str = "GetBucketReplication";
+}
+
+impl crate::intercept::OperationInput for GetBucketRequestPaymentInput {
+    type Output = GetBucketRequestPaymentOutput;
+    const NAME: &'static str = "GetBucketRequestPayment";
+}
+
+impl crate::intercept::OperationInput for GetBucketTaggingInput {
+    type Output = GetBucketTaggingOutput;
+    const NAME: &'static str = "GetBucketTagging";
+}
+
+impl crate::intercept::OperationInput for GetBucketVersioningInput {
+    type Output = GetBucketVersioningOutput;
+    const NAME: &'static str = "GetBucketVersioning";
+}
+
+impl crate::intercept::OperationInput for GetBucketWebsiteInput {
+    type Output = GetBucketWebsiteOutput;
+    const NAME: &'static str = "GetBucketWebsite";
+}
+
+impl crate::intercept::OperationInput for GetObjectInput {
+    type Output = GetObjectOutput;
+    const NAME: &'static str = "GetObject";
+}
+
+impl crate::intercept::OperationInput for GetObjectAclInput {
+    type Output = GetObjectAclOutput;
+    const NAME: &'static str = "GetObjectAcl";
+}
+
+impl crate::intercept::OperationInput for GetObjectAttributesInput {
+    type Output = GetObjectAttributesOutput;
+    const NAME: &'static str = "GetObjectAttributes";
+}
+
+impl crate::intercept::OperationInput for GetObjectLegalHoldInput {
+    type Output = GetObjectLegalHoldOutput;
+    const NAME: &'static str = "GetObjectLegalHold";
+}
+
+impl crate::intercept::OperationInput for GetObjectLockConfigurationInput {
+    type Output = GetObjectLockConfigurationOutput;
+    const NAME: &'static str = "GetObjectLockConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetObjectRetentionInput {
+    type Output = GetObjectRetentionOutput;
+    const NAME: &'static str = "GetObjectRetention";
+}
+
+impl crate::intercept::OperationInput for GetObjectTaggingInput {
+    type Output = GetObjectTaggingOutput;
+    const NAME: &'static str = "GetObjectTagging";
+}
+
+impl crate::intercept::OperationInput for GetObjectTorrentInput {
+    type Output = GetObjectTorrentOutput;
+    const NAME: &'static str = "GetObjectTorrent";
+}
+
+impl crate::intercept::OperationInput for GetPublicAccessBlockInput {
+    type Output = GetPublicAccessBlockOutput;
+    const NAME: &'static str = "GetPublicAccessBlock";
+}
+
+impl crate::intercept::OperationInput for HeadBucketInput {
+    type Output = HeadBucketOutput;
+    const NAME: &'static str = "HeadBucket";
+}
+
+impl crate::intercept::OperationInput for HeadObjectInput {
+    type Output = HeadObjectOutput;
+    const NAME: &'static str = "HeadObject";
+}
+
+impl crate::intercept::OperationInput for ListBucketAnalyticsConfigurationsInput {
+    type Output = ListBucketAnalyticsConfigurationsOutput;
+    const NAME: &'static str = "ListBucketAnalyticsConfigurations";
+}
+
+impl crate::intercept::OperationInput for ListBucketIntelligentTieringConfigurationsInput {
+    type Output = ListBucketIntelligentTieringConfigurationsOutput;
+    const NAME: &'static str = "ListBucketIntelligentTieringConfigurations";
+}
+
+impl crate::intercept::OperationInput for ListBucketInventoryConfigurationsInput {
+    type Output = ListBucketInventoryConfigurationsOutput;
+    const NAME: &'static str = "ListBucketInventoryConfigurations";
+}
+
+impl crate::intercept::OperationInput for ListBucketMetricsConfigurationsInput {
+    type Output = ListBucketMetricsConfigurationsOutput;
+    const NAME: &'static str = "ListBucketMetricsConfigurations";
+}
+
+impl crate::intercept::OperationInput for ListBucketsInput {
+    type Output = ListBucketsOutput;
+    const NAME: &'static str = "ListBuckets";
+}
+
+impl crate::intercept::OperationInput for ListDirectoryBucketsInput {
+    type Output = ListDirectoryBucketsOutput;
+    const NAME: &'static str = "ListDirectoryBuckets";
+}
+
+impl crate::intercept::OperationInput for ListMultipartUploadsInput {
+    type Output = ListMultipartUploadsOutput;
+    const NAME: &'static str = "ListMultipartUploads";
+}
+
+impl crate::intercept::OperationInput for ListObjectVersionsInput {
+    type Output = ListObjectVersionsOutput;
+    const NAME: &'static str = "ListObjectVersions";
+}
+
+impl crate::intercept::OperationInput for ListObjectsInput {
+    type Output = ListObjectsOutput;
+    const NAME: &'static str = "ListObjects";
+}
+
+impl crate::intercept::OperationInput for ListObjectsV2Input {
+    type Output = ListObjectsV2Output;
+    const NAME: &'static str = "ListObjectsV2";
+}
+
+impl crate::intercept::OperationInput for ListPartsInput {
+    type Output = ListPartsOutput;
+    const NAME: &'static str = "ListParts";
+}
+
+impl crate::intercept::OperationInput for PostObjectInput {
+    type Output = PostObjectOutput;
+    const NAME: &'static str = "PostObject";
+}
+
+impl crate::intercept::OperationInput for PutBucketAccelerateConfigurationInput {
+    type Output = PutBucketAccelerateConfigurationOutput;
+    const NAME: &'static str = "PutBucketAccelerateConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketAclInput {
+    type Output = PutBucketAclOutput;
+    const NAME: &'static str = "PutBucketAcl";
+}
+
+impl crate::intercept::OperationInput for PutBucketAnalyticsConfigurationInput {
+    type Output = PutBucketAnalyticsConfigurationOutput;
+    const NAME: &'static str = "PutBucketAnalyticsConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketCorsInput {
+    type Output = PutBucketCorsOutput;
+    const NAME: &'static str = "PutBucketCors";
+}
+
+impl crate::intercept::OperationInput for PutBucketEncryptionInput {
+    type Output = PutBucketEncryptionOutput;
+    const NAME: &'static str = "PutBucketEncryption";
+}
+
+impl crate::intercept::OperationInput for PutBucketIntelligentTieringConfigurationInput {
+    type Output = PutBucketIntelligentTieringConfigurationOutput;
+    const NAME: &'static str = "PutBucketIntelligentTieringConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketInventoryConfigurationInput {
+    type Output = PutBucketInventoryConfigurationOutput;
+    const NAME: &'static str = "PutBucketInventoryConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketLifecycleConfigurationInput {
+    type Output = PutBucketLifecycleConfigurationOutput;
+    const NAME: &'static str = "PutBucketLifecycleConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketLoggingInput {
+    type Output = PutBucketLoggingOutput;
+    const NAME: &'static str = "PutBucketLogging";
+}
+
+impl crate::intercept::OperationInput for PutBucketMetricsConfigurationInput {
+    type Output = PutBucketMetricsConfigurationOutput;
+    const NAME: &'static str = "PutBucketMetricsConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketNotificationConfigurationInput {
+    type Output = PutBucketNotificationConfigurationOutput;
+    const NAME: &'static str = "PutBucketNotificationConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketOwnershipControlsInput {
+    type Output = PutBucketOwnershipControlsOutput;
+    const NAME: &'static str = "PutBucketOwnershipControls";
+}
+
+impl crate::intercept::OperationInput for PutBucketPolicyInput {
+    type Output = PutBucketPolicyOutput;
+    const NAME: &'static str = "PutBucketPolicy";
+}
+
+impl crate::intercept::OperationInput for PutBucketReplicationInput {
+    type Output = PutBucketReplicationOutput;
+    const NAME: &'static str = "PutBucketReplication";
+}
+
+impl crate::intercept::OperationInput for PutBucketRequestPaymentInput {
+    type Output = PutBucketRequestPaymentOutput;
+    const NAME: &'static str = "PutBucketRequestPayment";
+}
+
+impl crate::intercept::OperationInput for PutBucketTaggingInput {
+    type Output = PutBucketTaggingOutput;
+    const NAME: &'static str = "PutBucketTagging";
+}
+
+impl crate::intercept::OperationInput for PutBucketVersioningInput {
+    type Output = PutBucketVersioningOutput;
+    const NAME: &'static str = "PutBucketVersioning";
+}
+
+impl crate::intercept::OperationInput for PutBucketWebsiteInput {
+    type Output = PutBucketWebsiteOutput;
+    const NAME: &'static str = "PutBucketWebsite";
+}
+
+impl crate::intercept::OperationInput for PutObjectInput {
+    type Output = PutObjectOutput;
+    const NAME: &'static str = "PutObject";
+}
+
+impl crate::intercept::OperationInput for PutObjectAclInput {
+    type Output = PutObjectAclOutput;
+    const NAME: &'static str = "PutObjectAcl";
+}
+
+impl crate::intercept::OperationInput for PutObjectLegalHoldInput {
+    type Output = PutObjectLegalHoldOutput;
+    const NAME: &'static str = "PutObjectLegalHold";
+}
+
+impl crate::intercept::OperationInput for PutObjectLockConfigurationInput {
+    type Output = PutObjectLockConfigurationOutput;
+    const NAME: &'static str = "PutObjectLockConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutObjectRetentionInput {
+    type Output = PutObjectRetentionOutput;
+    const NAME: &'static str = "PutObjectRetention";
+}
+
+impl crate::intercept::OperationInput for PutObjectTaggingInput {
+    type Output = PutObjectTaggingOutput;
+    const NAME: &'static str = "PutObjectTagging";
+}
+
+impl crate::intercept::OperationInput for PutPublicAccessBlockInput {
+    type Output = PutPublicAccessBlockOutput;
+    const NAME: &'static str = "PutPublicAccessBlock";
+}
+
+impl crate::intercept::OperationInput for RestoreObjectInput {
+    type Output = RestoreObjectOutput;
+    const NAME: &'static str = "RestoreObject";
+}
+
+impl crate::intercept::OperationInput for SelectObjectContentInput {
+    type Output = SelectObjectContentOutput;
+    const NAME: &'static str = "SelectObjectContent";
+}
+
+impl crate::intercept::OperationInput for UploadPartInput {
+    type Output = UploadPartOutput;
+    const NAME: &'static str = "UploadPart";
+}
+
+impl crate::intercept::OperationInput for UploadPartCopyInput {
+    type Output = UploadPartCopyOutput;
+    const NAME: &'static str = "UploadPartCopy";
+}
+
+impl crate::intercept::OperationInput for WriteGetObjectResponseInput {
+    type Output = WriteGetObjectResponseOutput;
+    const NAME: &'static str = "WriteGetObjectResponse";
+}
+
 pub fn resolve_route(
     req: &http::Request,
     s3_path: &S3Path,
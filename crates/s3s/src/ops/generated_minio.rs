@@ -119,6 +119,111 @@ use crate::path::S3Path;
 
 use std::borrow::Cow;
 
+use tracing::{Instrument, debug_span};
+
+/// The names of all operations, in alphabetical order.
+pub const NAMES: &[&str] = &[
+    "AbortMultipartUpload",
+    "CompleteMultipartUpload",
+    "CopyObject",
+    "CreateBucket",
+    "CreateBucketMetadataTableConfiguration",
+    "CreateMultipartUpload",
+    "CreateSession",
+    "DeleteBucket",
+    "DeleteBucketAnalyticsConfiguration",
+    "DeleteBucketCors",
+    "DeleteBucketEncryption",
+    "DeleteBucketIntelligentTieringConfiguration",
+    "DeleteBucketInventoryConfiguration",
+    "DeleteBucketLifecycle",
+    "DeleteBucketMetadataTableConfiguration",
+    "DeleteBucketMetricsConfiguration",
+    "DeleteBucketOwnershipControls",
+    "DeleteBucketPolicy",
+    "DeleteBucketReplication",
+    "DeleteBucketTagging",
+    "DeleteBucketWebsite",
+    "DeleteObject",
+    "DeleteObjectTagging",
+    "DeleteObjects",
+    "DeletePublicAccessBlock",
+    "GetBucketAccelerateConfiguration",
+    "GetBucketAcl",
+    "GetBucketAnalyticsConfiguration",
+    "GetBucketCors",
+    "GetBucketEncryption",
+    "GetBucketIntelligentTieringConfiguration",
+    "GetBucketInventoryConfiguration",
+    "GetBucketLifecycleConfiguration",
+    "GetBucketLocation",
+    "GetBucketLogging",
+    "GetBucketMetadataTableConfiguration",
+    "GetBucketMetricsConfiguration",
+    "GetBucketNotificationConfiguration",
+    "GetBucketOwnershipControls",
+    "GetBucketPolicy",
+    "GetBucketPolicyStatus",
+    "GetBucketReplication",
+    "GetBucketRequestPayment",
+    "GetBucketTagging",
+    "GetBucketVersioning",
+    "GetBucketWebsite",
+    "GetObject",
+    "GetObjectAcl",
+    "GetObjectAttributes",
+    "GetObjectLegalHold",
+    "GetObjectLockConfiguration",
+    "GetObjectRetention",
+    "GetObjectTagging",
+    "GetObjectTorrent",
+    "GetPublicAccessBlock",
+    "HeadBucket",
+    "HeadObject",
+    "ListBucketAnalyticsConfigurations",
+    "ListBucketIntelligentTieringConfigurations",
+    "ListBucketInventoryConfigurations",
+    "ListBucketMetricsConfigurations",
+    "ListBuckets",
+    "ListDirectoryBuckets",
+    "ListMultipartUploads",
+    "ListObjectVersions",
+    "ListObjects",
+    "ListObjectsV2",
+    "ListParts",
+    "PostObject",
+    "PutBucketAccelerateConfiguration",
+    "PutBucketAcl",
+    "PutBucketAnalyticsConfiguration",
+    "PutBucketCors",
+    "PutBucketEncryption",
+    "PutBucketIntelligentTieringConfiguration",
+    "PutBucketInventoryConfiguration",
+    "PutBucketLifecycleConfiguration",
+    "PutBucketLogging",
+    "PutBucketMetricsConfiguration",
+    "PutBucketNotificationConfiguration",
+    "PutBucketOwnershipControls",
+    "PutBucketPolicy",
+    "PutBucketReplication",
+    "PutBucketRequestPayment",
+    "PutBucketTagging",
+    "PutBucketVersioning",
+    "PutBucketWebsite",
+    "PutObject",
+    "PutObjectAcl",
+    "PutObjectLegalHold",
+    "PutObjectLockConfiguration",
+    "PutObjectRetention",
+    "PutObjectTagging",
+    "PutPublicAccessBlock",
+    "RestoreObject",
+    "SelectObjectContent",
+    "UploadPart",
+    "UploadPartCopy",
+    "WriteGetObjectResponse",
+];
+
 impl http::TryIntoHeaderValue for ArchiveStatus {
     type Error = http::InvalidHeaderValue;
     fn try_into_header_value(self) -> Result<http::HeaderValue, Self::Error> {
@@ -536,18 +641,20 @@ impl super::Operation for AbortMultipartUpload {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.abort_multipart_upload(&mut s3_req).await?;
         }
-        let result = s3.abort_multipart_upload(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.abort_multipart_upload(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -645,18 +752,20 @@ impl super::Operation for CompleteMultipartUpload {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.complete_multipart_upload(&mut s3_req).await?;
         }
-        let result = s3.complete_multipart_upload(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.complete_multipart_upload(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -834,18 +943,20 @@ impl super::Operation for CopyObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.copy_object(&mut s3_req).await?;
         }
-        let result = s3.copy_object(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.copy_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -905,18 +1016,20 @@ impl super::Operation for CreateBucket {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.create_bucket(&mut s3_req).await?;
         }
-        let result = s3.create_bucket(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.create_bucket(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -958,18 +1071,23 @@ impl super::Operation for CreateBucketMetadataTableConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.create_bucket_metadata_table_configuration(&mut s3_req).await?;
         }
-        let result = s3.create_bucket_metadata_table_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .create_bucket_metadata_table_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1110,18 +1228,20 @@ impl super::Operation for CreateMultipartUpload {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.create_multipart_upload(&mut s3_req).await?;
         }
-        let result = s3.create_multipart_upload(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.create_multipart_upload(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1174,18 +1294,20 @@ impl super::Operation for CreateSession {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.create_session(&mut s3_req).await?;
         }
-        let result = s3.create_session(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.create_session(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1221,18 +1343,20 @@ impl super::Operation for DeleteBucket {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1268,18 +1392,23 @@ impl super::Operation for DeleteBucketAnalyticsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_analytics_configuration(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_analytics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_analytics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1312,18 +1441,20 @@ impl super::Operation for DeleteBucketCors {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_cors(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_cors(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_cors(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1356,18 +1487,20 @@ impl super::Operation for DeleteBucketEncryption {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_encryption(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_encryption(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_encryption(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1397,18 +1530,23 @@ impl super::Operation for DeleteBucketIntelligentTieringConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_intelligent_tiering_configuration(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_intelligent_tiering_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_intelligent_tiering_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1444,18 +1582,23 @@ impl super::Operation for DeleteBucketInventoryConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_inventory_configuration(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_inventory_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_inventory_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1488,18 +1631,20 @@ impl super::Operation for DeleteBucketLifecycle {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_lifecycle(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_lifecycle(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_lifecycle(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1532,18 +1677,23 @@ impl super::Operation for DeleteBucketMetadataTableConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_metadata_table_configuration(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_metadata_table_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_metadata_table_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1579,18 +1729,23 @@ impl super::Operation for DeleteBucketMetricsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_metrics_configuration(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_metrics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_metrics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1623,18 +1778,23 @@ impl super::Operation for DeleteBucketOwnershipControls {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_ownership_controls(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_ownership_controls(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .delete_bucket_ownership_controls(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1667,18 +1827,20 @@ impl super::Operation for DeleteBucketPolicy {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_policy(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_policy(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_policy(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1711,18 +1873,20 @@ impl super::Operation for DeleteBucketReplication {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_replication(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_replication(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_replication(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1755,18 +1919,20 @@ impl super::Operation for DeleteBucketTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_tagging(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1799,18 +1965,20 @@ impl super::Operation for DeleteBucketWebsite {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_bucket_website(&mut s3_req).await?;
         }
-        let result = s3.delete_bucket_website(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_bucket_website(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1871,18 +2039,20 @@ impl super::Operation for DeleteObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_object(&mut s3_req).await?;
         }
-        let result = s3.delete_object(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1921,18 +2091,20 @@ impl super::Operation for DeleteObjectTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_object_tagging(&mut s3_req).await?;
         }
-        let result = s3.delete_object_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_object_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -1984,18 +2156,20 @@ impl super::Operation for DeleteObjects {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_objects(&mut s3_req).await?;
         }
-        let result = s3.delete_objects(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_objects(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2028,18 +2202,20 @@ impl super::Operation for DeletePublicAccessBlock {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_public_access_block(&mut s3_req).await?;
         }
-        let result = s3.delete_public_access_block(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.delete_public_access_block(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2078,18 +2254,23 @@ impl super::Operation for GetBucketAccelerateConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_accelerate_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_accelerate_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_accelerate_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2124,18 +2305,20 @@ impl super::Operation for GetBucketAcl {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_acl(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_acl(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_acl(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2175,18 +2358,23 @@ impl super::Operation for GetBucketAnalyticsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_analytics_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_analytics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_analytics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2221,18 +2409,20 @@ impl super::Operation for GetBucketCors {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_cors(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_cors(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_cors(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2269,18 +2459,20 @@ impl super::Operation for GetBucketEncryption {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_encryption(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_encryption(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_encryption(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2314,18 +2506,23 @@ impl super::Operation for GetBucketIntelligentTieringConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_intelligent_tiering_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_intelligent_tiering_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_intelligent_tiering_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2365,18 +2562,23 @@ impl super::Operation for GetBucketInventoryConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_inventory_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_inventory_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_inventory_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2416,18 +2618,23 @@ impl super::Operation for GetBucketLifecycleConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_lifecycle_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_lifecycle_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_lifecycle_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2462,18 +2669,20 @@ impl super::Operation for GetBucketLocation {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_location(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_location(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_location(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2508,18 +2717,20 @@ impl super::Operation for GetBucketLogging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_logging(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_logging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_logging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2556,18 +2767,23 @@ impl super::Operation for GetBucketMetadataTableConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_metadata_table_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_metadata_table_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_metadata_table_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2607,18 +2823,23 @@ impl super::Operation for GetBucketMetricsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_metrics_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_metrics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_metrics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2653,18 +2874,23 @@ impl super::Operation for GetBucketNotificationConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_notification_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_notification_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_notification_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2701,18 +2927,23 @@ impl super::Operation for GetBucketOwnershipControls {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_ownership_controls(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_ownership_controls(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_bucket_ownership_controls(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2749,18 +2980,20 @@ impl super::Operation for GetBucketPolicy {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_policy(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_policy(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_policy(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2797,18 +3030,20 @@ impl super::Operation for GetBucketPolicyStatus {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_policy_status(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_policy_status(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_policy_status(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2845,18 +3080,20 @@ impl super::Operation for GetBucketReplication {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_replication(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_replication(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_replication(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2891,18 +3128,20 @@ impl super::Operation for GetBucketRequestPayment {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_request_payment(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_request_payment(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_request_payment(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2937,18 +3176,20 @@ impl super::Operation for GetBucketTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_tagging(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -2983,18 +3224,20 @@ impl super::Operation for GetBucketVersioning {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_versioning(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_versioning(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_versioning(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3029,18 +3272,20 @@ impl super::Operation for GetBucketWebsite {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_bucket_website(&mut s3_req).await?;
         }
-        let result = s3.get_bucket_website(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_bucket_website(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3188,19 +3433,21 @@ impl super::Operation for GetObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object(&mut s3_req).await?;
         }
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
         let overridden_headers = super::get_object::extract_overridden_response_headers(&s3_req)?;
-        let result = s3.get_object(s3_req).await;
+        let mut result = s3.get_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(overridden_headers);
         super::get_object::merge_custom_headers(&mut resp, s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -3244,18 +3491,20 @@ impl super::Operation for GetObjectAcl {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_acl(&mut s3_req).await?;
         }
-        let result = s3.get_object_acl(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_acl(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3321,18 +3570,20 @@ impl super::Operation for GetObjectAttributes {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_attributes(&mut s3_req).await?;
         }
-        let result = s3.get_object_attributes(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_attributes(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3376,18 +3627,20 @@ impl super::Operation for GetObjectLegalHold {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_legal_hold(&mut s3_req).await?;
         }
-        let result = s3.get_object_legal_hold(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_legal_hold(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3424,18 +3677,23 @@ impl super::Operation for GetObjectLockConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_lock_configuration(&mut s3_req).await?;
         }
-        let result = s3.get_object_lock_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .get_object_lock_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3479,18 +3737,20 @@ impl super::Operation for GetObjectRetention {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_retention(&mut s3_req).await?;
         }
-        let result = s3.get_object_retention(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_retention(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3533,18 +3793,20 @@ impl super::Operation for GetObjectTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_tagging(&mut s3_req).await?;
         }
-        let result = s3.get_object_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3586,18 +3848,20 @@ impl super::Operation for GetObjectTorrent {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_object_torrent(&mut s3_req).await?;
         }
-        let result = s3.get_object_torrent(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_object_torrent(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3634,18 +3898,20 @@ impl super::Operation for GetPublicAccessBlock {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.get_public_access_block(&mut s3_req).await?;
         }
-        let result = s3.get_public_access_block(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.get_public_access_block(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3683,18 +3949,20 @@ impl super::Operation for HeadBucket {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.head_bucket(&mut s3_req).await?;
         }
-        let result = s3.head_bucket(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.head_bucket(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3836,18 +4104,20 @@ impl super::Operation for HeadObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.head_object(&mut s3_req).await?;
         }
-        let result = s3.head_object(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.head_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3885,18 +4155,23 @@ impl super::Operation for ListBucketAnalyticsConfigurations {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_bucket_analytics_configurations(&mut s3_req).await?;
         }
-        let result = s3.list_bucket_analytics_configurations(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .list_bucket_analytics_configurations(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3931,18 +4206,23 @@ impl super::Operation for ListBucketIntelligentTieringConfigurations {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_bucket_intelligent_tiering_configurations(&mut s3_req).await?;
         }
-        let result = s3.list_bucket_intelligent_tiering_configurations(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .list_bucket_intelligent_tiering_configurations(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -3980,18 +4260,23 @@ impl super::Operation for ListBucketInventoryConfigurations {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_bucket_inventory_configurations(&mut s3_req).await?;
         }
-        let result = s3.list_bucket_inventory_configurations(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .list_bucket_inventory_configurations(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4029,18 +4314,23 @@ impl super::Operation for ListBucketMetricsConfigurations {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_bucket_metrics_configurations(&mut s3_req).await?;
         }
-        let result = s3.list_bucket_metrics_configurations(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .list_bucket_metrics_configurations(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4081,18 +4371,20 @@ impl super::Operation for ListBuckets {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_buckets(&mut s3_req).await?;
         }
-        let result = s3.list_buckets(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_buckets(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4127,18 +4419,20 @@ impl super::Operation for ListDirectoryBuckets {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_directory_buckets(&mut s3_req).await?;
         }
-        let result = s3.list_directory_buckets(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_directory_buckets(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4195,18 +4489,20 @@ impl super::Operation for ListMultipartUploads {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_multipart_uploads(&mut s3_req).await?;
         }
-        let result = s3.list_multipart_uploads(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_multipart_uploads(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4267,18 +4563,20 @@ impl super::Operation for ListObjectVersions {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_object_versions(&mut s3_req).await?;
         }
-        let result = s3.list_object_versions(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_object_versions(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4336,18 +4634,20 @@ impl super::Operation for ListObjects {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_objects(&mut s3_req).await?;
         }
-        let result = s3.list_objects(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_objects(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4411,18 +4711,20 @@ impl super::Operation for ListObjectsV2 {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_objects_v2(&mut s3_req).await?;
         }
-        let result = s3.list_objects_v2(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_objects_v2(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4484,18 +4786,20 @@ impl super::Operation for ListParts {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_parts(&mut s3_req).await?;
         }
-        let result = s3.list_parts(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.list_parts(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4534,18 +4838,23 @@ impl super::Operation for PutBucketAccelerateConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_accelerate_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_accelerate_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_accelerate_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4605,18 +4914,20 @@ impl super::Operation for PutBucketAcl {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_acl(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_acl(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_acl(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4655,18 +4966,23 @@ impl super::Operation for PutBucketAnalyticsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_analytics_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_analytics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_analytics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4708,18 +5024,20 @@ impl super::Operation for PutBucketCors {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_cors(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_cors(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_cors(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4761,18 +5079,20 @@ impl super::Operation for PutBucketEncryption {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_encryption(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_encryption(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_encryption(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4808,18 +5128,23 @@ impl super::Operation for PutBucketIntelligentTieringConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_intelligent_tiering_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_intelligent_tiering_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_intelligent_tiering_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4858,18 +5183,23 @@ impl super::Operation for PutBucketInventoryConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_inventory_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_inventory_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_inventory_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4918,18 +5248,23 @@ impl super::Operation for PutBucketLifecycleConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_lifecycle_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_lifecycle_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_lifecycle_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -4971,18 +5306,20 @@ impl super::Operation for PutBucketLogging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_logging(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_logging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_logging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5021,18 +5358,23 @@ impl super::Operation for PutBucketMetricsConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_metrics_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_metrics_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_metrics_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5072,18 +5414,23 @@ impl super::Operation for PutBucketNotificationConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_notification_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_notification_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_notification_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5122,18 +5469,23 @@ impl super::Operation for PutBucketOwnershipControls {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_ownership_controls(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_ownership_controls(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_bucket_ownership_controls(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5179,18 +5531,20 @@ impl super::Operation for PutBucketPolicy {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_policy(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_policy(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_policy(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5235,18 +5589,20 @@ impl super::Operation for PutBucketReplication {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_replication(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_replication(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_replication(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5288,18 +5644,20 @@ impl super::Operation for PutBucketRequestPayment {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_request_payment(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_request_payment(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_request_payment(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5341,18 +5699,20 @@ impl super::Operation for PutBucketTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_tagging(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5398,18 +5758,20 @@ impl super::Operation for PutBucketVersioning {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_versioning(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_versioning(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_versioning(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5451,18 +5813,20 @@ impl super::Operation for PutBucketWebsite {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_bucket_website(&mut s3_req).await?;
         }
-        let result = s3.put_bucket_website(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_bucket_website(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5474,7 +5838,7 @@ pub struct PutObject;
 impl PutObject {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutObjectInput> {
         if let Some(m) = req.s3ext.multipart.take() {
-            return Self::deserialize_http_multipart(req, m);
+            return Self::deserialize_http_multipart(req, *m);
         }
 
         let (bucket, key) = http::unwrap_object(req);
@@ -5833,18 +6197,20 @@ impl super::Operation for PutObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object(&mut s3_req).await?;
         }
-        let result = s3.put_object(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5913,18 +6279,20 @@ impl super::Operation for PutObjectAcl {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_acl(&mut s3_req).await?;
         }
-        let result = s3.put_object_acl(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_object_acl(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -5981,18 +6349,20 @@ impl super::Operation for PutObjectLegalHold {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_legal_hold(&mut s3_req).await?;
         }
-        let result = s3.put_object_legal_hold(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_object_legal_hold(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6043,18 +6413,23 @@ impl super::Operation for PutObjectLockConfiguration {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_lock_configuration(&mut s3_req).await?;
         }
-        let result = s3.put_object_lock_configuration(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3
+            .put_object_lock_configuration(s3_req)
+            .instrument(debug_span!("handler"))
+            .await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6115,18 +6490,20 @@ impl super::Operation for PutObjectRetention {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_retention(&mut s3_req).await?;
         }
-        let result = s3.put_object_retention(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_object_retention(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6177,18 +6554,20 @@ impl super::Operation for PutObjectTagging {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_tagging(&mut s3_req).await?;
         }
-        let result = s3.put_object_tagging(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_object_tagging(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6230,18 +6609,20 @@ impl super::Operation for PutPublicAccessBlock {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_public_access_block(&mut s3_req).await?;
         }
-        let result = s3.put_public_access_block(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.put_public_access_block(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6290,18 +6671,20 @@ impl super::Operation for RestoreObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.restore_object(&mut s3_req).await?;
         }
-        let result = s3.restore_object(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.restore_object(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6353,18 +6736,20 @@ impl super::Operation for SelectObjectContent {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.select_object_content(&mut s3_req).await?;
         }
-        let result = s3.select_object_content(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.select_object_content(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6478,18 +6863,20 @@ impl super::Operation for UploadPart {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.upload_part(&mut s3_req).await?;
         }
-        let result = s3.upload_part(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.upload_part(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6590,18 +6977,20 @@ impl super::Operation for UploadPartCopy {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.upload_part_copy(&mut s3_req).await?;
         }
-        let result = s3.upload_part_copy(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.upload_part_copy(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6781,18 +7170,20 @@ impl super::Operation for WriteGetObjectResponse {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.write_get_object_response(&mut s3_req).await?;
         }
-        let result = s3.write_get_object_response(s3_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut s3_req).await?;
+        let mut result = s3.write_get_object_response(s3_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
-        let mut resp = Self::serialize_http(s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| Self::serialize_http(s3_resp.output))?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
@@ -6803,7 +7194,7 @@ pub struct PostObject;
 
 impl PostObject {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PostObjectInput> {
-        let Some(m) = req.s3ext.multipart.take() else {
+        let Some(m) = req.s3ext.multipart.take().map(|m| *m) else {
             return Err(invalid_request!("missing multipart form"));
         };
 
@@ -6815,7 +7206,7 @@ impl PostObject {
         let success_action_status: Option<i32> = http::parse_field_value(&m, "success_action_status")?;
 
         // Get the validated POST policy from request extensions
-        let policy = req.s3ext.post_policy.take();
+        let policy = req.s3ext.post_policy.take().map(|p| *p);
 
         let put_input = PutObject::deserialize_http_multipart(req, m)?;
         let mut post_input = put_object_input_into_post_object_input(put_input);
@@ -6890,7 +7281,7 @@ impl super::Operation for PostObject {
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let post_input = Self::deserialize_http(req)?;
+        let post_input = debug_span!("deserialize").in_scope(|| Self::deserialize_http(req))?;
         // Save POST-specific fields before conversion
         let success_action_redirect = post_input.success_action_redirect.clone();
         let success_action_status = post_input.success_action_status;
@@ -6912,20 +7303,518 @@ impl super::Operation for PostObject {
             // New hook for POST object (optional).
             access.post_object(&mut post_req).await?;
         }
-        let result = s3.post_object(post_req).await;
+        let intercepted = crate::intercept::before(ccx.interceptors, self.name(), &mut post_req).await?;
+        let mut result = s3.post_object(post_req).instrument(debug_span!("handler")).await;
+        crate::intercept::after(intercepted, &mut result).await;
         let s3_resp = match result {
             Ok(val) => val,
-            Err(err) => return super::serialize_error(err, false),
+            Err(err) => return super::serialize_request_error(err, req),
         };
         // Serialize with POST-specific response behavior
-        let mut resp =
-            Self::serialize_http(&bucket, &key, success_action_redirect.as_deref(), success_action_status, &s3_resp.output)?;
+        let mut resp = debug_span!("serialize").in_scope(|| {
+            Self::serialize_http(&bucket, &key, success_action_redirect.as_deref(), success_action_status, &s3_resp.output)
+        })?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
     }
 }
 
+impl crate::intercept::OperationInput for AbortMultipartUploadInput {
+    type Output = AbortMultipartUploadOutput;
+    const NAME: &'static str = "AbortMultipartUpload";
+}
+
+impl crate::intercept::OperationInput for CompleteMultipartUploadInput {
+    type Output = CompleteMultipartUploadOutput;
+    const NAME: &'static str = "CompleteMultipartUpload";
+}
+
+impl crate::intercept::OperationInput for CopyObjectInput {
+    type Output = CopyObjectOutput;
+    const NAME: &'static str = "CopyObject";
+}
+
+impl crate::intercept::OperationInput for CreateBucketInput {
+    type Output = CreateBucketOutput;
+    const NAME: &'static str = "CreateBucket";
+}
+
+impl crate::intercept::OperationInput for CreateBucketMetadataTableConfigurationInput {
+    type Output = CreateBucketMetadataTableConfigurationOutput;
+    const NAME: &'static str = "CreateBucketMetadataTableConfiguration";
+}
+
+impl crate::intercept::OperationInput for CreateMultipartUploadInput {
+    type Output = CreateMultipartUploadOutput;
+    const NAME: &'static str = "CreateMultipartUpload";
+}
+
+impl crate::intercept::OperationInput for CreateSessionInput {
+    type Output = CreateSessionOutput;
+    const NAME: &'static str = "CreateSession";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketInput {
+    type Output = DeleteBucketOutput;
+    const NAME: &'static str = "DeleteBucket";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketAnalyticsConfigurationInput {
+    type Output = DeleteBucketAnalyticsConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketAnalyticsConfiguration";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketCorsInput {
+    type Output = DeleteBucketCorsOutput;
+    const NAME: &'static str = "DeleteBucketCors";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketEncryptionInput {
+    type Output = DeleteBucketEncryptionOutput;
+    const NAME: &'static str = "DeleteBucketEncryption";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketIntelligentTieringConfigurationInput {
+    type Output = DeleteBucketIntelligentTieringConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketIntelligentTieringConfiguration";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketInventoryConfigurationInput {
+    type Output = DeleteBucketInventoryConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketInventoryConfiguration";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketLifecycleInput {
+    type Output = DeleteBucketLifecycleOutput;
+    const NAME: &'static str = "DeleteBucketLifecycle";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketMetadataTableConfigurationInput {
+    type Output = DeleteBucketMetadataTableConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketMetadataTableConfiguration";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketMetricsConfigurationInput {
+    type Output = DeleteBucketMetricsConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketMetricsConfiguration";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketOwnershipControlsInput {
+    type Output = DeleteBucketOwnershipControlsOutput;
+    const NAME: &'static str = "DeleteBucketOwnershipControls";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketPolicyInput {
+    type Output = DeleteBucketPolicyOutput;
+    const NAME: &'static str = "DeleteBucketPolicy";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketReplicationInput {
+    type Output = DeleteBucketReplicationOutput;
+    const NAME: &'static str = "DeleteBucketReplication";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketTaggingInput {
+    type Output = DeleteBucketTaggingOutput;
+    const NAME: &'static str = "DeleteBucketTagging";
+}
+
+impl crate::intercept::OperationInput for DeleteBucketWebsiteInput {
+    type Output = DeleteBucketWebsiteOutput;
+    const NAME: &'static str = "DeleteBucketWebsite";
+}
+
+impl crate::intercept::OperationInput for DeleteObjectInput {
+    type Output = DeleteObjectOutput;
+    const NAME: &'static str = "DeleteObject";
+}
+
+impl crate::intercept::OperationInput for DeleteObjectTaggingInput {
+    type Output = DeleteObjectTaggingOutput;
+    const NAME: &'static str = "DeleteObjectTagging";
+}
+
+impl crate::intercept::OperationInput for DeleteObjectsInput {
+    type Output = DeleteObjectsOutput;
+    const NAME: &'static str = "DeleteObjects";
+}
+
+impl crate::intercept::OperationInput for DeletePublicAccessBlockInput {
+    type Output = DeletePublicAccessBlockOutput;
+    const NAME: &'static str = "DeletePublicAccessBlock";
+}
+
+impl crate::intercept::OperationInput for GetBucketAccelerateConfigurationInput {
+    type Output = GetBucketAccelerateConfigurationOutput;
+    const NAME: &'static str = "GetBucketAccelerateConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketAclInput {
+    type Output = GetBucketAclOutput;
+    const NAME: &'static str = "GetBucketAcl";
+}
+
+impl crate::intercept::OperationInput for GetBucketAnalyticsConfigurationInput {
+    type Output = GetBucketAnalyticsConfigurationOutput;
+    const NAME: &'static str = "GetBucketAnalyticsConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketCorsInput {
+    type Output = GetBucketCorsOutput;
+    const NAME: &'static str = "GetBucketCors";
+}
+
+impl crate::intercept::OperationInput for GetBucketEncryptionInput {
+    type Output = GetBucketEncryptionOutput;
+    const NAME: &'static str = "GetBucketEncryption";
+}
+
+impl crate::intercept::OperationInput for GetBucketIntelligentTieringConfigurationInput {
+    type Output = GetBucketIntelligentTieringConfigurationOutput;
+    const NAME: &'static str = "GetBucketIntelligentTieringConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketInventoryConfigurationInput {
+    type Output = GetBucketInventoryConfigurationOutput;
+    const NAME: &'static str = "GetBucketInventoryConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketLifecycleConfigurationInput {
+    type Output = GetBucketLifecycleConfigurationOutput;
+    const NAME: &'static str = "GetBucketLifecycleConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketLocationInput {
+    type Output = GetBucketLocationOutput;
+    const NAME: &'static str = "GetBucketLocation";
+}
+
+impl crate::intercept::OperationInput for GetBucketLoggingInput {
+    type Output = GetBucketLoggingOutput;
+    const NAME: &'static str = "GetBucketLogging";
+}
+
+impl crate::intercept::OperationInput for GetBucketMetadataTableConfigurationInput {
+    type Output = GetBucketMetadataTableConfigurationOutput;
+    const NAME: &'static str = "GetBucketMetadataTableConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketMetricsConfigurationInput {
+    type Output = GetBucketMetricsConfigurationOutput;
+    const NAME: &'static str = "GetBucketMetricsConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketNotificationConfigurationInput {
+    type Output = GetBucketNotificationConfigurationOutput;
+    const NAME: &'static str = "GetBucketNotificationConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetBucketOwnershipControlsInput {
+    type Output = GetBucketOwnershipControlsOutput;
+    const NAME: &'static str = "GetBucketOwnershipControls";
+}
+
+impl crate::intercept::OperationInput for GetBucketPolicyInput {
+    type Output = GetBucketPolicyOutput;
+    const NAME: &'static str = "GetBucketPolicy";
+}
+
+impl crate::intercept::OperationInput for GetBucketPolicyStatusInput {
+    type Output = GetBucketPolicyStatusOutput;
+    const NAME: &'static str = "GetBucketPolicyStatus";
+}
+
+impl crate::intercept::OperationInput for GetBucketReplicationInput {
+    type Output = GetBucketReplicationOutput;
+    const NAME: &'static str = "GetBucketReplication";
+}
+
+impl crate::intercept::OperationInput for GetBucketRequestPaymentInput {
+    type Output = GetBucketRequestPaymentOutput;
+    const NAME: &'static str = "GetBucketRequestPayment";
+}
+
+impl crate::intercept::OperationInput for GetBucketTaggingInput {
+    type Output = GetBucketTaggingOutput;
+    const NAME: &'static str = "GetBucketTagging";
+}
+
+impl crate::intercept::OperationInput for GetBucketVersioningInput {
+    type Output = GetBucketVersioningOutput;
+    const NAME: &'static str = "GetBucketVersioning";
+}
+
+impl crate::intercept::OperationInput for GetBucketWebsiteInput {
+    type Output = GetBucketWebsiteOutput;
+    const NAME: &'static str = "GetBucketWebsite";
+}
+
+impl crate::intercept::OperationInput for GetObjectInput {
+    type Output = GetObjectOutput;
+    const NAME: &'static str = "GetObject";
+}
+
+impl crate::intercept::OperationInput for GetObjectAclInput {
+    type Output = GetObjectAclOutput;
+    const NAME: &'static str = "GetObjectAcl";
+}
+
+impl crate::intercept::OperationInput for GetObjectAttributesInput {
+    type Output = GetObjectAttributesOutput;
+    const NAME: &'static str = "GetObjectAttributes";
+}
+
+impl crate::intercept::OperationInput for GetObjectLegalHoldInput {
+    type Output = GetObjectLegalHoldOutput;
+    const NAME: &'static str = "GetObjectLegalHold";
+}
+
+impl crate::intercept::OperationInput for GetObjectLockConfigurationInput {
+    type Output = GetObjectLockConfigurationOutput;
+    const NAME: &'static str = "GetObjectLockConfiguration";
+}
+
+impl crate::intercept::OperationInput for GetObjectRetentionInput {
+    type Output = GetObjectRetentionOutput;
+    const NAME: &'static str = "GetObjectRetention";
+}
+
+impl crate::intercept::OperationInput for GetObjectTaggingInput {
+    type Output = GetObjectTaggingOutput;
+    const NAME: &'static str = "GetObjectTagging";
+}
+
+impl crate::intercept::OperationInput for GetObjectTorrentInput {
+    type Output = GetObjectTorrentOutput;
+    const NAME: &'static str = "GetObjectTorrent";
+}
+
+impl crate::intercept::OperationInput for GetPublicAccessBlockInput {
+    type Output = GetPublicAccessBlockOutput;
+    const NAME: &'static str = "GetPublicAccessBlock";
+}
+
+impl crate::intercept::OperationInput for HeadBucketInput {
+    type Output = HeadBucketOutput;
+    const NAME: &'static str = "HeadBucket";
+}
+
+impl crate::intercept::OperationInput for HeadObjectInput {
+    type Output = HeadObjectOutput;
+    const NAME: &'static str = "HeadObject";
+}
+
+impl crate::intercept::OperationInput for ListBucketAnalyticsConfigurationsInput {
+    type Output = ListBucketAnalyticsConfigurationsOutput;
+    const NAME: &'static str = "ListBucketAnalyticsConfigurations";
+}
+
+impl crate::intercept::OperationInput for ListBucketIntelligentTieringConfigurationsInput {
+    type Output = ListBucketIntelligentTieringConfigurationsOutput;
+    const NAME: &'static str = "ListBucketIntelligentTieringConfigurations";
+}
+
+impl crate::intercept::OperationInput for ListBucketInventoryConfigurationsInput {
+    type Output = ListBucketInventoryConfigurationsOutput;
+    const NAME: &'static str = "ListBucketInventoryConfigurations";
+}
+
+impl crate::intercept::OperationInput for ListBucketMetricsConfigurationsInput {
+    type Output = ListBucketMetricsConfigurationsOutput;
+    const NAME: &'static str = "ListBucketMetricsConfigurations";
+}
+
+impl crate::intercept::OperationInput for ListBucketsInput {
+    type Output = ListBucketsOutput;
+    const NAME: &'static str = "ListBuckets";
+}
+
+impl crate::intercept::OperationInput for ListDirectoryBucketsInput {
+    type Output = ListDirectoryBucketsOutput;
+    const NAME: &'static str = "ListDirectoryBuckets";
+}
+
+impl crate::intercept::OperationInput for ListMultipartUploadsInput {
+    type Output = ListMultipartUploadsOutput;
+    const NAME: &'static str = "ListMultipartUploads";
+}
+
+impl crate::intercept::OperationInput for ListObjectVersionsInput {
+    type Output = ListObjectVersionsOutput;
+    const NAME: &'static str = "ListObjectVersions";
+}
+
+impl crate::intercept::OperationInput for ListObjectsInput {
+    type Output = ListObjectsOutput;
+    const NAME: &'static str = "ListObjects";
+}
+
+impl crate::intercept::OperationInput for ListObjectsV2Input {
+    type Output = ListObjectsV2Output;
+    const NAME: &'static str = "ListObjectsV2";
+}
+
+impl crate::intercept::OperationInput for ListPartsInput {
+    type Output = ListPartsOutput;
+    const NAME: &'static str = "ListParts";
+}
+
+impl crate::intercept::OperationInput for PostObjectInput {
+    type Output = PostObjectOutput;
+    const NAME: &'static str = "PostObject";
+}
+
+impl crate::intercept::OperationInput for PutBucketAccelerateConfigurationInput {
+    type Output = PutBucketAccelerateConfigurationOutput;
+    const NAME: &'static str = "PutBucketAccelerateConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketAclInput {
+    type Output = PutBucketAclOutput;
+    const NAME: &'static str = "PutBucketAcl";
+}
+
+impl crate::intercept::OperationInput for PutBucketAnalyticsConfigurationInput {
+    type Output = PutBucketAnalyticsConfigurationOutput;
+    const NAME: &'static str = "PutBucketAnalyticsConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketCorsInput {
+    type Output = PutBucketCorsOutput;
+    const NAME: &'static str = "PutBucketCors";
+}
+
+impl crate::intercept::OperationInput for PutBucketEncryptionInput {
+    type Output = PutBucketEncryptionOutput;
+    const NAME: &'static str = "PutBucketEncryption";
+}
+
+impl crate::intercept::OperationInput for PutBucketIntelligentTieringConfigurationInput {
+    type Output = PutBucketIntelligentTieringConfigurationOutput;
+    const NAME: &'static str = "PutBucketIntelligentTieringConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketInventoryConfigurationInput {
+    type Output = PutBucketInventoryConfigurationOutput;
+    const NAME: &'static str = "PutBucketInventoryConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketLifecycleConfigurationInput {
+    type Output = PutBucketLifecycleConfigurationOutput;
+    const NAME: &'static str = "PutBucketLifecycleConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketLoggingInput {
+    type Output = PutBucketLoggingOutput;
+    const NAME: &'static str = "PutBucketLogging";
+}
+
+impl crate::intercept::OperationInput for PutBucketMetricsConfigurationInput {
+    type Output = PutBucketMetricsConfigurationOutput;
+    const NAME: &'static str = "PutBucketMetricsConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketNotificationConfigurationInput {
+    type Output = PutBucketNotificationConfigurationOutput;
+    const NAME: &'static str = "PutBucketNotificationConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutBucketOwnershipControlsInput {
+    type Output = PutBucketOwnershipControlsOutput;
+    const NAME: &'static str = "PutBucketOwnershipControls";
+}
+
+impl crate::intercept::OperationInput for PutBucketPolicyInput {
+    type Output = PutBucketPolicyOutput;
+    const NAME: &'static str = "PutBucketPolicy";
+}
+
+impl crate::intercept::OperationInput for PutBucketReplicationInput {
+    type Output = PutBucketReplicationOutput;
+    const NAME: &'static str = "PutBucketReplication";
+}
+
+impl crate::intercept::OperationInput for PutBucketRequestPaymentInput {
+    type Output = PutBucketRequestPaymentOutput;
+    const NAME: &'static str = "PutBucketRequestPayment";
+}
+
+impl crate::intercept::OperationInput for PutBucketTaggingInput {
+    type Output = PutBucketTaggingOutput;
+    const NAME: &'static str = "PutBucketTagging";
+}
+
+impl crate::intercept::OperationInput for PutBucketVersioningInput {
+    type Output = PutBucketVersioningOutput;
+    const NAME: &'static str = "PutBucketVersioning";
+}
+
+impl crate::intercept::OperationInput for PutBucketWebsiteInput {
+    type Output = PutBucketWebsiteOutput;
+    const NAME: &'static str = "PutBucketWebsite";
+}
+
+impl crate::intercept::OperationInput for PutObjectInput {
+    type Output = PutObjectOutput;
+    const NAME: &'static str = "PutObject";
+}
+
+impl crate::intercept::OperationInput for PutObjectAclInput {
+    type Output = PutObjectAclOutput;
+    const NAME: &'static str = "PutObjectAcl";
+}
+
+impl crate::intercept::OperationInput for PutObjectLegalHoldInput {
+    type Output = PutObjectLegalHoldOutput;
+    const NAME: &'static str = "PutObjectLegalHold";
+}
+
+impl crate::intercept::OperationInput for PutObjectLockConfigurationInput {
+    type Output = PutObjectLockConfigurationOutput;
+    const NAME: &'static str = "PutObjectLockConfiguration";
+}
+
+impl crate::intercept::OperationInput for PutObjectRetentionInput {
+    type Output = PutObjectRetentionOutput;
+    const NAME: &'static str = "PutObjectRetention";
+}
+
+impl crate::intercept::OperationInput for PutObjectTaggingInput {
+    type Output = PutObjectTaggingOutput;
+    const NAME: &'static str = "PutObjectTagging";
+}
+
+impl crate::intercept::OperationInput for PutPublicAccessBlockInput {
+    type Output = PutPublicAccessBlockOutput;
+    const NAME: &'static str = "PutPublicAccessBlock";
+}
+
+impl crate::intercept::OperationInput for RestoreObjectInput {
+    type Output = RestoreObjectOutput;
+    const NAME: &'static str = "RestoreObject";
+}
+
+impl crate::intercept::OperationInput for SelectObjectContentInput {
+    type Output = SelectObjectContentOutput;
+    const NAME: &'static str = "SelectObjectContent";
+}
+
+impl crate::intercept::OperationInput for UploadPartInput {
+    type Output = UploadPartOutput;
+    const NAME: &'static str = "UploadPart";
+}
+
+impl crate::intercept::OperationInput for UploadPartCopyInput {
+    type Output = UploadPartCopyOutput;
+    const NAME: &'static str = "UploadPartCopy";
+}
+
+impl crate::intercept::OperationInput for WriteGetObjectResponseInput {
+    type Output = WriteGetObjectResponseOutput;
+    const NAME: &'static str = "WriteGetObjectResponse";
+}
+
 pub fn resolve_route(
     req: &http::Request,
     s3_path: &S3Path,
@@ -27,27 +27,33 @@ mod multipart;
 #[cfg(test)]
 mod tests;
 
-use crate::access::{S3Access, S3AccessContext};
+use crate::access::{AnonymousAccess, S3Access, S3AccessContext};
 use crate::auth::{Credentials, S3Auth};
-use crate::config::S3ConfigProvider;
+use crate::config::{OperationClass, S3Config, S3ConfigProvider};
+use crate::cors::{CorsRequest, S3Cors};
 use crate::error::*;
 use crate::header;
 use crate::host::S3Host;
 use crate::http::Body;
 use crate::http::{self, BodySizeLimitExceeded};
 use crate::http::{OrderedHeaders, OrderedQs};
-use crate::http::{Request, Response};
+use crate::http::{Request, Response, ResponseErrorCode};
+use crate::intercept::S3Interceptor;
+use crate::limit::ConcurrencyLimiter;
 use crate::path::{ParseS3PathError, S3Path};
 use crate::post_policy::PostPolicy;
 use crate::protocol::S3Request;
 use crate::route::S3Route;
 use crate::s3_trait::S3;
+use crate::sig_v4::SigningKeyCache;
+use crate::throttle::{S3Throttle, S3ThrottleContext};
 use crate::validation::{AwsNameValidation, NameValidation};
 
 use std::mem;
 use std::net::{IpAddr, SocketAddr};
 use std::ops::Not;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use bytes::Bytes;
 use hyper::HeaderMap;
@@ -55,7 +61,8 @@ use hyper::Method;
 use hyper::StatusCode;
 use hyper::Uri;
 use mime::Mime;
-use tracing::{debug, error, warn};
+use tracing::field::display;
+use tracing::{Instrument, Span, debug, debug_span, error, warn};
 
 #[async_trait::async_trait]
 pub trait Operation: Send + Sync + 'static {
@@ -70,8 +77,36 @@ pub struct CallContext<'a> {
     pub host: Option<&'a dyn S3Host>,
     pub auth: Option<&'a dyn S3Auth>,
     pub access: Option<&'a dyn S3Access>,
+    pub anonymous: Option<&'a dyn AnonymousAccess>,
     pub route: Option<&'a dyn S3Route>,
     pub validation: Option<&'a dyn NameValidation>,
+    pub throttle: Option<&'a dyn S3Throttle>,
+    pub limiter: Option<&'a ConcurrencyLimiter>,
+    pub cors: Option<&'a dyn S3Cors>,
+    pub interceptors: &'a [Box<dyn S3Interceptor>],
+    pub signing_keys: Option<&'a SigningKeyCache>,
+}
+
+#[cfg(test)]
+impl<'a> CallContext<'a> {
+    /// Creates a context without any optional hooks.
+    pub fn new(s3: &'a Arc<dyn S3>, config: &'a Arc<dyn S3ConfigProvider>) -> Self {
+        Self {
+            s3,
+            config,
+            host: None,
+            auth: None,
+            access: None,
+            anonymous: None,
+            route: None,
+            validation: None,
+            throttle: None,
+            limiter: None,
+            cors: None,
+            interceptors: &[],
+            signing_keys: None,
+        }
+    }
 }
 
 fn build_s3_request<T>(input: T, req: &mut Request) -> S3Request<T> {
@@ -108,10 +143,71 @@ pub(crate) fn serialize_error(mut e: S3Error, no_decl: bool) -> S3Result<Respons
     if let Some(headers) = e.take_headers() {
         res.headers = headers;
     }
+    res.extensions.insert(ResponseErrorCode(e.code().clone()));
     drop(e);
     Ok(res)
 }
 
+/// Records the bucket and key on the span of `S3Service::call`.
+fn record_span_path(s3_path: &S3Path) {
+    let span = Span::current();
+    if let Some(bucket) = s3_path.get_bucket_name() {
+        span.record("aws.s3.bucket", bucket);
+    }
+    if let Some(key) = s3_path.get_object_key() {
+        span.record("aws.s3.key", key);
+    }
+}
+
+/// Serializes an error of the request, filling in the request ID.
+///
+/// If the body idle timeout has expired, the error is replaced by `RequestTimeout`,
+/// since the body reader may have reported it as any error.
+fn serialize_request_error(mut e: S3Error, req: &Request) -> S3Result<Response> {
+    if let Some(timed_out) = &req.s3ext.body_timed_out
+        && timed_out.load(Ordering::Relaxed)
+        && e.code() != &S3ErrorCode::RequestTimeout
+    {
+        e = S3Error::with_message(S3ErrorCode::RequestTimeout, BODY_IDLE_TIMEOUT_MESSAGE);
+    }
+    if e.request_id().is_none()
+        && let Some(request_id) = &req.s3ext.request_id
+    {
+        e.set_request_id(request_id.id());
+    }
+    serialize_error(e, false)
+}
+
+const BODY_IDLE_TIMEOUT_MESSAGE: &str = "\
+Your socket connection to the server was not read from or written to within the timeout period. \
+Idle connections will be closed.";
+
+const REQUEST_TIMEOUT_MESSAGE: &str = "The request was not completed within the timeout period.";
+
+/// Sets the idle timeout of the request body.
+fn set_body_idle_timeout(req: &mut Request, config: &S3Config) {
+    if let Some(timeout) = config.body_idle_timeout() {
+        let timed_out = Arc::new(AtomicBool::new(false));
+        req.body = mem::take(&mut req.body).with_idle_timeout(timeout, Arc::clone(&timed_out));
+        req.s3ext.body_timed_out = Some(timed_out);
+    }
+}
+
+/// Calls the operation, cancelling it if the request timeout of its class expires.
+async fn call_with_timeout(op: &'static dyn Operation, ccx: &CallContext<'_>, req: &mut Request) -> S3Result<Response> {
+    let timeout = ccx.config.snapshot().request_timeout(OperationClass::of(op.name()));
+    let Some(timeout) = timeout else { return op.call(ccx, req).await };
+
+    // Boxed to keep the future of `call` small
+    match Box::pin(tokio::time::timeout(timeout, op.call(ccx, req))).await {
+        Ok(result) => result,
+        Err(_) => {
+            warn!(op = %op.name(), ?timeout, "operation timed out");
+            Err(S3Error::with_message(S3ErrorCode::RequestTimeout, REQUEST_TIMEOUT_MESSAGE))
+        }
+    }
+}
+
 const VIRTUAL_HOSTED_STYLE_HINT: &str = "\
 The request appears to use virtual-hosted-style addressing \
 (e.g., Host: bucket.domain) which may not be supported by this endpoint. \
@@ -250,6 +346,15 @@ async fn extract_full_body(content_length: Option<u64>, body: &mut Body, max_bod
     Ok(bytes)
 }
 
+fn is_multipart_form(req: &Request) -> bool {
+    if req.method != Method::POST {
+        return false;
+    }
+    let content_type = req.headers.get(crate::header::CONTENT_TYPE).and_then(|val| val.to_str().ok());
+    let mime = content_type.and_then(|s| s.parse::<Mime>().ok());
+    mime.is_some_and(|mime| mime.type_() == mime::MULTIPART && mime.subtype() == mime::FORM_DATA)
+}
+
 /// Prepares the POST object file stream for the operation.
 ///
 /// The file part is the last part of a POST object form. When the request
@@ -311,22 +416,23 @@ pub async fn call(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Response
         Ok(op) => op,
         Err(err) => {
             error!(?err, "failed to prepare");
-            return serialize_error(err, false);
+            return serialize_request_error(err, req);
         }
     };
 
     match prep {
         Prepare::S3(op) => {
-            match op.call(ccx, req).await {
+            match call_with_timeout(op, ccx, req).await {
                 Ok(resp) => {
                     Ok(resp) //
                 }
                 Err(err) => {
                     error!(op = %op.name(), ?err, "op returns error");
-                    serialize_error(err, false)
+                    serialize_request_error(err, req)
                 }
             }
         }
+        Prepare::CorsPreflight => Ok(Response::with_status(StatusCode::OK)),
         Prepare::CustomRoute => {
             let body = mem::take(&mut req.body);
             let mut s3_req = build_s3_request(body, req);
@@ -347,7 +453,7 @@ pub async fn call(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Response
                 }),
                 Err(err) => {
                     error!(?err, "custom route returns error");
-                    serialize_error(err, false)
+                    serialize_request_error(err, req)
                 }
             }
         }
@@ -356,12 +462,17 @@ pub async fn call(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Response
 
 enum Prepare {
     S3(&'static dyn Operation),
+    CorsPreflight,
     CustomRoute,
 }
 
 #[allow(clippy::too_many_lines)]
-#[tracing::instrument(level = "debug", skip_all, err)]
 async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare> {
+    if let Some(limiter) = ccx.limiter {
+        let permit = limiter.acquire_request().await?;
+        req.s3ext.limits.get_or_insert_default().request = permit;
+    }
+
     let s3_path;
     let mut content_length;
     let host_header: Option<String>;
@@ -381,6 +492,8 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
             req.headers.insert(hyper::header::HOST, val);
         }
 
+        set_body_idle_timeout(req, &ccx.config.snapshot());
+
         let decoded_uri_path = urlencoding::decode(req.uri.path()).map_err(|_| S3ErrorCode::InvalidURI)?;
 
         host_header = extract_host(req)?;
@@ -388,6 +501,8 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
         let vh_bucket;
         let vh_region;
         {
+            let _span = debug_span!("parse_host").entered();
+
             let default_validation = &const { AwsNameValidation::new() };
             let validation = ccx.validation.unwrap_or(default_validation);
 
@@ -421,12 +536,42 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
             };
 
             req.s3ext.s3_path = Some(result.map_err(|err| convert_parse_s3_path_error(&err))?);
-            s3_path = req.s3ext.s3_path.as_ref().unwrap();
+        }
+        s3_path = req.s3ext.s3_path.as_ref().unwrap();
+
+        if let Some(log) = &mut req.s3ext.observed {
+            log.record_path(s3_path);
+        }
+        record_span_path(s3_path);
+
+        // Preflight requests are answered before authentication, unless the custom route handles them
+        if let Some(cors) = ccx.cors
+            && let Some(bucket) = s3_path.get_bucket_name()
+            && !(req.method == Method::OPTIONS
+                && ccx
+                    .route
+                    .is_some_and(|route| route.is_match(&req.method, &req.uri, &req.headers, &mut req.extensions)))
+            && let Some(cors_req) = CorsRequest::extract(&req.method, &req.headers)?
+        {
+            let preflight = cors_req.is_preflight();
+            let headers = Box::pin(crate::cors::resolve(cors, bucket, cors_req)).await?;
+            req.s3ext.cors = headers.map(Box::new);
+            if preflight {
+                return Ok(Prepare::CorsPreflight);
+            }
         }
 
         req.s3ext.qs = extract_qs(&req.uri)?;
         content_length = extract_content_length(req);
 
+        // The form fields of POST object are parsed into memory by the signature check
+        if let Some(limiter) = ccx.limiter
+            && is_multipart_form(req)
+        {
+            let permit = limiter.acquire_multipart_form().await?;
+            req.s3ext.limits.get_or_insert_default().multipart_form = permit;
+        }
+
         let hs = extract_headers(&req.headers);
         let mime = extract_mime(&hs);
         let decoded_content_length = extract_decoded_content_length(&hs)?;
@@ -437,6 +582,7 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
             let mut scx = SignatureContext {
                 auth: ccx.auth,
                 config: ccx.config,
+                signing_keys: ccx.signing_keys,
 
                 req_version: req.version,
                 req_method: &req.method,
@@ -459,12 +605,22 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
                 trailing_headers: None,
             };
 
-            let credentials = scx.check().await?;
+            let span = debug_span!("check_signature");
+            let credentials = match ccx.throttle {
+                None => scx.check().instrument(span).await?,
+                // Boxed to keep the future of `call` small
+                Some(throttle) => {
+                    let source_ip = req.extensions.get::<SocketAddr>().map(SocketAddr::ip);
+                    Box::pin(scx.check_with_throttle(throttle, source_ip))
+                        .instrument(span)
+                        .await?
+                }
+            };
 
             body_changed = scx.transformed_body.is_some() || scx.multipart.is_some();
             transformed_body = scx.transformed_body;
 
-            req.s3ext.multipart = scx.multipart;
+            req.s3ext.multipart = scx.multipart.map(Box::new);
             req.s3ext.trailing_headers = scx.trailing_headers;
 
             match credentials {
@@ -472,6 +628,7 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
                     req.s3ext.credentials = Some(Credentials {
                         access_key: cred.access_key,
                         secret_key: cred.secret_key,
+                        identity: cred.identity,
                     });
 
                     let cred_region = cred
@@ -506,6 +663,13 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
                 }
             }
 
+            if let Some(log) = &mut req.s3ext.observed
+                && let Some(cred) = &req.s3ext.credentials
+            {
+                let arn = cred.identity.as_ref().and_then(|id| id.arn.as_deref());
+                log.requester = Some(arn.unwrap_or(&cred.access_key).to_owned());
+            }
+
             // Fallback: if no region was determined from the signature credential
             // (anonymous requests, SigV2), use the region provided by S3Host.
             if req.s3ext.region.is_none() {
@@ -534,6 +698,18 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
         debug!(?body_changed, ?decoded_content_length, ?has_multipart);
     }
 
+    if let Some(throttle) = ccx.throttle {
+        let tcx = S3ThrottleContext {
+            access_key: req.s3ext.credentials.as_ref().map(|cred| cred.access_key.as_str()),
+            s3_path,
+            method: &req.method,
+            uri: &req.uri,
+            headers: &req.headers,
+            extensions: &req.extensions,
+        };
+        throttle.check(&tcx).await?;
+    }
+
     if let Some(route) = ccx.route
         && route.is_match(&req.method, &req.uri, &req.headers, &mut req.extensions)
     {
@@ -595,7 +771,7 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
                     // even when clients (like boto3) don't include it in form fields.
                     if let Some(policy) = policy {
                         policy.validate_conditions_only(multipart, file_size, Some(bucket))?;
-                        req.s3ext.post_policy = Some(policy);
+                        req.s3ext.post_policy = Some(Box::new(policy));
                     }
 
                     break 'resolve (&PostObject as &'static dyn Operation, false);
@@ -641,8 +817,15 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
     }
 
     debug!(op = %op.name(), ?s3_path, "resolved route");
+    if let Some(log) = &mut req.s3ext.observed {
+        log.op_name = Some(op.name());
+    }
+    let span = Span::current();
+    span.record("rpc.method", op.name());
+    span.record("otel.name", display(format_args!("S3/{}", op.name())));
 
-    if ccx.auth.is_some() {
+    let anonymous = ccx.anonymous.filter(|_| req.s3ext.credentials.is_none());
+    if anonymous.is_some() || ccx.auth.is_some() {
         let mut acx = S3AccessContext {
             credentials: req.s3ext.credentials.as_ref(),
             s3_path,
@@ -652,9 +835,18 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
             headers: &req.headers,
             extensions: &mut req.extensions,
         };
-        match ccx.access {
-            Some(access) => access.check(&mut acx).await?,
-            None => crate::access::default_check(&mut acx)?,
+        let span = debug_span!("check_access");
+        // A single await point keeps the future of `call` small
+        let check = match (anonymous, ccx.access) {
+            (Some(anonymous), _) => Some(anonymous.check(&mut acx)),
+            (None, Some(access)) => Some(access.check(&mut acx)),
+            (None, None) => {
+                span.in_scope(|| crate::access::default_check(&mut acx))?;
+                None
+            }
+        };
+        if let Some(check) = check {
+            check.instrument(span).await?;
         }
     }
 
@@ -662,6 +854,16 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
 
     if needs_full_body {
         let config = ccx.config.snapshot();
+        if let Some(limiter) = ccx.limiter
+            && req.body.bytes().is_none()
+        {
+            let max = config.xml_max_body_size;
+            let len = content_length
+                .and_then(|n| usize::try_from(n).ok())
+                .map_or(max, |n| n.min(max));
+            let permit = limiter.acquire_body_bytes(len).await?;
+            req.s3ext.limits.get_or_insert_default().body_bytes = permit;
+        }
         extract_full_body(content_length, &mut req.body, config.xml_max_body_size).await?;
     }
 
@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Request IDs
+//!
+//! [`S3Service`](crate::service::S3Service) assigns a [`RequestId`] to every request.
+//! The request ID is
+//!
+//! - inserted into the extensions of [`S3Request`](crate::S3Request)
+//!   (also available via [`S3AccessContext::request_id`](crate::access::S3AccessContext::request_id)),
+//! - returned in the `x-amz-request-id` and `x-amz-id-2` response headers,
+//! - written to the `RequestId` element of error responses,
+//! - recorded in the `aws.request_id` field of the [tracing span](crate::trace) of the request.
+//!
+//! Response headers and error request IDs that are already set by the S3 implementation are kept.
+//!
+//! The IDs are generated by [`DefaultRequestIdGenerator`] unless another
+//! [`RequestIdGenerator`] is configured via
+//! [`S3ServiceBuilder::set_request_id_generator`](crate::service::S3ServiceBuilder::set_request_id_generator).
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::request_id::RequestId;
+//!
+//! // Any function or closure returning a request ID is a generator.
+//! let generator = || RequestId::new("0123456789ABCDEF", "host-1");
+//! # let _ = generator;
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The IDs of a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId {
+    id: Arc<str>,
+    host_id: Arc<str>,
+}
+
+impl RequestId {
+    /// Creates a request ID from the `x-amz-request-id` and `x-amz-id-2` values.
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>, host_id: impl Into<Arc<str>>) -> Self {
+        Self {
+            id: id.into(),
+            host_id: host_id.into(),
+        }
+    }
+
+    /// Returns the request ID, which is returned in the `x-amz-request-id` header.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the extended request ID, which is returned in the `x-amz-id-2` header.
+    #[must_use]
+    pub fn host_id(&self) -> &str {
+        &self.host_id
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.id)
+    }
+}
+
+/// Request ID generator
+pub trait RequestIdGenerator: Send + Sync + 'static {
+    /// Generates the IDs of a new request.
+    fn generate(&self) -> RequestId;
+}
+
+impl<F> RequestIdGenerator for F
+where
+    F: Fn() -> RequestId + Send + Sync + 'static,
+{
+    fn generate(&self) -> RequestId {
+        self()
+    }
+}
+
+/// The default request ID generator.
+///
+/// The request IDs are 16 uppercase hex digits, like the request IDs of Amazon S3.
+/// They are unique within a generator.
+/// The extended request IDs are base64 strings that share a random prefix per generator.
+#[derive(Debug)]
+pub struct DefaultRequestIdGenerator {
+    seed: u64,
+    host: [u8; 24],
+    counter: AtomicU64,
+}
+
+impl Default for DefaultRequestIdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns random bytes, falling back to the current time if the OS RNG is unavailable.
+fn random_bytes<const N: usize>() -> [u8; N] {
+    let mut buf = [0; N];
+    if getrandom::fill(&mut buf).is_err() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        for (b, n) in buf.iter_mut().zip(nanos.to_le_bytes().into_iter().cycle()) {
+            *b = n;
+        }
+    }
+    buf
+}
+
+/// The finalizer of `SplitMix64`, which is a bijection.
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+impl DefaultRequestIdGenerator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            seed: u64::from_le_bytes(random_bytes()),
+            host: random_bytes(),
+            counter: AtomicU64::new(0),
+        }
+    }
+}
+
+impl RequestIdGenerator for DefaultRequestIdGenerator {
+    fn generate(&self) -> RequestId {
+        let n = self.counter.fetch_add(1, Ordering::Relaxed);
+        let x = mix(self.seed.wrapping_add(n));
+
+        let mut buf = [0; 32];
+        buf[..24].copy_from_slice(&self.host);
+        buf[24..].copy_from_slice(&x.to_be_bytes());
+
+        RequestId::new(format!("{x:016X}"), base64_simd::STANDARD.encode_to_string(buf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn default_generator() {
+        let generator = DefaultRequestIdGenerator::new();
+
+        let mut ids = HashSet::new();
+        for _ in 0..1000 {
+            let id = generator.generate();
+            assert_eq!(id.id().len(), 16);
+            assert!(id.id().bytes().all(|b| b.is_ascii_digit() || (b'A'..=b'F').contains(&b)));
+            assert_eq!(id.host_id().len(), 44);
+            assert!(ids.insert(id.id().to_owned()));
+        }
+
+        let a = generator.generate();
+        let b = generator.generate();
+        assert_eq!(a.host_id()[..32], b.host_id()[..32]);
+        assert_ne!(a.host_id(), b.host_id());
+    }
+
+    #[test]
+    fn closure_generator() {
+        let generator = || RequestId::new("REQ", "HOST");
+        let id = RequestIdGenerator::generate(&generator);
+        assert_eq!(id.id(), "REQ");
+        assert_eq!(id.host_id(), "HOST");
+        assert_eq!(id.to_string(), "REQ");
+    }
+}
@@ -24,6 +24,18 @@ impl S3Operation {
     pub fn name(&self) -> &str {
         self.name
     }
+
+    /// Returns the names of all operations, in alphabetical order.
+    ///
+    /// # Example
+    /// ```
+    /// use s3s::S3Operation;
+    /// assert!(S3Operation::names().contains(&"GetObject"));
+    /// ```
+    #[must_use]
+    pub fn names() -> &'static [&'static str] {
+        crate::ops::NAMES
+    }
 }
 
 #[cfg(test)]
@@ -35,4 +47,12 @@ mod tests {
         let op = S3Operation { name: "GetObject" };
         assert_eq!(op.name(), "GetObject");
     }
+
+    #[test]
+    fn operation_names() {
+        let names = S3Operation::names();
+        assert!(names.contains(&"ListBuckets"));
+        assert!(names.contains(&"PostObject"));
+        assert!(names.is_sorted());
+    }
 }
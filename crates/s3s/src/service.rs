@@ -15,9 +15,15 @@
 //! - **Configuration**: Service settings via [`S3ConfigProvider`]
 //! - **Authentication**: Optional request signing verification via [`S3Auth`]
 //! - **Authorization**: Optional access control via [`S3Access`]
+//! - **Anonymous Access**: Optional policy for unsigned requests via [`AnonymousAccess`]
 //! - **Host Parsing**: Optional virtual host handling via [`S3Host`]
 //! - **Custom Routes**: Optional route interception via [`S3Route`]
 //! - **Validation**: Optional bucket/object name validation via [`NameValidation`]
+//! - **Throttling**: Optional rate limiting and auth failure backoff via [`S3Throttle`]
+//! - **Interceptors**: Optional hooks around each S3 operation via [`S3Interceptor`]
+//! - **Access Logging**: Optional server access log records via [`S3AccessLog`]
+//! - **Metrics**: Optional request metrics via [`S3Metrics`]
+//! - **Request IDs**: Customizable request ID generation via [`RequestIdGenerator`]
 //!
 //! # Example
 //!
@@ -56,17 +62,33 @@
 //! - **Config**: [`StaticConfigProvider::default()`]
 //! - **Auth**: None (no authentication required)
 //! - **Access**: None (no custom access policy; when auth is enabled, uses the default access check that denies anonymous requests)
+//! - **Anonymous Access**: None (anonymous requests are checked by the access policy above)
 //! - **Host**: None (assumes path-style requests)
 //! - **Route**: None (no custom routes)
 //! - **Validation**: None (uses AWS-compatible validation)
-
-use crate::access::S3Access;
+//! - **Throttle**: None (no rate limiting)
+//! - **Interceptors**: None
+//! - **Access Log**: None (no access log records)
+//! - **Metrics**: None (no metrics recorded)
+//! - **Request ID Generator**: [`DefaultRequestIdGenerator`]
+
+use crate::access::{AnonymousAccess, S3Access};
+use crate::access_log::{PendingRecord, S3AccessLog};
 use crate::auth::S3Auth;
 use crate::config::{S3ConfigProvider, StaticConfigProvider};
+use crate::cors::{CorsFromS3, S3Cors};
+use crate::header;
 use crate::host::S3Host;
-use crate::http::{Body, Request};
+use crate::http::{Body, Request, ResponseErrorCode};
+use crate::intercept::{OperationInput, OperationInterceptor, S3Interceptor, Typed};
+use crate::limit::ConcurrencyLimiter;
+use crate::metrics::{InFlight, S3Metrics};
+use crate::request_id::{DefaultRequestIdGenerator, RequestId, RequestIdGenerator};
 use crate::route::S3Route;
 use crate::s3_trait::S3;
+use crate::sig_v4::SigningKeyCache;
+use crate::throttle::S3Throttle;
+use crate::trace::TraceParent;
 use crate::validation::NameValidation;
 use crate::{HttpError, HttpRequest, HttpResponse};
 
@@ -76,7 +98,10 @@ use std::sync::Arc;
 
 use bytes::Bytes;
 use futures::future::BoxFuture;
-use tracing::{debug, error};
+use hyper::HeaderMap;
+use hyper::header::{HeaderName, HeaderValue};
+use tracing::field::{Empty, display};
+use tracing::{Span, debug, error};
 
 /// Builder for [`S3Service`].
 ///
@@ -126,8 +151,17 @@ pub struct S3ServiceBuilder {
     host: Option<Box<dyn S3Host>>,
     auth: Option<Box<dyn S3Auth>>,
     access: Option<Box<dyn S3Access>>,
+    anonymous: Option<Box<dyn AnonymousAccess>>,
     route: Option<Box<dyn S3Route>>,
     validation: Option<Box<dyn NameValidation>>,
+    throttle: Option<Box<dyn S3Throttle>>,
+    limiter: Option<ConcurrencyLimiter>,
+    cors: Option<Box<dyn S3Cors>>,
+    interceptors: Vec<Box<dyn S3Interceptor>>,
+    access_log: Option<Arc<dyn S3AccessLog>>,
+    metrics: Option<Box<dyn S3Metrics>>,
+    request_id: Option<Box<dyn RequestIdGenerator>>,
+    signing_key_cache_capacity: Option<usize>,
 }
 
 impl S3ServiceBuilder {
@@ -163,8 +197,17 @@ impl S3ServiceBuilder {
             host: None,
             auth: None,
             access: None,
+            anonymous: None,
             route: None,
             validation: None,
+            throttle: None,
+            limiter: None,
+            cors: None,
+            interceptors: Vec::new(),
+            access_log: None,
+            metrics: None,
+            request_id: None,
+            signing_key_cache_capacity: None,
         }
     }
 
@@ -330,10 +373,51 @@ impl S3ServiceBuilder {
         self.access = Some(Box::new(access));
     }
 
+    /// Sets the access policy for anonymous requests.
+    ///
+    /// When set, every S3 operation request without credentials is checked by this
+    /// policy instead of the access control provider, whether or not authentication
+    /// is configured. Requests with credentials are not affected.
+    ///
+    /// This allows, for example, public reads on some buckets while requiring
+    /// signatures elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use s3s::service::S3ServiceBuilder;
+    /// use s3s::access::PublicReadAccess;
+    /// use s3s::auth::SimpleAuth;
+    /// use s3s::{S3, S3Request, S3Response, S3Result};
+    /// use s3s::dto::{GetObjectInput, GetObjectOutput};
+    ///
+    /// #[derive(Clone)]
+    /// struct MyS3;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl S3 for MyS3 {
+    /// #   async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+    /// #       Err(s3s::s3_error!(NotImplemented))
+    /// #   }
+    ///     // Implement S3 operations
+    /// }
+    ///
+    /// let mut public = PublicReadAccess::new();
+    /// public.add_prefix("assets", "public/");
+    ///
+    /// let mut builder = S3ServiceBuilder::new(MyS3);
+    /// builder.set_auth(SimpleAuth::from_single("ACCESS_KEY", "SECRET_KEY"));
+    /// builder.set_anonymous_access(public);
+    /// ```
+    pub fn set_anonymous_access(&mut self, anonymous: impl AnonymousAccess) {
+        self.anonymous = Some(Box::new(anonymous));
+    }
+
     /// Sets the custom route handler for the service.
     ///
     /// The route handler allows intercepting requests before they reach the S3 service,
     /// useful for implementing custom endpoints or middleware.
+    /// Use a [`RouteSet`](crate::route::RouteSet) to attach multiple custom endpoints.
     ///
     /// If not set, all requests are processed as S3 operations.
     ///
@@ -413,6 +497,347 @@ impl S3ServiceBuilder {
         self.validation = Some(Box::new(validation));
     }
 
+    /// Sets the request throttle for the service.
+    ///
+    /// The throttle can reject requests with `SlowDown` (503) before they reach
+    /// the S3 service, and back off access keys that repeatedly fail the signature check.
+    /// See [`crate::throttle`] for details.
+    ///
+    /// If not set, requests are not throttled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use s3s::service::S3ServiceBuilder;
+    /// use s3s::throttle::{RateLimit, RateLimiter};
+    /// use s3s::{S3, S3Request, S3Response, S3Result};
+    /// use s3s::dto::{GetObjectInput, GetObjectOutput};
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone)]
+    /// struct MyS3;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl S3 for MyS3 {
+    /// #   async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+    /// #       Err(s3s::s3_error!(NotImplemented))
+    /// #   }
+    ///     // Implement S3 operations
+    /// }
+    ///
+    /// let limiter = RateLimiter::new()
+    ///     .with_access_key_limit(RateLimit::per_second(100))
+    ///     .with_auth_failure_backoff(5, Duration::from_secs(1), Duration::from_secs(300));
+    ///
+    /// let mut builder = S3ServiceBuilder::new(MyS3);
+    /// builder.set_throttle(limiter);
+    /// ```
+    pub fn set_throttle(&mut self, throttle: impl S3Throttle) {
+        self.throttle = Some(Box::new(throttle));
+    }
+
+    /// Sets the concurrency limiter for the service.
+    ///
+    /// The limiter caps the number of concurrent requests and the memory used for
+    /// buffering request bodies, rejecting or queueing excess requests.
+    /// See [`crate::limit`] for details.
+    ///
+    /// If not set, requests are not limited.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use s3s::service::S3ServiceBuilder;
+    /// use s3s::limit::ConcurrencyLimiter;
+    /// use s3s::{S3, S3Request, S3Response, S3Result};
+    /// use s3s::dto::{GetObjectInput, GetObjectOutput};
+    ///
+    /// #[derive(Clone)]
+    /// struct MyS3;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl S3 for MyS3 {
+    /// #   async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+    /// #       Err(s3s::s3_error!(NotImplemented))
+    /// #   }
+    ///     // Implement S3 operations
+    /// }
+    ///
+    /// let limiter = ConcurrencyLimiter::new()
+    ///     .with_max_requests(1024)
+    ///     .with_max_buffered_body_bytes(256 * 1024 * 1024);
+    ///
+    /// let mut builder = S3ServiceBuilder::new(MyS3);
+    /// builder.set_concurrency_limiter(limiter);
+    /// ```
+    pub fn set_concurrency_limiter(&mut self, limiter: ConcurrencyLimiter) {
+        self.limiter = Some(limiter);
+    }
+
+    /// Sets the CORS configuration provider for the service.
+    ///
+    /// Preflight requests are answered and the responses to CORS requests are decorated
+    /// according to the CORS configuration of the bucket.
+    /// See [`crate::cors`] for details.
+    ///
+    /// If neither this nor [`set_cors_from_s3`](Self::set_cors_from_s3) is called,
+    /// CORS requests are passed to the S3 implementation unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use s3s::service::S3ServiceBuilder;
+    /// use s3s::dto::{CORSConfiguration, CORSRule};
+    /// use s3s::{S3, S3Request, S3Response, S3Result};
+    /// use s3s::dto::{GetObjectInput, GetObjectOutput};
+    ///
+    /// #[derive(Clone)]
+    /// struct MyS3;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl S3 for MyS3 {
+    /// #   async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+    /// #       Err(s3s::s3_error!(NotImplemented))
+    /// #   }
+    ///     // Implement S3 operations
+    /// }
+    ///
+    /// // The same rules for every bucket
+    /// let config = CORSConfiguration {
+    ///     cors_rules: vec![CORSRule {
+    ///         allowed_origins: vec!["https://example.com".to_owned()],
+    ///         allowed_methods: vec!["GET".to_owned(), "PUT".to_owned()],
+    ///         allowed_headers: Some(vec!["*".to_owned()]),
+    ///         ..Default::default()
+    ///     }],
+    /// };
+    ///
+    /// let mut builder = S3ServiceBuilder::new(MyS3);
+    /// builder.set_cors(config);
+    /// ```
+    pub fn set_cors(&mut self, cors: impl S3Cors) {
+        self.cors = Some(Box::new(cors));
+    }
+
+    /// Resolves the CORS configurations of buckets with `GetBucketCors` of the S3 implementation.
+    ///
+    /// Buckets are treated as having no CORS configuration if `GetBucketCors` returns
+    /// `NoSuchCORSConfiguration`, `NoSuchBucket` or `NotImplemented`.
+    /// The configurations are cached per bucket for one minute. `PutBucketCors` and
+    /// `DeleteBucketCors` requests through the service invalidate the cached configuration.
+    /// See [`set_cors`](Self::set_cors) for details.
+    pub fn set_cors_from_s3(&mut self) {
+        let cors = CorsFromS3::new(Arc::clone(&self.s3));
+        self.interceptors.push(Box::new(cors.invalidation()));
+        self.cors = Some(Box::new(cors));
+    }
+
+    /// Adds an operation interceptor to the service.
+    ///
+    /// Interceptors are called around the invocation of each S3 operation.
+    /// The `before` hooks run in the order in which the interceptors are added,
+    /// and the `after` hooks run in reverse order.
+    /// See [`crate::intercept`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use s3s::service::S3ServiceBuilder;
+    /// use s3s::intercept::{BeforeContext, S3Interceptor};
+    /// use s3s::{S3, S3Request, S3Response, S3Result};
+    /// use s3s::dto::{GetObjectInput, GetObjectOutput};
+    ///
+    /// #[derive(Clone)]
+    /// struct MyS3;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl S3 for MyS3 {
+    /// #   async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+    /// #       Err(s3s::s3_error!(NotImplemented))
+    /// #   }
+    ///     // Implement S3 operations
+    /// }
+    ///
+    /// struct Audit;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl S3Interceptor for Audit {
+    ///     async fn before(&self, cx: &mut BeforeContext<'_>) -> S3Result<()> {
+    ///         tracing::info!(op = cx.s3_op().name(), "audit");
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut builder = S3ServiceBuilder::new(MyS3);
+    /// builder.add_interceptor(Audit);
+    /// ```
+    pub fn add_interceptor(&mut self, interceptor: impl S3Interceptor) {
+        self.interceptors.push(Box::new(interceptor));
+    }
+
+    /// Adds an interceptor of a single operation to the service.
+    ///
+    /// The operation is identified by its input type `I`.
+    /// The interceptor is ordered with the ones added by [`add_interceptor`](Self::add_interceptor).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use s3s::service::S3ServiceBuilder;
+    /// use s3s::intercept::{BeforeContext, OperationInterceptor};
+    /// use s3s::{S3, S3Request, S3Response, S3Result, s3_error};
+    /// use s3s::dto::{DeleteBucketInput, DeleteBucketOutput};
+    ///
+    /// #[derive(Clone)]
+    /// struct MyS3;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl S3 for MyS3 {
+    /// #   async fn delete_bucket(&self, _req: S3Request<DeleteBucketInput>) -> S3Result<S3Response<DeleteBucketOutput>> {
+    /// #       Err(s3_error!(NotImplemented))
+    /// #   }
+    ///     // Implement S3 operations
+    /// }
+    ///
+    /// /// Protects a bucket from deletion
+    /// struct Protect;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl OperationInterceptor<DeleteBucketInput> for Protect {
+    ///     async fn before(&self, cx: &mut BeforeContext<'_, DeleteBucketInput>) -> S3Result<()> {
+    ///         if cx.input().bucket == "important" {
+    ///             return Err(s3_error!(AccessDenied));
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut builder = S3ServiceBuilder::new(MyS3);
+    /// builder.add_operation_interceptor(Protect);
+    /// ```
+    pub fn add_operation_interceptor<I: OperationInput>(&mut self, interceptor: impl OperationInterceptor<I>) {
+        self.interceptors.push(Box::new(Typed::new(interceptor)));
+    }
+
+    /// Sets the access log sink for the service.
+    ///
+    /// The sink receives a record in the S3 server access log format for every request.
+    /// See [`crate::access_log`] for details.
+    ///
+    /// If not set, no access log records are produced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use s3s::service::S3ServiceBuilder;
+    /// use s3s::access_log::TracingAccessLog;
+    /// use s3s::{S3, S3Request, S3Response, S3Result};
+    /// use s3s::dto::{GetObjectInput, GetObjectOutput};
+    ///
+    /// #[derive(Clone)]
+    /// struct MyS3;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl S3 for MyS3 {
+    /// #   async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+    /// #       Err(s3s::s3_error!(NotImplemented))
+    /// #   }
+    ///     // Implement S3 operations
+    /// }
+    ///
+    /// let mut builder = S3ServiceBuilder::new(MyS3);
+    /// builder.set_access_log(TracingAccessLog);
+    /// ```
+    pub fn set_access_log(&mut self, access_log: impl S3AccessLog) {
+        self.access_log = Some(Arc::new(access_log));
+    }
+
+    /// Sets the metrics recorder for the service.
+    ///
+    /// The recorder observes the operation, status, latency and size of every request.
+    /// See [`crate::metrics`] for details.
+    ///
+    /// If not set, no metrics are recorded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use s3s::service::S3ServiceBuilder;
+    /// use s3s::metrics::PrometheusMetrics;
+    /// use s3s::{S3, S3Request, S3Response, S3Result};
+    /// use s3s::dto::{GetObjectInput, GetObjectOutput};
+    ///
+    /// #[derive(Clone)]
+    /// struct MyS3;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl S3 for MyS3 {
+    /// #   async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+    /// #       Err(s3s::s3_error!(NotImplemented))
+    /// #   }
+    ///     // Implement S3 operations
+    /// }
+    ///
+    /// let metrics = PrometheusMetrics::new();
+    ///
+    /// let mut builder = S3ServiceBuilder::new(MyS3);
+    /// builder.set_metrics(metrics.clone());
+    /// ```
+    pub fn set_metrics(&mut self, metrics: impl S3Metrics) {
+        self.metrics = Some(Box::new(metrics));
+    }
+
+    /// Sets the request ID generator for the service.
+    ///
+    /// Every request is assigned a request ID, which is returned in the response headers
+    /// and error bodies. See [`crate::request_id`] for details.
+    ///
+    /// If not set, defaults to [`DefaultRequestIdGenerator`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use s3s::service::S3ServiceBuilder;
+    /// use s3s::request_id::RequestId;
+    /// use s3s::{S3, S3Request, S3Response, S3Result};
+    /// use s3s::dto::{GetObjectInput, GetObjectOutput};
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    ///
+    /// #[derive(Clone)]
+    /// struct MyS3;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl S3 for MyS3 {
+    /// #   async fn get_object(&self, _req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+    /// #       Err(s3s::s3_error!(NotImplemented))
+    /// #   }
+    ///     // Implement S3 operations
+    /// }
+    ///
+    /// let counter = AtomicU64::new(0);
+    ///
+    /// let mut builder = S3ServiceBuilder::new(MyS3);
+    /// builder.set_request_id_generator(move || {
+    ///     let n = counter.fetch_add(1, Ordering::Relaxed);
+    ///     RequestId::new(format!("{n:016X}"), "node-1")
+    /// });
+    /// ```
+    pub fn set_request_id_generator(&mut self, generator: impl RequestIdGenerator) {
+        self.request_id = Some(Box::new(generator));
+    }
+
+    /// Sets the maximum number of cached signing keys.
+    ///
+    /// Deriving a `SigV4` signing key takes four HMAC-SHA256 rounds, so the service
+    /// caches the keys per access key, date, region and service.
+    /// When the cache is full, the least recently used key is evicted.
+    /// A capacity of zero disables the cache.
+    ///
+    /// If not set, defaults to 4096.
+    pub fn set_signing_key_cache_capacity(&mut self, capacity: usize) {
+        self.signing_key_cache_capacity = Some(capacity);
+    }
+
     /// Builds the [`S3Service`] from this builder.
     ///
     /// This consumes the builder and returns the configured service ready to handle requests.
@@ -440,6 +865,10 @@ impl S3ServiceBuilder {
     #[must_use]
     pub fn build(self) -> S3Service {
         let config = self.config.unwrap_or_else(|| Arc::new(StaticConfigProvider::default()));
+        let request_id = self.request_id.unwrap_or_else(|| Box::new(DefaultRequestIdGenerator::new()));
+        let signing_keys = self
+            .signing_key_cache_capacity
+            .map_or_else(SigningKeyCache::default, SigningKeyCache::new);
         S3Service {
             inner: Arc::new(Inner {
                 s3: self.s3,
@@ -447,8 +876,17 @@ impl S3ServiceBuilder {
                 host: self.host,
                 auth: self.auth,
                 access: self.access,
+                anonymous: self.anonymous,
                 route: self.route,
                 validation: self.validation,
+                throttle: self.throttle,
+                limiter: self.limiter,
+                cors: self.cors,
+                interceptors: self.interceptors,
+                access_log: self.access_log,
+                metrics: self.metrics,
+                request_id,
+                signing_keys,
             }),
         }
     }
@@ -551,8 +989,17 @@ struct Inner {
     host: Option<Box<dyn S3Host>>,
     auth: Option<Box<dyn S3Auth>>,
     access: Option<Box<dyn S3Access>>,
+    anonymous: Option<Box<dyn AnonymousAccess>>,
     route: Option<Box<dyn S3Route>>,
     validation: Option<Box<dyn NameValidation>>,
+    throttle: Option<Box<dyn S3Throttle>>,
+    limiter: Option<ConcurrencyLimiter>,
+    cors: Option<Box<dyn S3Cors>>,
+    interceptors: Vec<Box<dyn S3Interceptor>>,
+    access_log: Option<Arc<dyn S3AccessLog>>,
+    metrics: Option<Box<dyn S3Metrics>>,
+    request_id: Box<dyn RequestIdGenerator>,
+    signing_keys: SigningKeyCache,
 }
 
 impl S3Service {
@@ -561,9 +1008,11 @@ impl S3Service {
     /// This is the main entry point for handling S3 requests. The method:
     ///
     /// 1. Parses the HTTP request
-    /// 2. Authenticates the request (if authentication is configured)
+    /// 2. Authenticates the request (if authentication is configured) and applies
+    ///    the throttle (if configured)
     /// 3. Authorizes the request whenever authentication is enabled (using custom access
-    ///    control if configured, otherwise the default access check)
+    ///    control if configured, otherwise the default access check), or when it is
+    ///    anonymous and an anonymous access policy is configured
     /// 4. Routes the request to the appropriate S3 operation
     /// 5. Invokes your S3 implementation
     /// 6. Converts the result to an HTTP response
@@ -611,15 +1060,44 @@ impl S3Service {
     /// ```
     #[tracing::instrument(
         level = "debug",
+        name = "s3s.request",
         skip(self, req),
-        fields(start_time=?crate::time::now_utc())
+        fields(
+            start_time = ?crate::time::now_utc(),
+            otel.name = Empty,
+            otel.kind = "server",
+            otel.status_code = Empty,
+            rpc.system = "aws-api",
+            rpc.service = "S3",
+            rpc.method = Empty,
+            http.request.method = Empty,
+            url.path = Empty,
+            http.response.status_code = Empty,
+            error.type = Empty,
+            aws.request_id = Empty,
+            aws.s3.bucket = Empty,
+            aws.s3.key = Empty,
+            trace_id = Empty,
+            parent_span_id = Empty,
+        )
     )]
     pub async fn call(&self, req: HttpRequest) -> Result<HttpResponse, HttpError> {
         debug!(?req);
 
         let t0 = crate::time::Instant::now();
 
-        let mut req = Request::from(req);
+        let mut guard = CallGuard {
+            req: Request::from(req),
+            in_flight: None,
+        };
+        let req = &mut guard.req;
+        self.start_request(req);
+
+        let pending = self.inner.access_log.as_ref().map(|_| Box::new(PendingRecord::capture(req)));
+        guard.in_flight = self.inner.metrics.as_deref().map(|m| Box::new(InFlight::start(m, req)));
+        if pending.is_some() || guard.in_flight.is_some() {
+            req.s3ext.observed = Some(Box::default());
+        }
 
         let ccx = crate::ops::CallContext {
             s3: &self.inner.s3,
@@ -627,15 +1105,30 @@ impl S3Service {
             host: self.inner.host.as_deref(),
             auth: self.inner.auth.as_deref(),
             access: self.inner.access.as_deref(),
+            anonymous: self.inner.anonymous.as_deref(),
             route: self.inner.route.as_deref(),
             validation: self.inner.validation.as_deref(),
+            throttle: self.inner.throttle.as_deref(),
+            limiter: self.inner.limiter.as_ref(),
+            cors: self.inner.cors.as_deref(),
+            signing_keys: Some(&self.inner.signing_keys),
+            interceptors: &self.inner.interceptors,
         };
-        let result = match crate::ops::call(&mut req, &ccx).await {
-            Ok(resp) => Ok(HttpResponse::from(resp)),
+        let mut result = match crate::ops::call(req, &ccx).await {
+            Ok(mut resp) => {
+                if let Some(request_id) = &req.s3ext.request_id {
+                    set_request_id_headers(&mut resp.headers, request_id);
+                }
+                if let Some(cors) = req.s3ext.cors.take() {
+                    crate::cors::set_response_headers(&mut resp.headers, *cors);
+                }
+                Ok(HttpResponse::from(resp))
+            }
             Err(err) => Err(HttpError::new(Box::new(err))),
         };
 
         let duration = t0.elapsed();
+        record_response(&result);
 
         match result {
             Ok(ref resp) => {
@@ -648,14 +1141,95 @@ impl S3Service {
             Err(ref err) => error!(?duration, ?err),
         }
 
+        let observed = req.s3ext.observed.take();
+        if let Some(in_flight) = guard.in_flight.take() {
+            in_flight.finish(observed.as_deref(), &result);
+        }
+        if let (Some(access_log), Some(pending)) = (&self.inner.access_log, pending) {
+            pending.log(access_log, observed, &mut result);
+        }
+
         result
     }
 
+    /// Assigns the request ID and extracts the trace context.
+    fn start_request(&self, req: &mut Request) {
+        let span = Span::current();
+        span.record("http.request.method", req.method.as_str());
+        span.record("url.path", req.uri.path());
+
+        let request_id = self.inner.request_id.generate();
+        span.record("aws.request_id", request_id.id());
+        req.extensions.insert(request_id.clone());
+        req.s3ext.request_id = Some(request_id);
+
+        let traceparent = req.headers.get(TRACEPARENT).and_then(|val| val.to_str().ok());
+        if let Some(traceparent) = traceparent.and_then(TraceParent::parse) {
+            span.record("trace_id", display(format_args!("{:032x}", traceparent.trace_id)));
+            span.record("parent_span_id", display(format_args!("{:016x}", traceparent.parent_id)));
+            req.extensions.insert(traceparent);
+        }
+    }
+
     async fn call_owned(self, req: HttpRequest) -> Result<HttpResponse, HttpError> {
         self.call(req).await
     }
 }
 
+/// Keeps the operation of a request that is cancelled before the response is ready.
+struct CallGuard<'a> {
+    req: Request,
+    in_flight: Option<Box<InFlight<'a>>>,
+}
+
+impl Drop for CallGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(in_flight) = &mut self.in_flight {
+            in_flight.set_operation(self.req.s3ext.observed.as_ref().and_then(|o| o.op_name));
+        }
+    }
+}
+
+const TRACEPARENT: HeaderName = HeaderName::from_static("traceparent");
+
+/// Records the response attributes on the current span.
+fn record_response(result: &Result<HttpResponse, HttpError>) {
+    let span = Span::current();
+    if span.is_disabled() {
+        return;
+    }
+    match result {
+        Ok(resp) => {
+            span.record("http.response.status_code", resp.status().as_u16());
+            if let Some(code) = resp.extensions().get::<ResponseErrorCode>() {
+                span.record("error.type", code.0.as_str());
+            }
+            if resp.status().is_server_error() {
+                span.record("otel.status_code", "ERROR");
+            }
+        }
+        Err(_) => {
+            span.record("http.response.status_code", 500);
+            span.record("error.type", "InternalError");
+            span.record("otel.status_code", "ERROR");
+        }
+    }
+}
+
+/// Sets the request ID headers unless they are set by the S3 implementation.
+fn set_request_id_headers(headers: &mut HeaderMap, request_id: &RequestId) {
+    for (name, val) in [
+        (header::X_AMZ_REQUEST_ID, request_id.id()),
+        (header::X_AMZ_ID_2, request_id.host_id()),
+    ] {
+        if !headers.contains_key(&name)
+            && let Ok(val) = HeaderValue::from_str(val)
+        {
+            headers.insert(name, val);
+        }
+    }
+}
+
 impl fmt::Debug for S3Service {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("S3Service").finish_non_exhaustive()
@@ -751,8 +1325,8 @@ mod tests {
 
         // In case the futures are made too large accidentally
         assert!(output_size(&crate::ops::call) <= 1800);
-        assert!(output_size(&S3Service::call) <= 3200);
-        assert!(output_size(&S3Service::call_owned) <= 3500);
+        assert!(output_size(&S3Service::call) <= 3300);
+        assert!(output_size(&S3Service::call_owned) <= 3600);
     }
 
     // Test validation functionality
@@ -936,4 +1510,632 @@ mod tests {
         let cloned = service.clone();
         assert!(format!("{cloned:?}").contains("S3Service"));
     }
+
+    #[tokio::test]
+    async fn test_service_access_log() {
+        use crate::access_log::AccessLogRecord;
+        use std::net::SocketAddr;
+        use std::sync::Mutex;
+
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let mut builder = S3ServiceBuilder::new(MockS3);
+        builder.set_access_log({
+            let records = Arc::clone(&records);
+            move |record: AccessLogRecord| records.lock().unwrap().push(record)
+        });
+        let service = builder.build();
+
+        let mut req = http::Request::builder()
+            .method(http::Method::GET)
+            .uri("http://localhost/bucket/photos/my%20puppy.jpg?versionId=v1")
+            .header(crate::header::HOST, "localhost")
+            .header(http::header::USER_AGENT, "test-agent")
+            .body(Body::empty())
+            .unwrap();
+        req.extensions_mut().insert(SocketAddr::from(([192, 0, 2, 3], 4567)));
+
+        let resp = service.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::NOT_IMPLEMENTED);
+
+        // the record is logged after the body is sent
+        assert!(records.lock().unwrap().is_empty());
+        let body = http_body_util::BodyExt::collect(resp.into_body()).await.unwrap().to_bytes();
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.bytes_sent, Some(body.len() as u64));
+        assert!(record.total_time >= record.turn_around_time);
+        assert_eq!(record.bucket.as_deref(), Some("bucket"));
+        assert_eq!(record.key.as_deref(), Some("photos/my puppy.jpg"));
+        assert_eq!(record.operation, "REST.GET.OBJECT");
+        assert_eq!(record.http_status, 501);
+        assert_eq!(record.error_code.as_deref(), Some("NotImplemented"));
+        assert_eq!(record.version_id.as_deref(), Some("v1"));
+        assert_eq!(record.requester, None);
+        assert!(record.request_id.is_some());
+        assert!(record.host_id.is_some());
+
+        let line = record.to_string();
+        assert!(line.contains(" 192.0.2.3 - "), "{line}");
+        assert!(
+            line.contains(" \"GET /bucket/photos/my%20puppy.jpg?versionId=v1 HTTP/1.1\" 501 NotImplemented "),
+            "{line}"
+        );
+        assert!(line.contains(" \"test-agent\" v1 "), "{line}");
+    }
+
+    mod span_capture {
+        use std::collections::BTreeMap;
+        use std::fmt;
+        use std::sync::{Arc, Mutex};
+
+        use tracing::Subscriber;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing_subscriber::layer::{Context, Layer};
+        use tracing_subscriber::registry::LookupSpan;
+
+        /// A closed span with its parent and fields
+        #[derive(Debug)]
+        pub struct CapturedSpan {
+            pub name: &'static str,
+            pub level: tracing::Level,
+            pub parent: Option<&'static str>,
+            pub fields: BTreeMap<&'static str, String>,
+        }
+
+        struct Fields(BTreeMap<&'static str, String>);
+
+        impl Visit for Fields {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.insert(field.name(), value.to_owned());
+            }
+
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.0.insert(field.name(), format!("{value:?}"));
+            }
+        }
+
+        /// A layer that captures the spans
+        #[derive(Clone, Default)]
+        pub struct SpanCapture(pub Arc<Mutex<Vec<CapturedSpan>>>);
+
+        impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SpanCapture {
+            fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+                let mut fields = Fields(BTreeMap::new());
+                attrs.record(&mut fields);
+                ctx.span(id).unwrap().extensions_mut().insert(fields);
+            }
+
+            fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+                let span = ctx.span(id).unwrap();
+                let mut extensions = span.extensions_mut();
+                values.record(extensions.get_mut::<Fields>().unwrap());
+            }
+
+            fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+                let span = ctx.span(&id).unwrap();
+                let fields = span.extensions_mut().remove::<Fields>().unwrap().0;
+                self.0.lock().unwrap().push(CapturedSpan {
+                    name: span.name(),
+                    level: *span.metadata().level(),
+                    parent: span.parent().map(|p| p.name()),
+                    fields,
+                });
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_service_spans() {
+        use span_capture::SpanCapture;
+        use tracing_subscriber::layer::SubscriberExt as _;
+
+        let capture = SpanCapture::default();
+        let subscriber = tracing_subscriber::registry().with(capture.clone());
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let service = S3ServiceBuilder::new(MockS3).build();
+        let req = http::Request::builder()
+            .method(http::Method::GET)
+            .uri("http://localhost/bucket/key")
+            .header("traceparent", "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+            .body(Body::empty())
+            .unwrap();
+        let resp = service.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::NOT_IMPLEMENTED);
+
+        let spans = capture.0.lock().unwrap();
+        let request = spans.iter().find(|s| s.name == "s3s.request").unwrap();
+        assert_eq!(request.level, tracing::Level::DEBUG);
+        assert_eq!(request.parent, None);
+
+        let expected = [
+            ("otel.name", "S3/GetObject"),
+            ("otel.kind", "server"),
+            ("otel.status_code", "ERROR"),
+            ("rpc.system", "aws-api"),
+            ("rpc.service", "S3"),
+            ("rpc.method", "GetObject"),
+            ("http.request.method", "GET"),
+            ("url.path", "/bucket/key"),
+            ("http.response.status_code", "501"),
+            ("error.type", "NotImplemented"),
+            ("aws.s3.bucket", "bucket"),
+            ("aws.s3.key", "key"),
+            ("trace_id", "4bf92f3577b34da6a3ce929d0e0e4736"),
+            ("parent_span_id", "00f067aa0ba902b7"),
+        ];
+        for (field, value) in expected {
+            assert_eq!(request.fields.get(field).map(String::as_str), Some(value), "{field}");
+        }
+        assert!(request.fields.contains_key("aws.request_id"));
+
+        for phase in ["check_signature", "deserialize", "handler"] {
+            let span = spans.iter().find(|s| s.name == phase).unwrap();
+            assert_eq!(span.level, tracing::Level::DEBUG, "{phase}");
+            assert_eq!(span.parent, Some("s3s.request"), "{phase}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_service_metrics() {
+        use crate::metrics::{MetricsRoute, PrometheusMetrics};
+
+        let metrics = PrometheusMetrics::new();
+        let mut builder = S3ServiceBuilder::new(MockS3);
+        builder.set_metrics(metrics.clone());
+        builder.set_route(MetricsRoute::new(metrics.clone()).with_anonymous_access());
+        let service = builder.build();
+
+        let req = http::Request::builder()
+            .method(http::Method::GET)
+            .uri("http://localhost/bucket/key")
+            .body(Body::empty())
+            .unwrap();
+        let resp = service.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::NOT_IMPLEMENTED);
+
+        // a bucket named `metrics` is not shadowed by the route
+        let req = http::Request::builder()
+            .method(http::Method::GET)
+            .uri("http://localhost/metrics")
+            .body(Body::empty())
+            .unwrap();
+        let resp = service.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::NOT_IMPLEMENTED);
+
+        let req = http::Request::builder()
+            .method(http::Method::GET)
+            .uri("http://localhost/?x-s3s-metrics")
+            .body(Body::empty())
+            .unwrap();
+        let resp = service.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(http::header::CONTENT_TYPE).unwrap(),
+            "text/plain; version=0.0.4; charset=utf-8"
+        );
+
+        let text = metrics.render();
+        assert!(
+            text.contains("s3s_requests_total{operation=\"GetObject\",status_class=\"5xx\",error_code=\"NotImplemented\"} 1"),
+            "{text}"
+        );
+        assert!(
+            text.contains("s3s_requests_total{operation=\"unknown\",status_class=\"2xx\",error_code=\"\"} 1"),
+            "{text}"
+        );
+        assert!(
+            text.contains("s3s_requests_total{operation=\"ListObjects\",status_class=\"5xx\",error_code=\"NotImplemented\"} 1"),
+            "{text}"
+        );
+        assert!(text.contains("s3s_request_duration_seconds_count{operation=\"GetObject\"} 1"), "{text}");
+        assert!(text.contains("\ns3s_requests_in_flight 0\n"), "{text}");
+    }
+
+    #[tokio::test]
+    async fn test_service_request_id() {
+        use crate::dto::{GetObjectInput, GetObjectOutput};
+        use crate::request_id::RequestId;
+
+        struct RequestIdS3;
+
+        #[async_trait::async_trait]
+        impl S3 for RequestIdS3 {
+            async fn get_object(&self, req: S3Request<GetObjectInput>) -> crate::S3Result<S3Response<GetObjectOutput>> {
+                let request_id = req.extensions.get::<RequestId>().unwrap();
+                assert_eq!(request_id.id(), "REQ-1");
+                Err(crate::s3_error!(NoSuchKey))
+            }
+        }
+
+        let mut builder = S3ServiceBuilder::new(RequestIdS3);
+        builder.set_request_id_generator(|| RequestId::new("REQ-1", "HOST-1"));
+        let service = builder.build();
+
+        let req = http::Request::builder()
+            .method(http::Method::GET)
+            .uri("http://localhost/bucket/key")
+            .body(Body::empty())
+            .unwrap();
+        let mut resp = service.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+        assert_eq!(resp.headers().get(crate::header::X_AMZ_REQUEST_ID).unwrap(), "REQ-1");
+        assert_eq!(resp.headers().get(crate::header::X_AMZ_ID_2).unwrap(), "HOST-1");
+
+        let body = resp.body_mut().store_all_limited(1 << 20).await.unwrap();
+        let body = std::str::from_utf8(&body).unwrap();
+        assert!(body.contains("<Code>NoSuchKey</Code>"), "{body}");
+        assert!(body.contains("<RequestId>REQ-1</RequestId>"), "{body}");
+    }
+
+    #[tokio::test]
+    async fn test_service_traceparent() {
+        use crate::dto::{GetObjectInput, GetObjectOutput};
+        use crate::trace::TraceParent;
+
+        struct TraceS3;
+
+        #[async_trait::async_trait]
+        impl S3 for TraceS3 {
+            async fn get_object(&self, req: S3Request<GetObjectInput>) -> crate::S3Result<S3Response<GetObjectOutput>> {
+                match req.extensions.get::<TraceParent>() {
+                    Some(tp) => {
+                        assert_eq!(tp.trace_id, 0x4bf9_2f35_77b3_4da6_a3ce_929d_0e0e_4736);
+                        assert_eq!(tp.parent_id, 0x00f0_67aa_0ba9_02b7);
+                        Err(crate::s3_error!(NoSuchKey))
+                    }
+                    None => Err(crate::s3_error!(AccessDenied)),
+                }
+            }
+        }
+
+        let service = S3ServiceBuilder::new(TraceS3).build();
+
+        for (traceparent, expected) in [
+            (
+                Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+                http::StatusCode::NOT_FOUND,
+            ),
+            (Some("invalid"), http::StatusCode::FORBIDDEN),
+            (None, http::StatusCode::FORBIDDEN),
+        ] {
+            let mut req = http::Request::builder()
+                .method(http::Method::GET)
+                .uri("http://localhost/bucket/key");
+            if let Some(traceparent) = traceparent {
+                req = req.header("traceparent", traceparent);
+            }
+            let resp = service.call(req.body(Body::empty()).unwrap()).await.unwrap();
+            assert_eq!(resp.status(), expected, "{traceparent:?}");
+        }
+    }
+
+    fn stalled_body(prefix: &'static str) -> Body {
+        use futures::StreamExt;
+
+        let frames = futures::stream::iter([Ok::<_, std::io::Error>(http_body::Frame::data(Bytes::from_static(
+            prefix.as_bytes(),
+        )))])
+        .chain(futures::stream::pending());
+        Body::http_body(http_body_util::StreamBody::new(frames))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_service_body_idle_timeout() {
+        use crate::dto::{PutObjectInput, PutObjectOutput};
+        use futures::StreamExt;
+
+        struct UploadS3;
+
+        #[async_trait::async_trait]
+        impl S3 for UploadS3 {
+            async fn put_object(&self, req: S3Request<PutObjectInput>) -> crate::S3Result<S3Response<PutObjectOutput>> {
+                let mut body = req.input.body.unwrap();
+                while let Some(chunk) = body.next().await {
+                    // The error of the body is discarded on purpose
+                    if chunk.is_err() {
+                        return Err(crate::s3_error!(InternalError));
+                    }
+                }
+                Ok(S3Response::new(PutObjectOutput::default()))
+            }
+        }
+
+        let mut builder = S3ServiceBuilder::new(UploadS3);
+        builder.set_config(Arc::new(StaticConfigProvider::new(Arc::new(crate::config::S3Config {
+            body_idle_timeout_secs: Some(20),
+            ..Default::default()
+        }))));
+        let service = builder.build();
+
+        for uri in ["http://localhost/bucket/key", "http://localhost/bucket?tagging"] {
+            let req = http::Request::builder()
+                .method(http::Method::PUT)
+                .uri(uri)
+                .header("content-length", "10")
+                .body(stalled_body("hello"))
+                .unwrap();
+            let mut resp = service.call(req).await.unwrap();
+            assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST, "{uri}");
+
+            let body = resp.body_mut().store_all_limited(1 << 20).await.unwrap();
+            let body = std::str::from_utf8(&body).unwrap();
+            assert!(body.contains("<Code>RequestTimeout</Code>"), "{body}");
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_service_request_timeout() {
+        use crate::dto::{GetObjectInput, GetObjectOutput, ListObjectsV2Input, ListObjectsV2Output};
+
+        struct SlowS3;
+
+        #[async_trait::async_trait]
+        impl S3 for SlowS3 {
+            async fn list_objects_v2(
+                &self,
+                _req: S3Request<ListObjectsV2Input>,
+            ) -> crate::S3Result<S3Response<ListObjectsV2Output>> {
+                std::future::pending().await
+            }
+
+            async fn get_object(&self, _req: S3Request<GetObjectInput>) -> crate::S3Result<S3Response<GetObjectOutput>> {
+                tokio::time::sleep(std::time::Duration::from_secs(45)).await;
+                Err(crate::s3_error!(NoSuchKey))
+            }
+        }
+
+        let mut builder = S3ServiceBuilder::new(SlowS3);
+        builder.set_config(Arc::new(StaticConfigProvider::new(Arc::new(crate::config::S3Config {
+            list_request_timeout_secs: Some(30),
+            data_request_timeout_secs: Some(300),
+            ..Default::default()
+        }))));
+        let service = builder.build();
+
+        let req = http::Request::builder()
+            .method(http::Method::GET)
+            .uri("http://localhost/bucket?list-type=2")
+            .body(Body::empty())
+            .unwrap();
+        let mut resp = service.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+        let body = resp.body_mut().store_all_limited(1 << 20).await.unwrap();
+        let body = std::str::from_utf8(&body).unwrap();
+        assert!(body.contains("<Code>RequestTimeout</Code>"), "{body}");
+
+        // The data-plane timeout is longer than the handler
+        let req = http::Request::builder()
+            .method(http::Method::GET)
+            .uri("http://localhost/bucket/key")
+            .body(Body::empty())
+            .unwrap();
+        let resp = service.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_service_concurrency_limits() {
+        use crate::limit::ConcurrencyLimiter;
+        use futures::FutureExt;
+
+        let put_tagging = |content_length: &str, body: Body| {
+            http::Request::builder()
+                .method(http::Method::PUT)
+                .uri("http://localhost/bucket?tagging")
+                .header("content-length", content_length)
+                .body(body)
+                .unwrap()
+        };
+        let get_object = || {
+            http::Request::builder()
+                .method(http::Method::GET)
+                .uri("http://localhost/bucket/key")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        for (limiter, by_bytes) in [
+            (ConcurrencyLimiter::new().with_max_requests(1), false),
+            (ConcurrencyLimiter::new().with_max_buffered_body_bytes(100), true),
+        ] {
+            let mut builder = S3ServiceBuilder::new(MockS3);
+            builder.set_concurrency_limiter(limiter);
+            let service = builder.build();
+
+            // The first request holds its share while its body is stalled
+            let mut first = Box::pin(service.call(put_tagging("80", stalled_body("<Tagging>"))));
+            assert!(first.as_mut().now_or_never().is_none());
+
+            let second = if by_bytes {
+                service.call(put_tagging("80", stalled_body("<Tagging>"))).await
+            } else {
+                service.call(get_object()).await
+            };
+            let mut resp = second.unwrap();
+            assert_eq!(resp.status(), http::StatusCode::SERVICE_UNAVAILABLE);
+            let body = resp.body_mut().store_all_limited(1 << 20).await.unwrap();
+            let body = std::str::from_utf8(&body).unwrap();
+            assert!(body.contains("<Code>SlowDown</Code>"), "{body}");
+
+            // Small bodies and requests without bodies are not limited by bytes
+            if by_bytes {
+                let resp = service.call(get_object()).await.unwrap();
+                assert_eq!(resp.status(), http::StatusCode::NOT_IMPLEMENTED);
+            }
+
+            // The share is released when the first request is dropped
+            drop(first);
+            let resp = service.call(get_object()).await.unwrap();
+            assert_eq!(resp.status(), http::StatusCode::NOT_IMPLEMENTED);
+        }
+    }
+
+    #[tokio::test]
+    #[allow(clippy::too_many_lines)]
+    async fn test_service_cors() {
+        use crate::dto::{CORSRule, GetBucketCorsInput, GetBucketCorsOutput, GetObjectInput, GetObjectOutput};
+        use crate::dto::{PutBucketCorsInput, PutBucketCorsOutput};
+        use crate::route::S3Route;
+        use hyper::http::Extensions;
+        use hyper::{HeaderMap, Method, Uri};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct CorsS3 {
+            get_bucket_cors_calls: Arc<AtomicUsize>,
+        }
+
+        #[async_trait::async_trait]
+        impl S3 for CorsS3 {
+            async fn get_bucket_cors(
+                &self,
+                req: S3Request<GetBucketCorsInput>,
+            ) -> crate::S3Result<S3Response<GetBucketCorsOutput>> {
+                self.get_bucket_cors_calls.fetch_add(1, Ordering::SeqCst);
+                if req.input.bucket != "bucket" {
+                    return Err(crate::s3_error!(NoSuchCORSConfiguration));
+                }
+                let rule = CORSRule {
+                    allowed_origins: vec!["https://example.com".to_owned()],
+                    allowed_methods: vec!["GET".to_owned()],
+                    allowed_headers: Some(vec!["x-amz-*".to_owned()]),
+                    expose_headers: Some(vec!["ETag".to_owned()]),
+                    ..Default::default()
+                };
+                Ok(S3Response::new(GetBucketCorsOutput {
+                    cors_rules: Some(vec![rule]),
+                }))
+            }
+
+            async fn get_object(&self, _req: S3Request<GetObjectInput>) -> crate::S3Result<S3Response<GetObjectOutput>> {
+                Err(crate::s3_error!(NoSuchKey))
+            }
+
+            async fn put_bucket_cors(
+                &self,
+                _req: S3Request<PutBucketCorsInput>,
+            ) -> crate::S3Result<S3Response<PutBucketCorsOutput>> {
+                Ok(S3Response::new(PutBucketCorsOutput::default()))
+            }
+        }
+
+        /// Handles `OPTIONS /bucket/route`
+        struct OptionsRoute;
+
+        #[async_trait::async_trait]
+        impl S3Route for OptionsRoute {
+            fn is_match(&self, method: &Method, uri: &Uri, _: &HeaderMap, _: &mut Extensions) -> bool {
+                method == Method::OPTIONS && uri.path() == "/bucket/route"
+            }
+
+            async fn check_access(&self, _req: &mut S3Request<Body>) -> crate::S3Result<()> {
+                Ok(())
+            }
+
+            async fn call(&self, _req: S3Request<Body>) -> crate::S3Result<S3Response<Body>> {
+                Ok(S3Response::with_status(Body::empty(), http::StatusCode::NO_CONTENT))
+            }
+        }
+
+        let get_bucket_cors_calls = Arc::new(AtomicUsize::new(0));
+        let mut builder = S3ServiceBuilder::new(CorsS3 {
+            get_bucket_cors_calls: Arc::clone(&get_bucket_cors_calls),
+        });
+        builder.set_cors_from_s3();
+        builder.set_route(OptionsRoute);
+        let service = builder.build();
+
+        let preflight = |path: &str, origin: &str, method: &str| {
+            http::Request::builder()
+                .method(http::Method::OPTIONS)
+                .uri(format!("http://localhost{path}"))
+                .header("origin", origin)
+                .header("access-control-request-method", method)
+                .header("access-control-request-headers", "X-Amz-Date")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let resp = service
+            .call(preflight("/bucket/key", "https://example.com", "GET"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let headers = resp.headers();
+        assert_eq!(headers["access-control-allow-origin"], "https://example.com");
+        assert_eq!(headers["access-control-allow-methods"], "GET");
+        assert_eq!(headers["access-control-allow-headers"], "x-amz-date");
+        assert!(headers.contains_key(crate::header::X_AMZ_REQUEST_ID));
+
+        for (path, origin, method) in [
+            ("/bucket/key", "https://example.com", "PUT"),
+            ("/bucket/key", "https://example.org", "GET"),
+            ("/other/key", "https://example.com", "GET"),
+        ] {
+            let resp = service.call(preflight(path, origin, method)).await.unwrap();
+            assert_eq!(resp.status(), http::StatusCode::FORBIDDEN, "{path} {origin} {method}");
+            assert!(!resp.headers().contains_key("access-control-allow-origin"));
+        }
+
+        // The configurations are cached per bucket
+        assert_eq!(get_bucket_cors_calls.load(Ordering::SeqCst), 2);
+
+        // PutBucketCors invalidates the cached configuration
+        let req = http::Request::builder()
+            .method(http::Method::PUT)
+            .uri("http://localhost/bucket?cors")
+            .body(Body::from(
+                "<CORSConfiguration><CORSRule><AllowedMethod>GET</AllowedMethod><AllowedOrigin>*</AllowedOrigin></CORSRule></CORSConfiguration>"
+                    .to_owned(),
+            ))
+            .unwrap();
+        let resp = service.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let resp = service
+            .call(preflight("/bucket/key", "https://example.com", "GET"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(get_bucket_cors_calls.load(Ordering::SeqCst), 3);
+
+        // The custom route handles its OPTIONS requests
+        let resp = service
+            .call(preflight("/bucket/route", "https://example.org", "GET"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let req = http::Request::builder()
+            .method(http::Method::OPTIONS)
+            .uri("http://localhost/bucket/key")
+            .body(Body::empty())
+            .unwrap();
+        let resp = service.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        // Actual requests are decorated, including error responses
+        let get_object = |origin: &str| {
+            http::Request::builder()
+                .method(http::Method::GET)
+                .uri("http://localhost/bucket/key")
+                .header("origin", origin)
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let resp = service.call(get_object("https://example.com")).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+        let headers = resp.headers();
+        assert_eq!(headers["access-control-allow-origin"], "https://example.com");
+        assert_eq!(headers["access-control-allow-credentials"], "true");
+        assert_eq!(headers["access-control-expose-headers"], "ETag");
+        assert!(!headers.contains_key("access-control-allow-headers"));
+
+        let resp = service.call(get_object("https://example.org")).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+        assert!(!resp.headers().contains_key("access-control-allow-origin"));
+    }
 }
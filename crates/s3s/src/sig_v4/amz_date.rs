@@ -54,6 +54,23 @@ impl AmzDate {
         buf
     }
 
+    /// Constructs `AmzDate` from a time, truncated to seconds
+    ///
+    /// Returns `None` if the year is not in `0..=9999`
+    #[must_use]
+    pub fn from_time(t: time::OffsetDateTime) -> Option<Self> {
+        let t = t.to_offset(time::UtcOffset::UTC);
+        let year = u16::try_from(t.year()).ok().filter(|&y| y <= 9999)?;
+        Some(Self {
+            year,
+            month: t.month().into(),
+            day: t.day(),
+            hour: t.hour(),
+            minute: t.minute(),
+            second: t.second(),
+        })
+    }
+
     pub fn to_time(&self) -> Option<time::OffsetDateTime> {
         let y = i32::from(self.year);
         let m: time::Month = self.month.try_into().ok()?;
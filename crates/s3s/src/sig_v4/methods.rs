@@ -11,6 +11,8 @@ use crate::http::OrderedHeaders;
 use crate::utils::crypto::{hex, hex_sha256, hex_sha256_chunk, hmac_sha256};
 use crate::utils::stable_sort_by_first;
 
+use std::fmt;
+
 use hyper::Method;
 use hyper::body::Bytes;
 use smallvec::SmallVec;
@@ -95,7 +97,7 @@ fn normalize_header_value(ans: &mut String, value: &str) {
 }
 
 /// sha256 hash of an empty string
-const EMPTY_STRING_SHA256_HASH: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+pub(crate) const EMPTY_STRING_SHA256_HASH: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
 
 /// Payload
 #[derive(Debug, Clone, Copy)]
@@ -110,6 +112,10 @@ pub enum Payload<'a> {
     MultipleChunksWithTrailer,
     /// unsigned streaming with trailing headers
     UnsignedMultipleChunksWithTrailer,
+    /// multiple chunks signed with `AWS4-ECDSA-P256-SHA256`
+    EcdsaMultipleChunks,
+    /// multiple chunks with trailing headers signed with `AWS4-ECDSA-P256-SHA256`
+    EcdsaMultipleChunksWithTrailer,
 }
 
 #[cfg(test)]
@@ -247,6 +253,8 @@ fn create_canonical_request_with_uri_mode(
             Payload::MultipleChunks => ans.push_str("STREAMING-AWS4-HMAC-SHA256-PAYLOAD"),
             Payload::MultipleChunksWithTrailer => ans.push_str("STREAMING-AWS4-HMAC-SHA256-PAYLOAD-TRAILER"),
             Payload::UnsignedMultipleChunksWithTrailer => ans.push_str("STREAMING-UNSIGNED-PAYLOAD-TRAILER"),
+            Payload::EcdsaMultipleChunks => ans.push_str("STREAMING-AWS4-ECDSA-P256-SHA256-PAYLOAD"),
+            Payload::EcdsaMultipleChunksWithTrailer => ans.push_str("STREAMING-AWS4-ECDSA-P256-SHA256-PAYLOAD-TRAILER"),
         }
     }
 
@@ -390,15 +398,27 @@ pub fn create_trailer_string_to_sign(
     ans
 }
 
-/// calculate signature
+/// The `SigV4` signing key of a secret key, date, region and service.
+///
+/// The key is zeroized on drop.
+#[derive(Clone)]
+pub struct SigningKey([u8; 32]);
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SigningKey").field(&"[SENSITIVE-SIGNING-KEY]").finish()
+    }
+}
+
+/// derive the signing key
 #[must_use]
-pub fn calculate_signature(
-    string_to_sign: &str,
-    secret_key: &SecretKey,
-    amz_date: &AmzDate,
-    region: &str,
-    service: &str,
-) -> Signature {
+pub fn derive_signing_key(secret_key: &SecretKey, amz_date: &AmzDate, region: &str, service: &str) -> SigningKey {
     let mut secret = {
         let secret_key = secret_key.expose();
         let mut buf = <SmallVec<[u8; 128]>>::with_capacity(secret_key.len().saturating_add(4));
@@ -421,10 +441,26 @@ pub fn calculate_signature(
     let date_region_service_key = hmac_sha256(date_region_key, service);
 
     // SigningKey
-    let signing_key = hmac_sha256(date_region_service_key, "aws4_request");
+    SigningKey(hmac_sha256(date_region_service_key, "aws4_request"))
+}
+
+/// calculate signature with a derived signing key
+#[must_use]
+pub fn calculate_signature_with_key(string_to_sign: &str, signing_key: &SigningKey) -> Signature {
+    Signature::from_computed(hex(hmac_sha256(signing_key.0, string_to_sign)))
+}
 
-    // Signature
-    Signature::from_computed(hex(hmac_sha256(signing_key, string_to_sign)))
+/// calculate signature
+#[must_use]
+pub fn calculate_signature(
+    string_to_sign: &str,
+    secret_key: &SecretKey,
+    amz_date: &AmzDate,
+    region: &str,
+    service: &str,
+) -> Signature {
+    let signing_key = derive_signing_key(secret_key, amz_date, region, service);
+    calculate_signature_with_key(string_to_sign, &signing_key)
 }
 
 fn create_presigned_canonical_request_with_uri_mode(
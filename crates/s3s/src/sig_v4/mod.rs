@@ -31,3 +31,6 @@ pub use self::upload_stream::*;
 
 mod methods;
 pub use self::methods::*;
+
+mod signing_key_cache;
+pub use self::signing_key_cache::SigningKeyCache;
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Authorization (`SigV4A`)
+//!
+//! `SigV4A` uses the same header layout as `SigV4`, but the credential scope has no region:
+//! `<access-key-id>/<date>/<service>/aws4_request`.
+//! The regions are carried by the signed `x-amz-region-set` header instead.
+//!
+
+use serde::{Deserialize, Serialize};
+
+/// Authorization (`SigV4A`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthorizationV4a<'a> {
+    /// The algorithm that was used to calculate the signature.
+    pub algorithm: &'a str,
+
+    /// Access key ID and the scope information, which includes the date and service that were used to calculate the signature.
+    pub credential: CredentialV4a<'a>,
+
+    /// A semicolon-separated list of request headers that you used to compute `Signature`.
+    pub signed_headers: Vec<&'a str>,
+
+    /// The DER-encoded ECDSA signature expressed as lowercase hexadecimal characters.
+    pub signature: &'a str,
+}
+
+/// Access key ID and the scope information, which includes the date and service that were used to calculate the signature.
+///
+/// This string has the following form:
+/// `<your-access-key-id>/<date>/<aws-service>/aws4_request`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CredentialV4a<'a> {
+    /// access key id
+    pub access_key_id: &'a str,
+    /// \<date\> value is specified using YYYYMMDD format.
+    pub date: &'a str,
+    /// \<aws-service\> value is `s3` when sending request to Amazon S3.
+    pub aws_service: &'a str,
+}
+
+/// [`AuthorizationV4a`]
+#[derive(Debug, thiserror::Error)]
+#[error("ParseAuthorizationV4aError")]
+pub struct ParseAuthorizationV4aError {
+    /// priv place holder
+    _priv: (),
+}
+
+impl<'a> AuthorizationV4a<'a> {
+    /// Parses `AuthorizationV4a` from `Authorization` header
+    /// # Errors
+    /// Returns an `Err` if the header is invalid
+    pub fn parse(header: &'a str) -> Result<Self, ParseAuthorizationV4aError> {
+        match parser::parse_authorization(header) {
+            Ok(("", ans)) => Ok(ans),
+            Ok(_) | Err(_) => Err(ParseAuthorizationV4aError { _priv: () }),
+        }
+    }
+}
+
+mod parser {
+    use super::*;
+
+    use crate::utils::parser::{Error, consume, digit2, digit4};
+
+    use nom::IResult;
+    use nom::Parser;
+    use nom::bytes::complete::{tag, take, take_till, take_till1};
+    use nom::character::complete::{multispace0, multispace1};
+    use nom::combinator::verify;
+    use nom::multi::separated_list1;
+    use nom::sequence::{delimited, preceded, terminated};
+
+    pub fn parse_authorization(mut input: &str) -> IResult<&str, AuthorizationV4a<'_>> {
+        let s = &mut input;
+
+        let algorithm = consume(s, till_space1)?;
+
+        consume(s, multispace1)?;
+        let credential = consume(s, |i| delimited(tag("Credential="), parse_credential, tag(",")).parse(i))?;
+
+        consume(s, multispace0)?;
+        let parse_headers = separated_list1(tag(";"), take_till(|c| c == ';' || c == ','));
+        let signed_headers = consume(s, |i| delimited(tag("SignedHeaders="), parse_headers, tag(",")).parse(i))?;
+
+        consume(s, multispace0)?;
+        let signature = consume(s, |i| preceded(tag("Signature="), till_space0).parse(i))?;
+
+        consume(s, multispace0)?;
+
+        let ans = AuthorizationV4a {
+            algorithm,
+            credential,
+            signed_headers,
+            signature,
+        };
+
+        Ok((input, ans))
+    }
+
+    fn till_space0(input: &str) -> IResult<&str, &str> {
+        take_till(|c: char| c.is_ascii_whitespace())(input)
+    }
+
+    fn till_space1(input: &str) -> IResult<&str, &str> {
+        take_till1(|c: char| c.is_ascii_whitespace())(input)
+    }
+
+    fn parse_credential(mut input: &str) -> IResult<&str, CredentialV4a<'_>> {
+        let s = &mut input;
+
+        let access_key_id = consume(s, until_slash0)?;
+        let date = consume(s, |i| verify(until_slash1, |s| verify_date(s).is_ok()).parse(i))?;
+        let aws_service = consume(s, until_slash1)?;
+        consume(s, tag("aws4_request"))?;
+
+        let ans = CredentialV4a {
+            access_key_id,
+            date,
+            aws_service,
+        };
+
+        Ok((input, ans))
+    }
+
+    fn until_slash0(input: &str) -> IResult<&str, &str> {
+        terminated(take_till(|c| c == '/'), take(1_usize)).parse(input)
+    }
+
+    fn until_slash1(input: &str) -> IResult<&str, &str> {
+        terminated(take_till1(|c| c == '/'), take(1_usize)).parse(input)
+    }
+
+    fn verify_date(s: &str) -> Result<(), Error> {
+        let x = s.as_bytes();
+        if x.len() != 8 {
+            return Err(Error);
+        }
+
+        let yyyy = digit4([x[0], x[1], x[2], x[3]])?.into();
+        let mm = digit2([x[4], x[5]])?.into();
+        let dd = digit2([x[6], x[7]])?.into();
+
+        match chrono::NaiveDate::from_ymd_opt(yyyy, mm, dd) {
+            Some(_) => Ok(()),
+            None => Err(Error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auth_header() {
+        let auth = concat!(
+            "AWS4-ECDSA-P256-SHA256 ",
+            "Credential=AKIDEXAMPLE/20150830/service/aws4_request, ",
+            "SignedHeaders=host;x-amz-date;x-amz-region-set, ",
+            "Signature=3046022100fddaaf816a31e30d04973875b13bc27b98da10907a3a1872fd5819a221334abc",
+            "022100de2ee81d83b54f31efc82834de07e6cd4d5c6f25bfa08e0f29a15bb0977c343e",
+        );
+        let ans = AuthorizationV4a::parse(auth).unwrap();
+
+        assert_eq!(ans.algorithm, "AWS4-ECDSA-P256-SHA256");
+        assert_eq!(ans.credential.access_key_id, "AKIDEXAMPLE");
+        assert_eq!(ans.credential.date, "20150830");
+        assert_eq!(ans.credential.aws_service, "service");
+        assert_eq!(ans.signed_headers, &["host", "x-amz-date", "x-amz-region-set"]);
+        assert_eq!(ans.signature.len(), 144);
+    }
+
+    #[test]
+    fn rejects_sig_v4_credential_scope() {
+        let auth = concat!(
+            "AWS4-ECDSA-P256-SHA256 ",
+            "Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, ",
+            "SignedHeaders=host;x-amz-date;x-amz-region-set, ",
+            "Signature=3044022051fe398025aafbfc21d054bc78e5edfb96c9acb7fd272795565181d757815e47",
+        );
+        assert!(AuthorizationV4a::parse(auth).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_date() {
+        let auth = |date: &str| {
+            format!("AWS4-ECDSA-P256-SHA256 Credential=AKIDEXAMPLE/{date}/s3/aws4_request, SignedHeaders=host, Signature=3044")
+        };
+        assert!(AuthorizationV4a::parse(&auth("20150931")).is_err());
+        assert!(AuthorizationV4a::parse(&auth("20150930")).is_ok());
+    }
+}
@@ -0,0 +1,493 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Signature v4a methods
+//!
+//! The canonical request is shared with `SigV4` (see [`crate::sig_v4::create_canonical_request`]).
+
+use crate::auth::SecretKey;
+use crate::sig_v4::{AmzDate, EMPTY_STRING_SHA256_HASH};
+use crate::utils::crypto::{hex_sha256, hex_sha256_chunk, hmac_sha256};
+
+use hyper::body::Bytes;
+
+use p256::NistP256;
+use p256::ecdsa::Signature;
+use p256::ecdsa::signature::hazmat::PrehashVerifier;
+use p256::elliptic_curve::Curve;
+use p256::elliptic_curve::bigint::{Encoding, U256};
+use sha2::{Digest, Sha256};
+use smallvec::SmallVec;
+use zeroize::Zeroize;
+
+/// The ECDSA P-256 private key of an access key pair
+pub use p256::SecretKey as SigningKey;
+
+/// The ECDSA P-256 public key of an access key pair
+pub use p256::PublicKey as VerifyingKey;
+
+/// The `SigV4A` signing algorithm name
+pub const ALGORITHM: &str = "AWS4-ECDSA-P256-SHA256";
+
+/// create string to sign
+#[must_use]
+pub fn create_string_to_sign(canonical_request: &str, amz_date: &AmzDate, service: &str) -> String {
+    let mut ans = String::with_capacity(256);
+
+    {
+        // <Algorithm>\n
+        ans.push_str(ALGORITHM);
+        ans.push('\n');
+    }
+
+    {
+        // <RequestDateTime>\n
+        ans.push_str(&amz_date.fmt_iso8601());
+        ans.push('\n');
+    }
+
+    {
+        // <CredentialScope>\n
+        ans.push_str(&amz_date.fmt_date());
+        ans.push('/');
+        ans.push_str(service);
+        ans.push_str("/aws4_request\n");
+    }
+
+    {
+        // <HashedCanonicalRequest>
+        hex_sha256(canonical_request.as_bytes(), |s| ans.push_str(s));
+    }
+
+    ans
+}
+
+/// create `string_to_sign` of a chunk
+#[must_use]
+pub fn create_chunk_string_to_sign(amz_date: &AmzDate, service: &str, prev_signature: &str, chunk_data: &[Bytes]) -> String {
+    let mut ans = String::with_capacity(256);
+
+    {
+        ans.push_str("AWS4-ECDSA-P256-SHA256-PAYLOAD\n");
+    }
+    {
+        ans.push_str(&amz_date.fmt_iso8601());
+        ans.push('\n');
+    }
+    {
+        ans.push_str(&amz_date.fmt_date());
+        ans.push('/');
+        ans.push_str(service);
+        ans.push_str("/aws4_request\n");
+    }
+    {
+        ans.push_str(prev_signature);
+        ans.push('\n');
+    }
+    {
+        ans.push_str(EMPTY_STRING_SHA256_HASH);
+        ans.push('\n');
+    }
+    {
+        if chunk_data.is_empty() {
+            ans.push_str(EMPTY_STRING_SHA256_HASH);
+        } else {
+            hex_sha256_chunk(chunk_data, |s| ans.push_str(s));
+        }
+    }
+
+    ans
+}
+
+/// create `string_to_sign` of the final trailer block (0-chunk with trailing headers)
+#[must_use]
+pub fn create_trailer_string_to_sign(
+    amz_date: &AmzDate,
+    service: &str,
+    prev_signature: &str,
+    canonical_trailers: &[u8],
+) -> String {
+    let mut ans = String::with_capacity(256);
+
+    {
+        ans.push_str("AWS4-ECDSA-P256-SHA256-TRAILER\n");
+    }
+    {
+        ans.push_str(&amz_date.fmt_iso8601());
+        ans.push('\n');
+    }
+    {
+        ans.push_str(&amz_date.fmt_date());
+        ans.push('/');
+        ans.push_str(service);
+        ans.push_str("/aws4_request\n");
+    }
+    {
+        ans.push_str(prev_signature);
+        ans.push('\n');
+    }
+    {
+        // hash of canonicalized trailing headers
+        hex_sha256(canonical_trailers, |s| ans.push_str(s));
+    }
+
+    ans
+}
+
+/// Removes the `*` padding that some clients append to chunk signatures.
+///
+/// `SigV4A` chunk signatures have a variable length, so they may be padded up to
+/// [`MAX_SIGNATURE_LEN`] to keep the chunk metadata size predictable.
+/// The padding is not part of the signature and is not used for chaining.
+#[must_use]
+pub fn trim_signature_padding(signature: &str) -> &str {
+    signature.trim_end_matches('*')
+}
+
+/// The maximum length of a hex-encoded DER P-256 signature (72 bytes)
+pub const MAX_SIGNATURE_LEN: usize = 144;
+
+/// Derives the ECDSA P-256 signing key of an access key pair.
+///
+/// This is the NIST SP 800-108 counter-mode KDF (HMAC-SHA256) used by `SigV4A`.
+/// The derived key does not depend on the date, region or service,
+/// so it can be reused for every request signed by the same access key.
+#[must_use]
+pub fn derive_signing_key(access_key: &str, secret_key: &SecretKey) -> SigningKey {
+    let n_minus_2 = NistP256::ORDER.wrapping_sub(&U256::from_u8(2));
+
+    let mut input_key = {
+        let secret_key = secret_key.expose();
+        let mut buf = <SmallVec<[u8; 128]>>::with_capacity(secret_key.len().saturating_add(5));
+        buf.extend_from_slice(b"AWS4A");
+        buf.extend_from_slice(secret_key.as_bytes());
+        buf
+    };
+
+    let mut fixed_input = <SmallVec<[u8; 128]>>::new();
+    let mut counter: u8 = 1;
+    let signing_key = loop {
+        // i || Label || 0x00 || Context || L
+        fixed_input.clear();
+        fixed_input.extend_from_slice(&1_u32.to_be_bytes());
+        fixed_input.extend_from_slice(ALGORITHM.as_bytes());
+        fixed_input.push(0);
+        fixed_input.extend_from_slice(access_key.as_bytes());
+        fixed_input.push(counter);
+        fixed_input.extend_from_slice(&256_u32.to_be_bytes());
+
+        let mut k0 = U256::from_be_bytes(hmac_sha256(input_key.as_slice(), fixed_input.as_slice()));
+
+        // The candidate is accepted only if `k0 + 1` is a valid private scalar in `[1, n - 1]`.
+        if k0 <= n_minus_2 {
+            let mut d = k0.wrapping_add(&U256::ONE).to_be_bytes();
+            let key = SigningKey::from_slice(&d);
+            d.zeroize();
+            k0.zeroize();
+            break key.expect("derived scalar is always in range");
+        }
+
+        k0.zeroize();
+        counter = counter.checked_add(1).expect("counter will never get to 255");
+    };
+
+    input_key.zeroize();
+    signing_key
+}
+
+/// Derives the ECDSA P-256 public key of an access key pair.
+///
+/// See [`derive_signing_key`].
+#[must_use]
+pub fn derive_verifying_key(access_key: &str, secret_key: &SecretKey) -> VerifyingKey {
+    derive_signing_key(access_key, secret_key).public_key()
+}
+
+/// Verifies a hex-encoded DER ECDSA signature over the SHA-256 digest of `string_to_sign`.
+///
+/// The DER encoding must be canonical, and `r` and `s` must be in `[1, n - 1]`.
+/// High-S signatures are accepted, since AWS SDKs do not normalize `s`.
+#[must_use]
+pub fn verify_signature(verifying_key: &VerifyingKey, string_to_sign: &str, signature: &str) -> bool {
+    let Ok(der) = hex_simd::decode_to_vec(signature) else { return false };
+    let Ok(signature) = Signature::from_der(&der) else { return false };
+    let digest = Sha256::digest(string_to_sign.as_bytes());
+    p256::ecdsa::VerifyingKey::from(verifying_key)
+        .verify_prehash(&digest, &signature)
+        .is_ok()
+}
+
+/// calculate signature (hex-encoded DER)
+#[cfg(test)]
+#[must_use]
+pub fn calculate_signature(signing_key: &SigningKey, string_to_sign: &str) -> String {
+    use p256::ecdsa::signature::hazmat::PrehashSigner;
+
+    let digest = Sha256::digest(string_to_sign.as_bytes());
+    let signature: Signature = p256::ecdsa::SigningKey::from(signing_key).sign_prehash(&digest).unwrap();
+    crate::utils::crypto::hex(signature.to_der().as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::http::OrderedHeaders;
+    use crate::sig_v4;
+
+    use hyper::Method;
+
+    // Fixtures from the AWS signing test suite (`v4a/*`):
+    // the credentials are shared by every case, the signatures are the published `header-signature.txt`.
+    const ACCESS_KEY: &str = "AKIDEXAMPLE";
+    const SECRET_KEY: &str = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+    const PUBLIC_KEY_X: &str = "b6618f6a65740a99e650b33b6b4b5bd0d43b176d721a3edfea7e7d2d56d936b1";
+    const PUBLIC_KEY_Y: &str = "865ed22a7eadc9c5cb9d2cbaca1b3699139fedc5043dc6661864218330c8e518";
+    const TIMESTAMP: &str = "20150830T123600Z";
+    const SERVICE: &str = "service";
+
+    fn verifying_key() -> VerifyingKey {
+        derive_verifying_key(ACCESS_KEY, &SecretKey::from(SECRET_KEY))
+    }
+
+    struct Fixture<'a> {
+        method: Method,
+        query_strings: &'a [(&'a str, &'a str)],
+        extra_headers: &'a [(&'a str, &'a str)],
+        canonical_request: &'a str,
+        string_to_sign: &'a str,
+        signature: &'a str,
+    }
+
+    fn check_fixture(fixture: &Fixture<'_>) {
+        let mut headers = vec![
+            ("host", "example.amazonaws.com"),
+            ("x-amz-date", TIMESTAMP),
+            ("x-amz-region-set", "us-east-1"),
+        ];
+        headers.extend_from_slice(fixture.extra_headers);
+        let headers = OrderedHeaders::from_slice_unchecked(&headers);
+
+        let canonical_request =
+            sig_v4::create_canonical_request(&fixture.method, "/", fixture.query_strings, &headers, sig_v4::Payload::empty());
+        assert_eq!(canonical_request, fixture.canonical_request);
+
+        let amz_date = AmzDate::parse(TIMESTAMP).unwrap();
+        let string_to_sign = create_string_to_sign(&canonical_request, &amz_date, SERVICE);
+        assert_eq!(string_to_sign, fixture.string_to_sign);
+
+        assert!(verify_signature(&verifying_key(), &string_to_sign, fixture.signature));
+    }
+
+    #[test]
+    fn derive_signing_key_matches_public_key() {
+        use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+        let point = verifying_key().to_encoded_point(false);
+        assert_eq!(crate::utils::crypto::hex(point.x().unwrap()), PUBLIC_KEY_X);
+        assert_eq!(crate::utils::crypto::hex(point.y().unwrap()), PUBLIC_KEY_Y);
+    }
+
+    #[test]
+    fn get_vanilla() {
+        check_fixture(&Fixture {
+            method: Method::GET,
+            query_strings: &[],
+            extra_headers: &[],
+            canonical_request: concat!(
+                "GET\n",
+                "/\n",
+                "\n",
+                "host:example.amazonaws.com\n",
+                "x-amz-date:20150830T123600Z\n",
+                "x-amz-region-set:us-east-1\n",
+                "\n",
+                "host;x-amz-date;x-amz-region-set\n",
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ),
+            string_to_sign: concat!(
+                "AWS4-ECDSA-P256-SHA256\n",
+                "20150830T123600Z\n",
+                "20150830/service/aws4_request\n",
+                "cf59db423e841c8b7e3444158185aa261b724a5c27cbe762676f3eed19f4dc02",
+            ),
+            signature: concat!(
+                "3045022018b4e277d0281864beb51d3600e23f88510ea5031d68ddfbb68614b82a5eb7d2",
+                "022100effb9c5f22ed9ef3ae0ab243d21f06bce82365bbb79529a07b6888c343ae5f8c",
+            ),
+        });
+    }
+
+    #[test]
+    fn post_vanilla() {
+        check_fixture(&Fixture {
+            method: Method::POST,
+            query_strings: &[],
+            extra_headers: &[],
+            canonical_request: concat!(
+                "POST\n",
+                "/\n",
+                "\n",
+                "host:example.amazonaws.com\n",
+                "x-amz-date:20150830T123600Z\n",
+                "x-amz-region-set:us-east-1\n",
+                "\n",
+                "host;x-amz-date;x-amz-region-set\n",
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ),
+            string_to_sign: concat!(
+                "AWS4-ECDSA-P256-SHA256\n",
+                "20150830T123600Z\n",
+                "20150830/service/aws4_request\n",
+                "806a9b01b76472cc6b66fff02630726d55f8b4ada6d2fd9b36eb0d710e215861",
+            ),
+            signature: concat!(
+                "3044022051fe398025aafbfc21d054bc78e5edfb96c9acb7fd272795565181d757815e47",
+                "02202e7b8d2b92324290b1d95f8b0fc5e333bb8b5e333f6160bcab39d7258156d224",
+            ),
+        });
+    }
+
+    #[test]
+    fn get_vanilla_query_order_key_case() {
+        check_fixture(&Fixture {
+            method: Method::GET,
+            query_strings: &[("Param1", "value1"), ("Param2", "value2")],
+            extra_headers: &[],
+            canonical_request: concat!(
+                "GET\n",
+                "/\n",
+                "Param1=value1&Param2=value2\n",
+                "host:example.amazonaws.com\n",
+                "x-amz-date:20150830T123600Z\n",
+                "x-amz-region-set:us-east-1\n",
+                "\n",
+                "host;x-amz-date;x-amz-region-set\n",
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ),
+            string_to_sign: concat!(
+                "AWS4-ECDSA-P256-SHA256\n",
+                "20150830T123600Z\n",
+                "20150830/service/aws4_request\n",
+                "cc07b29e0d0f0b2d6aa296621a5608fd9c2271159b9b2f737f682704ebb96482",
+            ),
+            signature: concat!(
+                "3045022100b342b0d62487d235a5baf82e970102670a8d7af34c61f086125d204a90724d9e",
+                "022070304e846de3de228c57e528b61f4addfdb5fa788a8a452e483c6a286d8803f5",
+            ),
+        });
+    }
+
+    #[test]
+    fn get_vanilla_with_session_token() {
+        check_fixture(&Fixture {
+            method: Method::GET,
+            query_strings: &[],
+            extra_headers: &[("x-amz-security-token", "6e86291e8372ff2a2260956d9b8aae1d763fbf315fa00fa31553b73ebf194267")],
+            canonical_request: concat!(
+                "GET\n",
+                "/\n",
+                "\n",
+                "host:example.amazonaws.com\n",
+                "x-amz-date:20150830T123600Z\n",
+                "x-amz-region-set:us-east-1\n",
+                "x-amz-security-token:6e86291e8372ff2a2260956d9b8aae1d763fbf315fa00fa31553b73ebf194267\n",
+                "\n",
+                "host;x-amz-date;x-amz-region-set;x-amz-security-token\n",
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ),
+            string_to_sign: concat!(
+                "AWS4-ECDSA-P256-SHA256\n",
+                "20150830T123600Z\n",
+                "20150830/service/aws4_request\n",
+                "79893373104239a0547df489af395ec3c1b8873a8601f07f11ffd3f1ac557e7d",
+            ),
+            signature: concat!(
+                "3044022035c6bc9a8cab8a434bd8bd1cc3b6f1401842f530f1537722ad10d72a1577f8c8",
+                "0220101352fe0720715efbab80f82edb133175f0b61cad85daf943ad615852f9ae10",
+            ),
+        });
+    }
+
+    #[test]
+    fn verify_rejects_tampered_or_malformed_signature() {
+        let key = verifying_key();
+        let amz_date = AmzDate::parse(TIMESTAMP).unwrap();
+        let string_to_sign = create_string_to_sign("GET\n/\n", &amz_date, SERVICE);
+
+        let signing_key = derive_signing_key(ACCESS_KEY, &SecretKey::from(SECRET_KEY));
+        let signature = calculate_signature(&signing_key, &string_to_sign);
+        assert!(verify_signature(&key, &string_to_sign, &signature));
+
+        let other_string_to_sign = create_string_to_sign("PUT\n/\n", &amz_date, SERVICE);
+        assert!(!verify_signature(&key, &other_string_to_sign, &signature));
+        assert!(!verify_signature(&key, &string_to_sign, "not-hex"));
+        assert!(!verify_signature(&key, &string_to_sign, "3044"));
+
+        let other_key = derive_verifying_key("AKIDOTHER", &SecretKey::from(SECRET_KEY));
+        assert!(!verify_signature(&other_key, &string_to_sign, &signature));
+    }
+
+    #[test]
+    fn verify_rejects_non_canonical_der() {
+        let key = verifying_key();
+        let string_to_sign = concat!(
+            "AWS4-ECDSA-P256-SHA256\n",
+            "20150830T123600Z\n",
+            "20150830/service/aws4_request\n",
+            "cf59db423e841c8b7e3444158185aa261b724a5c27cbe762676f3eed19f4dc02",
+        );
+        let r = "18b4e277d0281864beb51d3600e23f88510ea5031d68ddfbb68614b82a5eb7d2";
+        let s = "00effb9c5f22ed9ef3ae0ab243d21f06bce82365bbb79529a07b6888c343ae5f8c";
+        assert!(verify_signature(&key, string_to_sign, &format!("30450220{r}0221{s}")));
+
+        let cases = [
+            // redundant leading zero
+            format!("3046022100{r}0221{s}"),
+            // negative integer
+            format!("30440220{r}0220{}", &s[2..]),
+            // trailing data
+            format!("30470220{r}0221{s}0000"),
+            // wrong sequence length
+            format!("30460220{r}0221{s}"),
+            // zero `r`
+            format!("30260201000221{s}"),
+            // zero `s`
+            format!("30250220{r}020100"),
+            // `r` equal to the group order
+            format!("3046022100{N}0221{s}"),
+            // `s` equal to the group order
+            format!("30450220{r}022100{N}"),
+        ];
+        for signature in cases {
+            assert!(!verify_signature(&key, string_to_sign, &signature), "{signature}");
+        }
+    }
+
+    /// The group order of P-256
+    const N: &str = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+
+    #[test]
+    fn verify_high_s() {
+        let key = verifying_key();
+        let string_to_sign = concat!(
+            "AWS4-ECDSA-P256-SHA256\n",
+            "20150830T123600Z\n",
+            "20150830/service/aws4_request\n",
+            "cf59db423e841c8b7e3444158185aa261b724a5c27cbe762676f3eed19f4dc02",
+        );
+        let r = "18b4e277d0281864beb51d3600e23f88510ea5031d68ddfbb68614b82a5eb7d2";
+
+        // The published signature has a high `s`, and `n - s` is the equivalent low-S form
+        let high_s = "effb9c5f22ed9ef3ae0ab243d21f06bce82365bbb79529a07b6888c343ae5f8c";
+        let low_s = "1004639fdd12610d51f54dbc2de0f942d4c394f1ef8274e4785141ffb8b4c5c5";
+        assert!(verify_signature(&key, string_to_sign, &format!("30450220{r}022100{high_s}")));
+        assert!(verify_signature(&key, string_to_sign, &format!("30440220{r}0220{low_s}")));
+
+        // A high-S signature is still bound to the message and the key
+        let other_string_to_sign = string_to_sign.replace("cf59", "cf5a");
+        assert!(!verify_signature(&key, &other_string_to_sign, &format!("30450220{r}022100{high_s}")));
+        let other_key = derive_verifying_key("AKIDOTHER", &SecretKey::from(SECRET_KEY));
+        assert!(!verify_signature(&other_key, string_to_sign, &format!("30450220{r}022100{high_s}")));
+    }
+}
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! AWS Signature Version 4A.
+//!
+//! Parses and verifies ECDSA-P256-SHA256-based request authentication (`AWS4-ECDSA-P256-SHA256`),
+//! the multi-region variant of Signature Version 4.
+//!
+//! See <https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_sigv.html>
+//!
+
+mod authorization_v4a;
+pub use self::authorization_v4a::*;
+
+mod region_set;
+pub use self::region_set::*;
+
+mod methods;
+pub use self::methods::*;
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! x-amz-region-set
+//!
+//! A comma-separated list of regions that a `SigV4A` signature is valid for.
+//! Each entry may contain `*` wildcards, e.g. `us-east-1,eu-*` or `*`.
+
+/// x-amz-region-set
+#[derive(Debug)]
+pub struct RegionSet<'a> {
+    /// region patterns
+    regions: Vec<&'a str>,
+}
+
+/// [`RegionSet`]
+#[derive(Debug, thiserror::Error)]
+#[error("ParseRegionSetError")]
+pub struct ParseRegionSetError(());
+
+impl<'a> RegionSet<'a> {
+    /// Parses `RegionSet` from header
+    /// # Errors
+    /// Returns an error if the header is empty or contains an empty entry
+    pub fn parse(header: &'a str) -> Result<Self, ParseRegionSetError> {
+        let mut regions = Vec::new();
+        for region in header.split(',') {
+            let region = region.trim();
+            let is_valid = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '*';
+            if region.is_empty() || !region.chars().all(is_valid) {
+                return Err(ParseRegionSetError(()));
+            }
+            regions.push(region);
+        }
+        Ok(Self { regions })
+    }
+
+    /// Returns `true` if any entry of the set matches `region`.
+    #[must_use]
+    pub fn contains(&self, region: &str) -> bool {
+        self.regions.iter().any(|pattern| wildcard_match(pattern, region))
+    }
+
+    /// Returns the region if the set consists of exactly one region without wildcards.
+    #[must_use]
+    pub fn single(&self) -> Option<&'a str> {
+        match self.regions.as_slice() {
+            [region] if !region.contains('*') => Some(region),
+            _ => None,
+        }
+    }
+}
+
+/// Matches `input` against `pattern` where `*` matches any (possibly empty) sequence.
+fn wildcard_match(pattern: &str, input: &str) -> bool {
+    let (p, s) = (pattern.as_bytes(), input.as_bytes());
+    let (mut pi, mut si) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while si < s.len() {
+        if pi < p.len() && p[pi] == b'*' {
+            backtrack = Some((pi, si));
+            pi += 1;
+        } else if pi < p.len() && p[pi] == s[si] {
+            pi += 1;
+            si += 1;
+        } else if let Some((bp, bs)) = backtrack {
+            pi = bp + 1;
+            si = bs + 1;
+            backtrack = Some((bp, bs + 1));
+        } else {
+            return false;
+        }
+    }
+
+    p[pi..].iter().all(|&c| c == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_match() {
+        let set = RegionSet::parse("us-east-1, eu-*").unwrap();
+        assert!(set.contains("us-east-1"));
+        assert!(set.contains("eu-west-1"));
+        assert!(set.contains("eu-"));
+        assert!(!set.contains("us-west-2"));
+        assert_eq!(set.single(), None);
+
+        let set = RegionSet::parse("*").unwrap();
+        assert!(set.contains("ap-southeast-2"));
+        assert_eq!(set.single(), None);
+
+        let set = RegionSet::parse("us-west-2").unwrap();
+        assert!(set.contains("us-west-2"));
+        assert!(!set.contains("us-west-22"));
+        assert_eq!(set.single(), Some("us-west-2"));
+    }
+
+    #[test]
+    fn parse_rejects_invalid() {
+        assert!(RegionSet::parse("").is_err());
+        assert!(RegionSet::parse("us-east-1,").is_err());
+        assert!(RegionSet::parse("us-east-1;us-west-2").is_err());
+    }
+
+    #[test]
+    fn wildcard_in_the_middle() {
+        assert!(wildcard_match("us-*-1", "us-east-1"));
+        assert!(wildcard_match("us-*-1", "us-gov-west-1"));
+        assert!(!wildcard_match("us-*-1", "us-east-2"));
+        assert!(wildcard_match("**", ""));
+    }
+}
@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023-2026 The s3s Authors
+
+//! Tracing spans and W3C trace context
+//!
+//! [`S3Service`](crate::service::S3Service) creates a `DEBUG` span named `s3s.request` for every
+//! request. Its fields follow the OpenTelemetry semantic conventions, so that the span can be
+//! exported with `tracing-opentelemetry`:
+//!
+//! | Field                       | Value                                               |
+//! | --------------------------- | --------------------------------------------------- |
+//! | `otel.name`                 | `S3/<operation>`, for example `S3/GetObject`        |
+//! | `otel.kind`                 | `server`                                            |
+//! | `otel.status_code`          | `ERROR` if the response is a server error           |
+//! | `rpc.system`                | `aws-api`                                           |
+//! | `rpc.service`               | `S3`                                                |
+//! | `rpc.method`                | The operation name                                  |
+//! | `http.request.method`       | The HTTP method                                     |
+//! | `url.path`                  | The request path                                    |
+//! | `http.response.status_code` | The HTTP status code                                |
+//! | `error.type`                | The S3 error code, if any                           |
+//! | `aws.request_id`            | The [request ID](crate::request_id)                 |
+//! | `aws.s3.bucket`             | The bucket name                                     |
+//! | `aws.s3.key`                | The object key                                      |
+//! | `trace_id`                  | The trace ID of the incoming `traceparent` header   |
+//! | `parent_span_id`            | The parent ID of the incoming `traceparent` header  |
+//!
+//! The phases of an S3 operation are covered by `DEBUG` child spans:
+//! `parse_host`, `check_signature`, `check_access`, `deserialize`, `handler` and `serialize`.
+//!
+//! # Trace context propagation
+//!
+//! If the request has a valid [W3C `traceparent`](https://www.w3.org/TR/trace-context/#traceparent-header)
+//! header, it is parsed into a [`TraceParent`] and inserted into the request extensions.
+//! `s3s` does not depend on OpenTelemetry, so the remote parent is only recorded in the
+//! `trace_id` and `parent_span_id` fields. To link the spans to the remote trace,
+//! set the parent of the span with the [`TraceParent`], for example in an
+//! [`S3Interceptor`](crate::intercept::S3Interceptor) or a middleware.
+
+use std::fmt;
+
+/// A parsed W3C `traceparent` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceParent {
+    /// The version of the header. Only version `00` is fully understood.
+    pub version: u8,
+    /// The trace ID. It is never zero.
+    pub trace_id: u128,
+    /// The span ID of the caller. It is never zero.
+    pub parent_id: u64,
+    /// The trace flags.
+    pub flags: u8,
+}
+
+impl TraceParent {
+    /// Parses a `traceparent` header value.
+    ///
+    /// Returns `None` if the value is invalid.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let mut parts = s.split('-');
+        let version = parse_hex::<1>(parts.next()?)?;
+        let trace_id = parse_hex::<16>(parts.next()?)?;
+        let parent_id = parse_hex::<8>(parts.next()?)?;
+        let flags = parse_hex::<1>(parts.next()?)?;
+
+        let version = version[0];
+        if version == 0xff {
+            return None;
+        }
+        // Version 00 has exactly four fields. Future versions may append more fields.
+        if parts.next().is_some() && version == 0 {
+            return None;
+        }
+
+        let trace_id = u128::from_be_bytes(trace_id);
+        let parent_id = u64::from_be_bytes(parent_id);
+        if trace_id == 0 || parent_id == 0 {
+            return None;
+        }
+
+        Some(Self {
+            version,
+            trace_id,
+            parent_id,
+            flags: flags[0],
+        })
+    }
+
+    /// Returns `true` if the caller may have recorded the trace.
+    #[must_use]
+    pub fn is_sampled(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+}
+
+/// Parses exactly `N` bytes of lowercase hex.
+fn parse_hex<const N: usize>(s: &str) -> Option<[u8; N]> {
+    if s.len() != N * 2 || !s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
+        return None;
+    }
+    let mut buf = [0; N];
+    hex_simd::decode(s.as_bytes(), hex_simd::Out::from_slice(&mut buf)).ok()?;
+    Some(buf)
+}
+
+impl fmt::Display for TraceParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02x}-{:032x}-{:016x}-{:02x}",
+            self.version, self.trace_id, self.parent_id, self.flags
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_traceparent() {
+        let s = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let tp = TraceParent::parse(s).unwrap();
+        assert_eq!(tp.version, 0);
+        assert_eq!(tp.trace_id, 0x4bf9_2f35_77b3_4da6_a3ce_929d_0e0e_4736);
+        assert_eq!(tp.parent_id, 0x00f0_67aa_0ba9_02b7);
+        assert!(tp.is_sampled());
+        assert_eq!(tp.to_string(), s);
+
+        let future = "01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00-extra";
+        let tp = TraceParent::parse(future).unwrap();
+        assert_eq!(tp.version, 1);
+        assert!(!tp.is_sampled());
+
+        let invalid = [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e473-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902bz-01",
+        ];
+        for s in invalid {
+            assert!(TraceParent::parse(s).is_none(), "{s:?}");
+        }
+    }
+}
@@ -18,6 +18,8 @@ s3s-fs \
     --port          8014            \
     --domain        localhost:8014  \
     --domain        localhost       \
+    --enable-sts                    \
+    --sts-role      arn:aws:iam::123456789012:role/example \
     "$DATA_DIR" | tee target/s3s-fs.log &
 
 sleep 1s